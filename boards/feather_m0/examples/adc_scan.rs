@@ -0,0 +1,64 @@
+//! Read a bank of four analog inputs in one call.
+//!
+//! A1 to A4 are ADC channels 2 to 5, so the input scan mode of the ADC
+//! converts all of them in sequence. Connect a joystick or sliders to these
+//! pins, and the readings are printed over semihosting.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use cortex_m_semihosting::hprintln;
+
+use bsp::hal;
+use bsp::pac;
+use feather_m0 as bsp;
+
+use bsp::entry;
+use hal::adc::Adc;
+use hal::clock::GenericClockController;
+use hal::gpio::B;
+use hal::prelude::*;
+use pac::{CorePeripherals, Peripherals};
+
+/// ADC channel of A1 (PB08)
+const FIRST_CHANNEL: u8 = 2;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.pm,
+        &mut peripherals.sysctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = hal::delay::Delay::new(core.SYST, &mut clocks);
+    let mut adc = Adc::adc(peripherals.adc, &mut peripherals.pm, &mut clocks);
+
+    // Hand the scanned pins over to the ADC
+    let _a1 = pins.a1.into_alternate::<B>();
+    let _a2 = pins.a2.into_alternate::<B>();
+    let _a3 = pins.a3.into_alternate::<B>();
+    let _a4 = pins.a4.into_alternate::<B>();
+
+    let mut readings = [0u16; 4];
+    loop {
+        adc.read_scan(FIRST_CHANNEL, &mut readings);
+        hprintln!(
+            "A1 {} A2 {} A3 {} A4 {}",
+            readings[0],
+            readings[1],
+            readings[2],
+            readings[3]
+        )
+        .ok();
+        delay.delay_ms(200u16);
+    }
+}
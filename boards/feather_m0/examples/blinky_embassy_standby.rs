@@ -0,0 +1,68 @@
+//! Blinks a led from an embassy task, keeping the chip in standby between
+//! blinks.
+//!
+//! The RTC monotonic wakes the executor up, and the executor enters standby
+//! again as soon as the task is waiting. To see the difference, power the board
+//! from a battery through an ammeter and compare the average current with the
+//! `blinky_embassy` example, which only stops the CPU clock. A debugger will
+//! lose its connection while the chip sleeps.
+#![no_std]
+#![no_main]
+
+use feather_m0 as bsp;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{hal, pac, pin_alias};
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::prelude::*;
+use hal::rtc::rtic::rtc_clock;
+use hal::sleep::{set_sleep_mode, SleepMode};
+
+hal::rtc_monotonic!(Mono, rtc_clock::ClockCustom<8_192>);
+
+#[no_mangle]
+static RTIC_ASYNC_MAX_LOGICAL_PRIO: u8 = 1;
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.pm,
+        &mut peripherals.sysctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    // The RTC clock must keep running in standby to wake the chip up
+    let rtc_clock_src = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 4, ClockSource::Xosc32k, false)
+        .unwrap();
+    clocks.configure_standby(ClockGenId::Gclk2, true);
+    let _ = clocks.rtc(&rtc_clock_src).unwrap();
+
+    // Work around the SAMD21 erratum which can prevent waking up from standby
+    peripherals
+        .nvmctrl
+        .ctrlb()
+        .modify(|_, w| w.sleepprm().disabled());
+
+    // From now on, the executor enters standby whenever it is idle
+    set_sleep_mode(&mut peripherals.pm, &mut core.SCB, SleepMode::Standby);
+
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    Mono::start(peripherals.rtc);
+
+    loop {
+        let _ = red_led.set_high();
+        Mono::delay(50u64.millis()).await;
+        let _ = red_led.set_low();
+        Mono::delay(2u64.secs()).await;
+    }
+}
@@ -11,7 +11,7 @@ pub use atsamd_hal as hal;
 pub use hal::ehal;
 pub use hal::pac;
 
-use hal::clock::GenericClockController;
+use hal::clock::{GClock, GenericClockController};
 use hal::sercom::{
     i2c, spi,
     uart::{self, BaudMode, Oversampling},
@@ -179,6 +179,9 @@ pub type Spi = spi::Spi<spi::Config<SpiPads>, spi::Duplex>;
 /// Convenience for setting up the labelled SPI peripheral.
 /// This powers up SERCOM1 and configures it for use as an
 /// SPI Master in SPI Mode 0.
+///
+/// The SERCOM is clocked from GCLK0. Use [`spi_master_with_gclk`] to select
+/// another generator.
 pub fn spi_master(
     clocks: &mut GenericClockController,
     baud: Hertz,
@@ -189,7 +192,26 @@ pub fn spi_master(
     miso: impl Into<Miso>,
 ) -> Spi {
     let gclk0 = clocks.gclk0();
-    let clock = clocks.sercom1_core(&gclk0).unwrap();
+    spi_master_with_gclk(clocks, &gclk0, baud, sercom, mclk, sclk, mosi, miso)
+}
+
+/// Same as [`spi_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+#[allow(clippy::too_many_arguments)]
+pub fn spi_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: Hertz,
+    sercom: SpiSercom,
+    mclk: &mut pac::Mclk,
+    sclk: impl Into<Sclk>,
+    mosi: impl Into<Mosi>,
+    miso: impl Into<Miso>,
+) -> Spi {
+    let clock = clocks.sercom1_core(gclk).unwrap();
     let freq = clock.freq();
     let (miso, mosi, sclk) = (miso.into(), mosi.into(), sclk.into());
     let pads = spi::Pads::default().data_in(miso).data_out(mosi).sclk(sclk);
@@ -213,6 +235,9 @@ pub type I2c = i2c::I2c<i2c::Config<I2cPads>>;
 
 /// Convenience for setting up the labelled SDA, SCL pins to
 /// operate as an I2C master running at the specified frequency.
+///
+/// The SERCOM is clocked from GCLK0. Use [`i2c_master_with_gclk`] to select
+/// another generator.
 pub fn i2c_master(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -222,7 +247,24 @@ pub fn i2c_master(
     scl: impl Into<Scl>,
 ) -> I2c {
     let gclk0 = clocks.gclk0();
-    let clock = &clocks.sercom2_core(&gclk0).unwrap();
+    i2c_master_with_gclk(clocks, &gclk0, baud, sercom, mclk, sda, scl)
+}
+
+/// Same as [`i2c_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn i2c_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom: I2cSercom,
+    mclk: &mut pac::Mclk,
+    sda: impl Into<Sda>,
+    scl: impl Into<Scl>,
+) -> I2c {
+    let clock = &clocks.sercom2_core(gclk).unwrap();
     let freq = clock.freq();
     let baud = baud.into();
     let pads = i2c::Pads::new(sda.into(), scl.into());
@@ -239,6 +281,9 @@ pub type Uart = uart::Uart<uart::Config<UartPads>, uart::Duplex>;
 
 /// Convenience for setting up the labelled RX, TX pins to
 /// operate as a UART device running at the specified baud.
+///
+/// The SERCOM is clocked from GCLK0. Use [`uart_with_gclk`] to select another
+/// generator.
 pub fn uart(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -248,8 +293,24 @@ pub fn uart(
     tx: impl Into<UartTx>,
 ) -> Uart {
     let gclk0 = clocks.gclk0();
+    uart_with_gclk(clocks, &gclk0, baud, sercom, mclk, rx, tx)
+}
 
-    let clock = &clocks.sercom5_core(&gclk0).unwrap();
+/// Same as [`uart`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn uart_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom: UartSercom,
+    mclk: &mut pac::Mclk,
+    rx: impl Into<UartRx>,
+    tx: impl Into<UartTx>,
+) -> Uart {
+    let clock = &clocks.sercom5_core(gclk).unwrap();
     let baud = baud.into();
     let pads = uart::Pads::default().rx(rx.into()).tx(tx.into());
     uart::Config::new(mclk, sercom, pads, clock.freq())
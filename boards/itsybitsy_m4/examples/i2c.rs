@@ -0,0 +1,70 @@
+#![no_std]
+#![no_main]
+
+//! This example sets up the labelled SDA and SCL pins as an I2C master with
+//! [`bsp::i2c_master`], then reads the chip ID of a BMP388 pressure sensor
+//! and echoes it on the default UART. The red LED is turned on if the expected
+//! chip ID was read.
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use itsybitsy_m4 as bsp;
+
+use bsp::{
+    entry,
+    hal::{clock::GenericClockController, nb, pac::Peripherals, prelude::*},
+};
+
+// Adjust the device address and registers to your sensor
+const ADDRESS: u8 = 0x77;
+const CHIP_ID_REGISTER: u8 = 0x00;
+const CHIP_ID: u8 = 0x50;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_internal_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut serial = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        peripherals.sercom3,
+        &mut peripherals.mclk,
+        pins.d0_rx,
+        pins.d1_tx,
+    );
+    let mut i2c = bsp::i2c_master(
+        &mut clocks,
+        100.kHz(),
+        peripherals.sercom2,
+        &mut peripherals.mclk,
+        pins.sda,
+        pins.scl,
+    );
+    let mut red_led = pins.d13.into_push_pull_output();
+
+    let mut chip_id = [0x00; 1];
+    if i2c
+        .write_read(ADDRESS, &[CHIP_ID_REGISTER], &mut chip_id)
+        .is_ok()
+    {
+        let _ = nb::block!(serial.write(chip_id[0]));
+        if chip_id[0] == CHIP_ID {
+            red_led.set_high().unwrap();
+        }
+    }
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -19,6 +19,7 @@ use bsp::{
     hal::{
         clock::GenericClockController,
         delay::Delay,
+        ehal::spi::SpiBus,
         nb,
         pac::{CorePeripherals, Peripherals},
         prelude::*,
@@ -60,11 +61,15 @@ fn main() -> ! {
     let mut cs = pins.a2.into_push_pull_output();
     let message = b"hello world";
     loop {
+        // `transfer_in_place` overwrites the buffer with the slave's response,
+        // so start every transaction from a fresh copy of the message
+        let mut buf = *message;
         cs.set_low().unwrap();
-        if let Ok(slave_msg) = spi1.transfer(&mut message.clone()) {
-            cs.set_high().unwrap();
-            for c in slave_msg {
-                let _ = nb::block!(serial.write(*c));
+        let result = spi1.transfer_in_place(&mut buf);
+        cs.set_high().unwrap();
+        if result.is_ok() {
+            for c in buf {
+                let _ = nb::block!(serial.write(c));
             }
         }
         delay.delay_ms(200u8);
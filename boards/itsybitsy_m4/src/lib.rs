@@ -9,7 +9,7 @@ use embedded_hal_02::timer::{CountDown, Periodic};
 pub use hal::ehal;
 
 pub use hal::{
-    clock::GenericClockController,
+    clock::{GClock, GenericClockController},
     dbgprint, pac,
     qspi::{OneShot, Qspi},
     sercom::{
@@ -299,6 +299,9 @@ pub type I2c = i2c::I2c<i2c::Config<I2cPads>>;
 
 /// Convenience for setting up the labelled SDA, SCL pins to
 /// operate as an I2C master running at the specified frequency.
+///
+/// The SERCOM is clocked from GCLK0. Use [`i2c_master_with_gclk`] to select
+/// another generator.
 pub fn i2c_master(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -308,7 +311,24 @@ pub fn i2c_master(
     scl: impl Into<Scl>,
 ) -> I2c {
     let gclk0 = clocks.gclk0();
-    let clock = &clocks.sercom2_core(&gclk0).unwrap();
+    i2c_master_with_gclk(clocks, &gclk0, baud, sercom, mclk, sda, scl)
+}
+
+/// Same as [`i2c_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn i2c_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom: I2cSercom,
+    mclk: &mut pac::Mclk,
+    sda: impl Into<Sda>,
+    scl: impl Into<Scl>,
+) -> I2c {
+    let clock = &clocks.sercom2_core(gclk).unwrap();
     let freq = clock.freq();
     let baud = baud.into();
     let pads = i2c::Pads::new(sda.into(), scl.into());
@@ -325,6 +345,9 @@ pub type Uart = uart::Uart<uart::Config<UartPads>, uart::Duplex>;
 
 /// Convenience for setting up the labelled RX, TX pins to
 /// operate as a UART device running at the specified baud.
+///
+/// The SERCOM is clocked from GCLK0. Use [`uart_with_gclk`] to select another
+/// generator.
 pub fn uart(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -334,7 +357,24 @@ pub fn uart(
     uart_tx: impl Into<UartTx>,
 ) -> Uart {
     let gclk0 = clocks.gclk0();
-    let clock = &clocks.sercom3_core(&gclk0).unwrap();
+    uart_with_gclk(clocks, &gclk0, baud, sercom3, mclk, uart_rx, uart_tx)
+}
+
+/// Same as [`uart`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn uart_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom3: UartSercom,
+    mclk: &mut pac::Mclk,
+    uart_rx: impl Into<UartRx>,
+    uart_tx: impl Into<UartTx>,
+) -> Uart {
+    let clock = &clocks.sercom3_core(gclk).unwrap();
     let baud = baud.into();
     let pads = uart::Pads::default().rx(uart_rx.into()).tx(uart_tx.into());
     uart::Config::new(mclk, sercom3, pads, clock.freq())
@@ -385,6 +425,9 @@ pub type Spi = spi::Spi<spi::Config<SpiPads>, spi::Duplex>;
 /// Convenience for setting up the default SPI.
 /// This powers up SERCOM1 and configures it for use as an
 /// SPI Master in SPI Mode 0.
+///
+/// The SERCOM is clocked from GCLK0. Use [`spi_master_with_gclk`] to select
+/// another generator.
 pub fn spi_master(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -396,7 +439,26 @@ pub fn spi_master(
     miso: impl Into<Miso>,
 ) -> Spi {
     let gclk0 = clocks.gclk0();
-    let clock = clocks.sercom1_core(&gclk0).unwrap();
+    spi_master_with_gclk(clocks, &gclk0, baud, sercom1, mclk, sck, mosi, miso)
+}
+
+/// Same as [`spi_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+#[allow(clippy::too_many_arguments)]
+pub fn spi_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom1: SpiSercom,
+    mclk: &mut pac::Mclk,
+    sck: impl Into<Sck>,
+    mosi: impl Into<Mosi>,
+    miso: impl Into<Miso>,
+) -> Spi {
+    let clock = clocks.sercom1_core(gclk).unwrap();
     let freq = clock.freq();
     let (miso, mosi, sck) = (miso.into(), mosi.into(), sck.into());
     let pads = spi::Pads::default().data_in(miso).data_out(mosi).sclk(sck);
@@ -261,7 +261,7 @@ pub fn flash_spi_master(
     let (miso, mosi, sclk, mut cs) = (miso.into(), mosi.into(), sclk.into(), cs.into());
     let pads = spi::Pads::default().data_in(miso).data_out(mosi).sclk(sclk);
     let spi = spi::Config::new(pm, sercom5, pads, freq)
-        .baud(48.MHz())
+        .baud(24.MHz())
         .spi_mode(spi::MODE_0)
         .enable();
 
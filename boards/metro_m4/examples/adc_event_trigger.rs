@@ -0,0 +1,90 @@
+//! Start an ADC conversion on a pin edge, without any CPU involvement.
+//!
+//! A button on D0 is routed through the EIC and the event system to the
+//! `START` event input of ADC0. Each press samples A0 in hardware. The
+//! firmware only collects the results, and prints them over semihosting.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use cortex_m_semihosting::hprintln;
+
+use bsp::entry;
+use hal::adc::{Adc, EventTriggered, InterruptAdc};
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::eic::{Eic, Sense};
+use hal::gpio::{Pin, PullUpInterrupt, B};
+use pac::gclk::pchctrl::Genselect::Gclk11;
+use pac::Peripherals;
+
+/// Event generator ID of `EIC/EXTINT7`
+const EVGEN_EIC_EXTINT_7: u8 = 0x12 + 7;
+
+/// Event user ID of `ADC0/START`
+const USER_ADC0_START: usize = 54;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    // Configure a clock for the EIC peripheral
+    let _internal_clock = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Osculp32k, false)
+        .unwrap();
+    let gclk2 = clocks.get_gclk(ClockGenId::Gclk2).unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // Generate an event on every press, with the filter debouncing the button
+    let button: Pin<_, PullUpInterrupt> = pins.d0.into();
+    let mut extint = eic_channels.7.with_pin(button);
+    extint.sense(Sense::Fall);
+    extint.filter(true);
+    extint.enable_event();
+
+    let adc0 = Adc::adc0(peripherals.adc0, &mut peripherals.mclk, &mut clocks, Gclk11);
+    let mut adc0: InterruptAdc<_, EventTriggered> = adc0.into();
+    let mut a0 = pins.a0.into_alternate::<B>();
+    adc0.start_on_event(&mut a0);
+
+    // Route EXTINT7 to ADC0/START through event channel 0. The asynchronous
+    // path does not require a clock for the event channel.
+    peripherals
+        .mclk
+        .apbbmask()
+        .modify(|_, w| w.evsys_().set_bit());
+    let evsys = peripherals.evsys;
+    // USER.CHANNEL holds the event channel number plus one
+    evsys
+        .user(USER_ADC0_START)
+        .write(|w| unsafe { w.channel().bits(1) });
+    evsys.channels(0).channel().write(|w| unsafe {
+        w.evgen().bits(EVGEN_EIC_EXTINT_7);
+        w.path().asynchronous()
+    });
+
+    loop {
+        if let Some(data) = adc0.service_interrupt_ready() {
+            hprintln!("{}", data).ok();
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Sample a voltage on pin A0 once per second, without busy-waiting.
+//!
+//! Each reading enables the ADC, sleeps until the conversion result is ready,
+//! and disables the ADC again, which suits occasional measurements such as
+//! battery checks. Connect A0 to the 3.3V pin to check that it reads close to
+//! full scale, or to GND to check that it reads close to zero.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use hal::{
+    adc::Adc,
+    clock::GenericClockController,
+    fugit::MillisDurationU32,
+    gpio::B,
+    pac::{gclk::pchctrl::Genselect::Gclk11, Tc4},
+    timer::TimerCounter,
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_multiple_interrupts!(struct AdcIrqs {
+    ADC0: [ADC0_OTHER, ADC0_RESRDY] => atsamd_hal::adc::InterruptHandler<pac::Adc0>;
+});
+
+atsamd_hal::bind_interrupts!(struct TimerIrqs {
+    TC4 => atsamd_hal::timer::InterruptHandler<Tc4>;
+});
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let timer_clock = clocks.gclk0();
+    let tc45 = &clocks.tc4_tc5(&timer_clock).unwrap();
+    let mut timer =
+        TimerCounter::tc4_(tc45, peripherals.tc4, &mut peripherals.mclk).into_future(TimerIrqs);
+
+    let mut adc0 = Adc::adc0(peripherals.adc0, &mut peripherals.mclk, &mut clocks, Gclk11)
+        .into_future(AdcIrqs);
+    let mut a0 = pins.a0.into_alternate::<B>();
+
+    loop {
+        let data = adc0.read(&mut a0).await;
+        defmt::info!("A0: {}", data);
+        timer
+            .delay(MillisDurationU32::from_ticks(1000).convert())
+            .await;
+    }
+}
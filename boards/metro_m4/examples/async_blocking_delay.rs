@@ -0,0 +1,66 @@
+//! Mix blocking SysTick delays with async timer delays.
+//!
+//! The Embassy executor doesn't need SysTick, and neither do the async APIs of
+//! the HAL. Here, the async delays come from TC4, while SysTick is handed to a
+//! blocking [`Delay`], which times a 10 µs trigger pulse on D2 after every LED
+//! toggle. Such short pulses are best done blocking, as waking the executor
+//! would take a significant part of the pulse.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{hal, pac, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, StatefulOutputPin};
+use hal::fugit::MillisDurationU32;
+use hal::pac::Tc4;
+use hal::timer::TimerCounter;
+use metro_m4 as bsp;
+
+atsamd_hal::bind_interrupts!(struct Irqs {
+    TC4 => atsamd_hal::timer::InterruptHandler<Tc4>;
+});
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+    let mut trigger = pins.d2.into_push_pull_output();
+
+    // SysTick is only used for blocking delays
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    // TC4 provides the async delays
+    let timer_clock = clocks.gclk0();
+    let tc45 = &clocks.tc4_tc5(&timer_clock).unwrap();
+    let timer = TimerCounter::tc4_(tc45, peripherals.tc4, &mut peripherals.mclk);
+    let mut timer = timer.into_future(Irqs);
+
+    loop {
+        timer
+            .delay(MillisDurationU32::from_ticks(500).convert())
+            .await;
+        red_led.toggle().unwrap();
+
+        trigger.set_high().unwrap();
+        delay.delay_us(10);
+        trigger.set_low().unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+//! Wait for presses of a push button, debounced by the EIC.
+//!
+//! Connect a push button between D0 and ground. The red LED lights up while
+//! the button is held down, and the number of presses is logged over RTT.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::pac;
+use bsp::{hal, pin_alias};
+use hal::{
+    clock::{ClockGenId, ClockSource, GenericClockController},
+    ehal::digital::{OutputPin, PinState},
+    eic::{Button, Eic},
+    gpio::{Pin, PullUpInterrupt},
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_interrupts!(struct Irqs {
+    EIC_EXTINT_7 => atsamd_hal::eic::InterruptHandler;
+});
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    // Configure a clock for the EIC peripheral
+    let _internal_clock = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Osculp32k, false)
+        .unwrap();
+    let gclk2 = clocks.get_gclk(ClockGenId::Gclk2).unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // The button pulls the pin to ground, so it is pressed when low
+    let pin: Pin<_, PullUpInterrupt> = pins.d0.into();
+    let extint = eic_channels.7.with_pin(pin).into_future(Irqs);
+    let mut button = Button::new(extint, PinState::Low);
+
+    let mut presses: u32 = 0;
+    loop {
+        button.wait_for_press().await;
+        red_led.set_high().unwrap();
+        presses = presses.wrapping_add(1);
+        defmt::info!("Pressed {} times", presses);
+
+        button.wait_for_release().await;
+        red_led.set_low().unwrap();
+    }
+}
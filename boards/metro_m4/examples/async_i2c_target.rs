@@ -0,0 +1,109 @@
+//! Emulate a simple register-map I2C device at address 0x28.
+//!
+//! Connect the SDA and SCL pins to an I2C controller, such as another board or
+//! a USB-I2C adapter. The device exposes 16 registers, initialized to zero
+//! except for the read-only identification register 0x0F, which reads 0x33. As
+//! on most sensors, the first byte written selects a register, further bytes
+//! are written to consecutive registers, and reads return consecutive
+//! registers starting from the selected one:
+//!
+//! * Write `[0x02, 0xAB]` to set register 2 to 0xAB
+//! * Write `[0x0F]`, then read one byte, to read the identification register
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use hal::{
+    clock::GenericClockController,
+    sercom::{
+        i2c::{
+            self,
+            target::{self, Transaction},
+        },
+        Sercom5,
+    },
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_multiple_interrupts!(struct I2cIrqs {
+    SERCOM5: [SERCOM5_0, SERCOM5_1, SERCOM5_2, SERCOM5_3, SERCOM5_OTHER] => target::InterruptHandler<Sercom5>;
+});
+
+const ADDRESS: u8 = 0x28;
+const WHO_AM_I: usize = 0x0F;
+const NUM_REGISTERS: usize = 16;
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let i2c_sercom = bsp::periph_alias!(peripherals.i2c_sercom);
+
+    // The target does not generate the bus clock, but the SERCOM core clock must
+    // be running
+    let gclk0 = clocks.gclk0();
+    clocks.sercom5_core(&gclk0).unwrap();
+    let pads = i2c::Pads::new(pins.sda, pins.scl);
+    let mut target = target::Config::new(&peripherals.mclk, i2c_sercom, pads, ADDRESS)
+        .general_call(true)
+        .enable(I2cIrqs);
+
+    let mut registers = [0u8; NUM_REGISTERS];
+    registers[WHO_AM_I] = 0x33;
+    let mut pointer = 0;
+
+    loop {
+        match target.transaction().await {
+            Ok(Transaction::Write { general_call: true }) => {
+                // General call: a single 0x06 byte requests a reset
+                let mut command = [0; 1];
+                if let Ok(1) = target.receive(&mut command).await {
+                    if command[0] == 0x06 {
+                        defmt::info!("General call reset");
+                        registers = [0; NUM_REGISTERS];
+                        registers[WHO_AM_I] = 0x33;
+                        pointer = 0;
+                    }
+                }
+            }
+            Ok(Transaction::Write {
+                general_call: false,
+            }) => {
+                let mut buffer = [0; 1 + NUM_REGISTERS];
+                match target.receive(&mut buffer).await {
+                    Ok(0) | Err(_) => {}
+                    Ok(n) => {
+                        pointer = buffer[0] as usize % NUM_REGISTERS;
+                        for &value in &buffer[1..n] {
+                            if pointer != WHO_AM_I {
+                                registers[pointer] = value;
+                            }
+                            pointer = (pointer + 1) % NUM_REGISTERS;
+                        }
+                        defmt::info!("Write, registers: {:#x}", registers);
+                    }
+                }
+            }
+            Ok(Transaction::Read) => {
+                if let Ok(n) = target.respond(&registers[pointer..]).await {
+                    pointer = (pointer + n) % NUM_REGISTERS;
+                }
+            }
+            Err(_) => defmt::warn!("I2C target error"),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Read a LIS3DH accelerometer whenever it signals that new data is ready.
+//!
+//! Wire the sensor to the I2C header, and its INT1 pin to D2. The sensor is
+//! configured to sample at 100 Hz and to raise INT1 while a new sample is
+//! pending. Instead of polling the sensor, the task waits for INT1 through the
+//! [`Wait`] trait, then reads all three axes in a single DMA transfer.
+//!
+//! [`Wait::wait_for_high`] resolves immediately if the pin is already high, so
+//! a sample that becomes ready while the previous one is being read is never
+//! missed.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use hal::ehal_async::digital::Wait;
+use hal::ehal_async::i2c::I2c;
+use hal::{
+    clock::{ClockGenId, ClockSource, GenericClockController},
+    dmac::{DmaController, PriorityLevel},
+    eic::Eic,
+    gpio::{FloatingInterrupt, Pin},
+    prelude::*,
+    sercom::{i2c, Sercom5},
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_interrupts!(struct EicIrqs {
+    EIC_EXTINT_1 => atsamd_hal::eic::InterruptHandler;
+});
+
+atsamd_hal::bind_multiple_interrupts!(struct DmacIrqs {
+    DMAC: [DMAC_0, DMAC_1, DMAC_2, DMAC_OTHER] => atsamd_hal::dmac::InterruptHandler;
+});
+
+atsamd_hal::bind_multiple_interrupts!(struct I2cIrqs {
+    SERCOM5: [SERCOM5_0, SERCOM5_1, SERCOM5_2, SERCOM5_3, SERCOM5_OTHER] => atsamd_hal::sercom::i2c::InterruptHandler<Sercom5>;
+});
+
+/// LIS3DH address, with its SDO/SA0 pin pulled high
+const LIS3DH_ADDR: u8 = 0x18;
+
+/// Register addresses
+const WHO_AM_I: u8 = 0x0f;
+const CTRL_REG1: u8 = 0x20;
+const CTRL_REG3: u8 = 0x22;
+const CTRL_REG4: u8 = 0x23;
+const OUT_X_L: u8 = 0x28;
+
+/// Setting the MSB of a register address auto-increments it on multi-byte
+/// reads
+const AUTO_INCREMENT: u8 = 0x80;
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let _core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    // Configure a clock for the EIC peripheral
+    let _internal_clock = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Osculp32k, false)
+        .unwrap();
+    let gclk2 = clocks.get_gclk(ClockGenId::Gclk2).unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // The LIS3DH drives INT1 push-pull, so no pull resistor is needed
+    let int1: Pin<_, FloatingInterrupt> = pins.d2.into();
+    let mut data_ready = eic_channels.1.with_pin(int1).into_future(EicIrqs);
+
+    // Initialize DMA Controller
+    let dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+    let mut dmac = dmac.into_future(DmacIrqs);
+    let channels = dmac.split();
+    let channel0 = channels.0.init(PriorityLevel::Lvl0);
+
+    let (sda, scl) = (pins.sda, pins.scl);
+    let i2c_sercom = bsp::periph_alias!(peripherals.i2c_sercom);
+    let gclk0 = clocks.gclk0();
+    let sercom5_clock = &clocks.sercom5_core(&gclk0).unwrap();
+    let pads = i2c::Pads::new(sda, scl);
+    let mut i2c = i2c::Config::new(&peripherals.mclk, i2c_sercom, pads, sercom5_clock.freq())
+        .baud(400.kHz())
+        .enable()
+        .into_future(I2cIrqs)
+        .with_dma_channel(channel0);
+
+    let mut id = [0];
+    i2c.write_read(LIS3DH_ADDR, &[WHO_AM_I], &mut id)
+        .await
+        .unwrap();
+    defmt::info!("WHO_AM_I: {:#x}", id[0]);
+
+    // 100 Hz, all axes enabled
+    i2c.write(LIS3DH_ADDR, &[CTRL_REG1, 0x57]).await.unwrap();
+    // Route the data-ready interrupt to INT1
+    i2c.write(LIS3DH_ADDR, &[CTRL_REG3, 0x10]).await.unwrap();
+    // High resolution mode, +/- 2 g
+    i2c.write(LIS3DH_ADDR, &[CTRL_REG4, 0x08]).await.unwrap();
+
+    loop {
+        data_ready.wait_for_high().await.unwrap();
+
+        // Reading the output registers clears the data-ready interrupt
+        let mut raw = [0; 6];
+        i2c.write_read(LIS3DH_ADDR, &[OUT_X_L | AUTO_INCREMENT], &mut raw)
+            .await
+            .unwrap();
+
+        // Samples are left-justified 12-bit values, at 1 mg/digit
+        let [x, y, z] = [0, 2, 4].map(|i| i16::from_le_bytes([raw[i], raw[i + 1]]) >> 4);
+        defmt::info!("x: {} mg, y: {} mg, z: {} mg", x, y, z);
+    }
+}
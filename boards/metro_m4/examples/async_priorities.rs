@@ -0,0 +1,99 @@
+//! Run two async peripherals with different interrupt priorities.
+//!
+//! The TC4 interrupt, which paces the LED, is given a higher priority than the
+//! SERCOM3 interrupt used by the UART echo. The priorities are set before
+//! calling `into_future`, which enables the interrupts.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::{hal, pac, periph_alias, pin_alias};
+use hal::fugit::{MillisDurationU32, RateExtU32};
+use hal::{
+    async_hal::interrupts::{InterruptSource, Priority, SERCOM3, TC4},
+    clock::GenericClockController,
+    ehal::digital::StatefulOutputPin,
+    pac::Tc4,
+    sercom::{
+        uart::{Config, UartFutureDuplex},
+        Sercom3,
+    },
+    timer::{TimerCounter, TimerFuture},
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_interrupts!(struct TimerIrqs {
+    TC4 => atsamd_hal::timer::InterruptHandler<Tc4>;
+});
+
+atsamd_hal::bind_multiple_interrupts!(struct UartIrqs {
+    SERCOM3: [SERCOM3_0, SERCOM3_1, SERCOM3_2, SERCOM3_OTHER] => atsamd_hal::sercom::uart::InterruptHandler<Sercom3>;
+});
+
+#[embassy_executor::main]
+async fn main(spawner: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let _core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    // Set the priorities before the interrupts are enabled by `into_future`.
+    // Setting them for SERCOM3 acts on all of SERCOM3_0..SERCOM3_OTHER.
+    TC4::set_priority(Priority::P3);
+    SERCOM3::set_priority(Priority::P1);
+
+    let timer_clock = clocks.gclk0();
+    let tc45 = &clocks.tc4_tc5(&timer_clock).unwrap();
+    let timer =
+        TimerCounter::tc4_(tc45, peripherals.tc4, &mut peripherals.mclk).into_future(TimerIrqs);
+
+    let (uart_rx, uart_tx) = (pin_alias!(pins.d0), pin_alias!(pins.d1));
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+    let uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    )
+    .into_future(UartIrqs);
+
+    spawner.spawn(blink(timer, red_led)).unwrap();
+    spawner.spawn(echo(uart)).unwrap();
+}
+
+#[embassy_executor::task]
+async fn blink(mut timer: TimerFuture<Tc4>, mut red_led: bsp::RedLed) {
+    loop {
+        timer
+            .delay(MillisDurationU32::from_ticks(500).convert())
+            .await;
+        red_led.toggle().unwrap();
+    }
+}
+
+#[embassy_executor::task]
+async fn echo(mut uart: UartFutureDuplex<Config<bsp::UartPads>>) {
+    loop {
+        let mut buf = [0x00; 1];
+        match uart.read(&mut buf).await {
+            Ok(()) => uart.write(&buf).await,
+            Err(_) => {
+                defmt::error!("UART Error.");
+                uart.as_mut().flush_rx_buffer();
+            }
+        }
+    }
+}
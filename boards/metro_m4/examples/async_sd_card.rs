@@ -0,0 +1,196 @@
+//! Read the first sector of an SD card using the async, DMA-backed SPI driver.
+//!
+//! Wire an SD card breakout (or a datalogger shield) to the 2x3 SPI header,
+//! with its chip select on D10. The card is initialized in SPI mode, then
+//! sector 0 is read and its MBR signature is logged over RTT.
+//!
+//! Every SPI transaction is awaited through [`SpiBus`], which runs the RX and
+//! TX halves on two DMA channels. Waiting for the card's read token is wrapped
+//! in a timeout: if it expires, the pending transfer future is dropped, which
+//! stops both DMA channels before the bus is used again.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use hal::ehal_async::spi::SpiBus;
+use hal::fugit::Hertz;
+use hal::fugit::MillisDuration;
+use hal::{
+    clock::GenericClockController,
+    dmac::{DmaController, PriorityLevel},
+    prelude::*,
+    sercom::Sercom2,
+};
+use metro_m4 as bsp;
+use rtic_monotonics::Monotonic;
+
+rtic_monotonics::systick_monotonic!(Mono, 10000);
+
+atsamd_hal::bind_multiple_interrupts!(struct DmacIrqs {
+    DMAC: [DMAC_0, DMAC_1, DMAC_2, DMAC_OTHER] => atsamd_hal::dmac::InterruptHandler;
+});
+
+atsamd_hal::bind_multiple_interrupts!(struct SpiIrqs {
+    SERCOM2: [SERCOM2_0, SERCOM2_1, SERCOM2_2, SERCOM2_3, SERCOM2_OTHER] => atsamd_hal::sercom::spi::InterruptHandler<Sercom2>;
+});
+
+/// Length of an SD card sector, in bytes
+const SECTOR_LEN: usize = 512;
+
+/// Token sent by the card before the contents of a data block
+const DATA_START_BLOCK: u8 = 0xfe;
+
+/// R1 response bit indicating the card is still initializing
+const R1_IDLE_STATE: u8 = 0x01;
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let _core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let freq: Hertz<u32> = clocks.gclk0().into();
+    Mono::start(_core.SYST, freq.to_Hz());
+
+    let pins = bsp::Pins::new(peripherals.port);
+
+    // Take SPI pins
+    let (miso, mosi, sclk) = (pins.miso, pins.mosi, pins.sclk);
+    let spi_sercom = bsp::periph_alias!(peripherals.spi_sercom);
+    let mut cs = pins.d10.into_push_pull_output();
+    cs.set_high().unwrap();
+
+    // Initialize DMA Controller
+    let dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+
+    // Turn dmac into an async controller
+    let mut dmac = dmac.into_future(DmacIrqs);
+    // Get individual handles to DMA channels
+    let channels = dmac.split();
+
+    // Initialize DMA Channels 0 and 1
+    let channel0 = channels.0.init(PriorityLevel::Lvl0);
+    let channel1 = channels.1.init(PriorityLevel::Lvl0);
+
+    // SD cards must be initialized at 400 kHz or less, and expect MOSI to be
+    // held high while they are sending data.
+    let mut spi = bsp::spi_master(
+        &mut clocks,
+        400.kHz(),
+        spi_sercom,
+        &mut peripherals.mclk,
+        sclk,
+        mosi,
+        miso,
+    );
+    spi.reconfigure(|c| c.set_nop_word(0xff));
+    let mut spi = spi
+        .into_future(SpiIrqs)
+        .with_dma_channels(channel0, channel1);
+
+    // At least 74 clock cycles with CS deasserted put the card in native mode
+    spi.write(&[0xff; 10]).await.unwrap();
+
+    cs.set_low().unwrap();
+
+    // CMD0: GO_IDLE_STATE. Entering SPI mode requires a valid CRC.
+    let r1 = command(&mut spi, 0, 0, 0x95).await;
+    defmt::info!("CMD0 -> {:#x}", r1);
+
+    // CMD8: SEND_IF_COND, 2.7-3.6V with check pattern 0xaa
+    let r1 = command(&mut spi, 8, 0x1aa, 0x87).await;
+    let mut r7 = [0; 4];
+    spi.read(&mut r7).await.unwrap();
+    defmt::info!("CMD8 -> {:#x}, {:#x}", r1, r7);
+
+    // ACMD41: SD_SEND_OP_COND with HCS set, until the card leaves idle state
+    loop {
+        command(&mut spi, 55, 0, 0x01).await;
+        if command(&mut spi, 41, 0x4000_0000, 0x01).await & R1_IDLE_STATE == 0 {
+            break;
+        }
+        Mono::delay(MillisDuration::<u32>::from_ticks(10).convert()).await;
+    }
+
+    // CMD58: READ_OCR, to find out whether the card uses block addressing
+    command(&mut spi, 58, 0, 0x01).await;
+    let mut ocr = [0; 4];
+    spi.read(&mut ocr).await.unwrap();
+    let block_addressing = ocr[0] & 0x40 != 0;
+    defmt::info!("OCR: {:#x}, block addressing: {}", ocr, block_addressing);
+
+    // Initialization is done, the bus can now run at full speed
+    spi.as_mut().reconfigure(|c| c.set_baud(4.MHz()));
+
+    // CMD17: READ_SINGLE_BLOCK. Sector 0 has the same address in both modes.
+    let r1 = command(&mut spi, 17, 0, 0x01).await;
+    defmt::info!("CMD17 -> {:#x}", r1);
+
+    let token = Mono::timeout_after(
+        MillisDuration::<u32>::from_ticks(100).convert(),
+        wait_for_token(&mut spi),
+    )
+    .await;
+
+    match token {
+        Ok(DATA_START_BLOCK) => {
+            let mut sector = [0; SECTOR_LEN];
+            spi.read(&mut sector).await.unwrap();
+
+            // Discard the data block's CRC
+            let mut crc = [0; 2];
+            spi.read(&mut crc).await.unwrap();
+
+            defmt::info!("Sector 0: {:#x}", sector[..16]);
+            defmt::info!("MBR signature: {:#x}", sector[SECTOR_LEN - 2..SECTOR_LEN]);
+        }
+        Ok(err) => defmt::error!("Read failed with error token {:#x}", err),
+        Err(_) => defmt::error!("Timed out waiting for the data block"),
+    }
+
+    cs.set_high().unwrap();
+    // Send an extra byte so the card releases MISO
+    spi.write(&[0xff]).await.unwrap();
+
+    loop {
+        Mono::delay(MillisDuration::<u32>::from_ticks(1000).convert()).await;
+    }
+}
+
+/// Send a command frame and return the card's R1 response.
+async fn command(spi: &mut impl SpiBus<u8>, index: u8, arg: u32, crc: u8) -> u8 {
+    let arg = arg.to_be_bytes();
+    let frame = [0x40 | index, arg[0], arg[1], arg[2], arg[3], crc];
+    spi.write(&frame).await.unwrap();
+
+    // The response arrives within 8 bytes, and always has its MSB cleared
+    let mut r1 = [0xff];
+    for _ in 0..8 {
+        spi.read(&mut r1).await.unwrap();
+        if r1[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    r1[0]
+}
+
+/// Poll the card until it sends anything other than an idle `0xff` byte.
+async fn wait_for_token(spi: &mut impl SpiBus<u8>) -> u8 {
+    let mut token = [0xff];
+    while token[0] == 0xff {
+        spi.read(&mut token).await.unwrap();
+    }
+    token[0]
+}
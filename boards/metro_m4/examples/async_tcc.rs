@@ -0,0 +1,84 @@
+//! Drive the red LED (D13) with TCC1 PWM, and use the same TCC as an async
+//! timer.
+//!
+//! The duty cycle is updated once every 10 PWM periods by awaiting the period
+//! interrupt, producing a "breathing" LED. Compare channel 3 has no pin
+//! attached, so it is used as a timeout in the middle of each period.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{hal, pac};
+use hal::{
+    clock::GenericClockController,
+    gpio::F,
+    pac::Tcc1,
+    prelude::*,
+    pwm::{Channel, TCC1Pinout, Tcc1Pwm},
+};
+use metro_m4 as bsp;
+
+atsamd_hal::bind_multiple_interrupts!(struct Irqs {
+    TCC1: [TCC1_OTHER, TCC1_MC0, TCC1_MC1, TCC1_MC2, TCC1_MC3] => atsamd_hal::pwm::InterruptHandler<Tcc1>;
+});
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let _core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let red_led = pins.d13.into_alternate::<F>();
+
+    let gclk0 = clocks.gclk0();
+    let pwm = Tcc1Pwm::new(
+        &clocks.tcc0_tcc1(&gclk0).unwrap(),
+        1.kHz(),
+        peripherals.tcc1,
+        TCC1Pinout::Pa16(red_led),
+        &mut peripherals.mclk,
+    );
+
+    let mut tcc = pwm.into_future(Irqs);
+    let max_duty = tcc.as_ref().get_max_duty();
+
+    // Arm the spare compare channel halfway through the period
+    tcc.set_compare(Channel::_3, max_duty / 2);
+
+    let step = max_duty / 50;
+    let mut duty = 0;
+    let mut rising = true;
+    loop {
+        tcc.as_mut().set_duty(Channel::_0, duty);
+
+        for _ in 0..10 {
+            tcc.wait_period().await;
+        }
+        // Only update the duty cycle in the middle of a period
+        tcc.wait_compare(Channel::_3).await;
+
+        if rising && duty + step > max_duty {
+            rising = false;
+        } else if !rising && duty < step {
+            rising = true;
+        }
+
+        if rising {
+            duty += step;
+        } else {
+            duty -= step;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Guard a UART DMA read with a timeout.
+//!
+//! The read is raced against a 1 second delay. When the delay wins, the read
+//! future is dropped, which stops its DMA channel. The UART and the channel
+//! are then reused for the next read, after flushing the bytes received in
+//! the meantime.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::{hal, pac, periph_alias, pin_alias};
+use hal::fugit::Hertz;
+use hal::fugit::MillisDuration;
+use hal::{
+    clock::GenericClockController,
+    dmac::{DmaController, PriorityLevel},
+    prelude::*,
+    sercom::Sercom3,
+};
+use metro_m4 as bsp;
+use rtic_monotonics::Monotonic;
+
+rtic_monotonics::systick_monotonic!(Mono, 10000);
+
+atsamd_hal::bind_multiple_interrupts!(struct DmacIrqs {
+    DMAC: [DMAC_0, DMAC_1, DMAC_2, DMAC_OTHER] => atsamd_hal::dmac::InterruptHandler;
+});
+
+atsamd_hal::bind_multiple_interrupts!(struct UartIrqs {
+    SERCOM3: [SERCOM3_0, SERCOM3_1, SERCOM3_2, SERCOM3_3, SERCOM3_OTHER] => atsamd_hal::sercom::uart::InterruptHandler<Sercom3>;
+});
+
+#[embassy_executor::main]
+async fn main(_spawner: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let freq: Hertz<u32> = clocks.gclk0().into();
+    Mono::start(core.SYST, freq.to_Hz());
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let (uart_rx, uart_tx) = (pin_alias!(pins.d0), pin_alias!(pins.d1));
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+
+    let dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+    let mut dmac = dmac.into_future(DmacIrqs);
+    let channels = dmac.split();
+    let channel0 = channels.0.init(PriorityLevel::Lvl0);
+
+    let mut uart = bsp::uart(
+        &mut clocks,
+        9600.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    )
+    .into_future(UartIrqs)
+    .with_rx_dma_channel(channel0);
+
+    uart.as_mut().flush_rx_buffer();
+
+    loop {
+        let mut buf = [0x00; 8];
+        let timeout = MillisDuration::<u32>::from_ticks(1000).convert();
+        match Mono::timeout_after(timeout, uart.read(&mut buf)).await {
+            Ok(Ok(())) => defmt::info!("read {:#x}", &buf),
+            Ok(Err(_)) => {
+                defmt::error!("UART Error.");
+                uart.as_mut().flush_rx_buffer();
+            }
+            Err(_) => {
+                // The read was cancelled. `buf` may hold the first bytes of a
+                // partial frame, and the rest may still be in the receive
+                // buffer.
+                defmt::warn!("timed out, partial data {:#x}", &buf);
+                uart.as_mut().flush_rx_buffer();
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Interrupt-driven UART echo using a `BufferedUart`.
+//!
+//! Every byte received on the labelled RX pin is echoed back on the labelled
+//! TX pin. Bytes are moved between the SERCOM and the software queues by the
+//! SERCOM interrupt, so the main loop never blocks on the peripheral.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::fugit::RateExtU32;
+use hal::pac::Peripherals;
+use hal::sercom::{uart, Sercom3};
+
+atsamd_hal::bind_multiple_interrupts!(struct UartIrqs {
+    SERCOM3: [SERCOM3_0, SERCOM3_1, SERCOM3_2, SERCOM3_OTHER] => uart::BufferedInterruptHandler<Sercom3>;
+});
+
+const LENGTH: usize = 256;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let uart_rx = pin_alias!(pins.uart_rx);
+    let uart_tx = pin_alias!(pins.uart_tx);
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+
+    let rx_buffer: &'static mut [u8; LENGTH] =
+        cortex_m::singleton!(: [u8; LENGTH] = [0x00; LENGTH]).unwrap();
+    let tx_buffer: &'static mut [u8; LENGTH] =
+        cortex_m::singleton!(: [u8; LENGTH] = [0x00; LENGTH]).unwrap();
+
+    let mut uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    )
+    .into_buffered(UartIrqs, rx_buffer, tx_buffer);
+
+    let mut buf = [0x00; 32];
+    loop {
+        let count = uart.try_read(&mut buf);
+
+        // Wait for room in the TX queue instead of dropping bytes. The TX queue
+        // drains at the same rate the RX queue fills, so this never stalls for
+        // long.
+        let mut sent = 0;
+        while sent < count {
+            sent += uart.try_write(&buf[sent..count]);
+        }
+    }
+}
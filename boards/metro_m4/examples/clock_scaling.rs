@@ -0,0 +1,103 @@
+//! Switch the CPU between 48 MHz and 8 MHz at runtime, with the `clock::v2`
+//! API, while a UART keeps its baud rate.
+//!
+//! The CPU clock, GCLK0, alternates between two sources:
+//!
+//! * DPLL0 at 96 MHz, from the 32.768 kHz crystal, divided by 2 to 48 MHz
+//! * DFLL at 48 MHz, divided by 6 to 8 MHz
+//!
+//! The UART on pins D0 and D1 is clocked from GCLK2, which divides the DFLL
+//! down to 12 MHz. GCLK2 doesn't change when GCLK0 does, so the baud rate
+//! stays correct without reconfiguring the UART. The `clock::v2` types
+//! enforce this: GCLK0 can only be reconfigured while nothing but the CPU
+//! depends on it.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::entry;
+use bsp::hal;
+use bsp::pac;
+use hal::clock::v2::{
+    self as clock,
+    dpll::Dpll,
+    gclk::{Gclk, GclkDiv8},
+    pclk::Pclk,
+    xosc32k::{Xosc32k, Xosc32kBase},
+};
+use hal::fugit::RateExtU32;
+use hal::gpio::Pins;
+use hal::sercom::uart::{self, BaudMode, Oversampling};
+use metro_m4 as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+
+    let (_buses, clocks, tokens) = clock::clock_system_at_reset(
+        peripherals.oscctrl,
+        peripherals.osc32kctrl,
+        peripherals.gclk,
+        peripherals.mclk,
+        &mut peripherals.nvmctrl,
+    );
+
+    // The `sercom` module has not yet been updated to take an `ApbClk`
+    let (_, _, _, mclk) = unsafe { clocks.pac.steal() };
+
+    let pins = Pins::new(peripherals.port);
+
+    // Multiply the 32 kHz crystal by `2929 + 22/32` to get 96 MHz
+    let xosc32k_base = Xosc32kBase::from_crystal(tokens.xosc32k.base, pins.pa00, pins.pa01)
+        .on_demand(false)
+        .enable();
+    while !xosc32k_base.is_ready() {}
+    let (xosc32k, _xosc32k_base) = Xosc32k::enable(tokens.xosc32k.xosc32k, xosc32k_base);
+    let (dpll0, _xosc32k) = Dpll::from_xosc32k(tokens.dpll0, xosc32k);
+    let mut dpll0 = dpll0.loop_div(2929, 22).enable();
+    while !dpll0.is_ready() {}
+
+    // Feed the UART from its own generator, at a fixed 12 MHz
+    let (gclk2, mut dfll) = Gclk::from_source(tokens.gclks.gclk2, clocks.dfll);
+    let gclk2 = gclk2.div(GclkDiv8::Div(4)).enable();
+    let (pclk_sercom3, _gclk2) = Pclk::enable(tokens.pclks.sercom3, gclk2);
+
+    let pads = uart::Pads::default().rx(pins.pa23).tx(pins.pa22);
+    let mut uart = uart::Config::new(&mclk, peripherals.sercom3, pads, pclk_sercom3.freq())
+        .baud(115_200.Hz(), BaudMode::Fractional(Oversampling::Bits16))
+        .enable();
+
+    // Start slow: DFLL / 6 = 8 MHz
+    let mut gclk0 = clocks.gclk0;
+    gclk0.div(GclkDiv8::Div(6));
+
+    loop {
+        // Speed up. DPLL0 / 6 = 16 MHz during the swap, then 48 MHz.
+        let (mut fast, dfll_dec, dpll0_inc) = gclk0.swap_sources(dfll, dpll0);
+        fast.div(GclkDiv8::Div(2));
+        burst(&mut uart, fast.freq().to_Hz());
+
+        // Slow down. DPLL0 / 12 = 8 MHz, then DFLL / 12 = 4 MHz during the
+        // swap, then 8 MHz.
+        fast.div(GclkDiv8::Div(12));
+        let (mut slow, dpll0_dec, dfll_inc) = fast.swap_sources(dpll0_inc, dfll_dec);
+        slow.div(GclkDiv8::Div(6));
+        burst(&mut uart, slow.freq().to_Hz());
+
+        gclk0 = slow;
+        dfll = dfll_inc;
+        dpll0 = dpll0_dec;
+    }
+}
+
+/// Report the CPU frequency, then busy-wait for about one second
+fn burst(uart: &mut impl core::fmt::Write, cpu_freq: u32) {
+    writeln!(uart, "CPU at {} Hz", cpu_freq).unwrap();
+    // Delays based on the CPU clock must be recomputed after every change
+    cortex_m::asm::delay(cpu_freq);
+}
@@ -0,0 +1,111 @@
+//! Build a custom clock tree with the `clock::v2` API.
+//!
+//! Unlike the `GenericClockController`, which always configures the same
+//! tree, `clock::v2` represents every oscillator, GCLK generator and peripheral
+//! channel clock as a typed object, created from a token. Each clock consumes
+//! its source, and the source counts its consumers in its type, so a clock
+//! which still feeds another one can't be reconfigured or disabled: such code
+//! doesn't compile.
+//!
+//! This example builds the following tree:
+//!
+//! * XOSC32K, from the 32.768 kHz crystal -> DPLL0 at 120 MHz -> GCLK0, the CPU
+//!   clock
+//! * DFLL at 48 MHz -> GCLK2, divided by 4 to 12 MHz -> SERCOM3, the UART on
+//!   pins D0 and D1
+//!
+//! It then prints the frequencies over the UART, and blinks the red LED.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use core::fmt::Write as _;
+
+use bsp::entry;
+use bsp::hal;
+use bsp::pac;
+use hal::clock::v2::{
+    self as clock,
+    dpll::Dpll,
+    gclk::{Gclk, GclkDiv8},
+    pclk::Pclk,
+    xosc32k::{Xosc32k, Xosc32kBase},
+};
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::StatefulOutputPin;
+use hal::fugit::RateExtU32;
+use hal::gpio::Pins;
+use hal::sercom::uart::{self, BaudMode, Oversampling};
+use metro_m4 as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+
+    // Take every clock and token, in the state they are in after reset
+    let (_buses, clocks, tokens) = clock::clock_system_at_reset(
+        peripherals.oscctrl,
+        peripherals.osc32kctrl,
+        peripherals.gclk,
+        peripherals.mclk,
+        &mut peripherals.nvmctrl,
+    );
+
+    // The `sercom` module has not yet been updated to take an `ApbClk`
+    let (_, _, _, mclk) = unsafe { clocks.pac.steal() };
+
+    let pins = Pins::new(peripherals.port);
+
+    // Start the crystal oscillator, and enable its 32 kHz output
+    let xosc32k_base = Xosc32kBase::from_crystal(tokens.xosc32k.base, pins.pa00, pins.pa01)
+        .on_demand(false)
+        .enable();
+    while !xosc32k_base.is_ready() {}
+    let (xosc32k, _xosc32k_base) = Xosc32k::enable(tokens.xosc32k.xosc32k, xosc32k_base);
+
+    // Multiply the 32 kHz clock by `3662 + 3/32` to get 120 MHz
+    let (dpll0, _xosc32k) = Dpll::from_xosc32k(tokens.dpll0, xosc32k);
+    let dpll0 = dpll0.loop_div(3662, 3).enable();
+    while !dpll0.is_ready() {}
+
+    // Switch the CPU from the DFLL to the DPLL. The DFLL keeps running, since
+    // it is returned rather than disabled
+    let (gclk0, dfll, _dpll0) = clocks.gclk0.swap_sources(clocks.dfll, dpll0);
+
+    // Divide the DFLL down to 12 MHz, and feed it to SERCOM3
+    let (gclk2, _dfll) = Gclk::from_source(tokens.gclks.gclk2, dfll);
+    let gclk2 = gclk2.div(GclkDiv8::Div(4)).enable();
+    let (pclk_sercom3, _gclk2) = Pclk::enable(tokens.pclks.sercom3, gclk2);
+
+    // At this point, calling `_gclk2.disable()` would fail to compile, because
+    // its type records that it feeds `pclk_sercom3`. The `Pclk` must first be
+    // disabled with `pclk_sercom3.disable(_gclk2)`.
+
+    let pads = uart::Pads::default().rx(pins.pa23).tx(pins.pa22);
+    let mut uart = uart::Config::new(&mclk, peripherals.sercom3, pads, pclk_sercom3.freq())
+        .baud(115_200.Hz(), BaudMode::Fractional(Oversampling::Bits16))
+        .enable();
+
+    let cpu_freq = gclk0.freq();
+    let (mut delay, _gclk0) = Delay::new_with_source(core.SYST, gclk0);
+    let mut red_led = pins.pa16.into_push_pull_output();
+
+    loop {
+        writeln!(
+            uart,
+            "CPU: {} Hz, SERCOM3: {} Hz",
+            cpu_freq.to_Hz(),
+            pclk_sercom3.freq().to_Hz()
+        )
+        .unwrap();
+        red_led.toggle().unwrap();
+        delay.delay_ms(1000);
+    }
+}
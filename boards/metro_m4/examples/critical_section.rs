@@ -0,0 +1,109 @@
+//! Check the behavior of nested critical sections, on the target.
+//!
+//! This example uses the PRIMASK-based `critical-section` implementation, which
+//! this board enables through `cortex-m`. It checks that:
+//!
+//! * Releasing a nested critical section leaves interrupts disabled, until the
+//!   outermost one is released
+//! * An interrupt pended inside a critical section is serviced once it is
+//!   released
+//! * An interrupt handler can take (nested) critical sections itself, and
+//!   returns with interrupts in the same state as on entry
+//!
+//! The `AC` interrupt is used as a software interrupt, since the analog
+//! comparator is not otherwise used. The results are printed over RTT, and the
+//! red LED lights up if every check passed.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use core::cell::Cell;
+
+use bsp::entry;
+use bsp::hal;
+use bsp::pac;
+use bsp::pin_alias;
+use cortex_m::register::primask;
+use critical_section::Mutex;
+use hal::ehal::digital::OutputPin;
+use hal::interrupt::InterruptExt;
+use metro_m4 as bsp;
+use pac::interrupt;
+
+/// Number of times the `AC` interrupt handler ran
+static HANDLER_RUNS: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+
+/// Whether interrupts are currently enabled
+fn interrupts_enabled() -> bool {
+    primask::read().is_active()
+}
+
+fn handler_runs() -> u32 {
+    critical_section::with(|cs| HANDLER_RUNS.borrow(cs).get())
+}
+
+#[entry]
+fn main() -> ! {
+    let peripherals = pac::Peripherals::take().unwrap();
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    unsafe { interrupt::AC.enable() };
+    assert!(interrupts_enabled());
+
+    critical_section::with(|cs| {
+        assert!(!interrupts_enabled());
+
+        critical_section::with(|_| {
+            assert!(!interrupts_enabled());
+            interrupt::AC.pend();
+        });
+
+        // Releasing the inner critical section must restore the state it saw
+        // on entry, so interrupts are still disabled
+        assert!(!interrupts_enabled());
+        assert!(interrupt::AC.is_pending());
+        assert_eq!(HANDLER_RUNS.borrow(cs).get(), 0);
+        defmt::info!("Nested critical section released, interrupts still disabled");
+    });
+
+    // Make sure the pending interrupt is taken before going on
+    cortex_m::asm::isb();
+    assert!(interrupts_enabled());
+    assert_eq!(handler_runs(), 1);
+    defmt::info!("Interrupt pended inside the critical section was serviced");
+
+    // Nothing prevents the handler from running outside of a critical section
+    interrupt::AC.pend();
+    cortex_m::asm::isb();
+    assert_eq!(handler_runs(), 2);
+
+    defmt::info!("All checks passed");
+    red_led.set_high().unwrap();
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
+#[interrupt]
+fn AC() {
+    // Interrupts are enabled on entry, since `PRIMASK` isn't set by the core
+    // when taking an exception
+    assert!(interrupts_enabled());
+
+    critical_section::with(|cs| {
+        // Taking a second critical section inside the handler can't deadlock,
+        // because acquiring one never waits
+        critical_section::with(|_| {
+            let runs = HANDLER_RUNS.borrow(cs);
+            runs.set(runs.get() + 1);
+        });
+        assert!(!interrupts_enabled());
+    });
+
+    assert!(interrupts_enabled());
+}
@@ -0,0 +1,149 @@
+//! Measure the accuracy of `Delay` with a TC pulse-width capture.
+//!
+//! Connect D1 to D0 with a jumper wire. D1 is driven high, `delay_cycles` (or
+//! `delay_us`) is called, and D1 is driven low again. The EIC follows the
+//! level on D0, and TC3 captures the width of the resulting pulse in core
+//! clock cycles, since it is clocked from GCLK0 like the core.
+//!
+//! Toggling the pin takes a few cycles of its own, so the pulse produced by
+//! `delay_cycles(0)` is measured first and subtracted from every other pulse.
+//! The remaining error is logged over RTT for each requested delay. A positive
+//! error means the delay was longer than requested.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m_rt::entry;
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::OutputPin;
+use hal::eic::{Eic, Sense};
+use hal::gpio::{Pin, PullDownInterrupt, PushPullOutput};
+use hal::timer::TimerCounter;
+use metro_m4 as bsp;
+
+/// Event generator ID of `EIC/EXTINT7`
+const EVGEN_EIC_EXTINT_7: u8 = 0x12 + 7;
+
+/// Event user ID of `TC3/EVU`
+const USER_TC3_EVU: usize = 46;
+
+/// Delays to measure, in core clock cycles. The TC counter is 16 bits wide.
+const CYCLES: [u32; 7] = [1, 10, 21, 50, 100, 1_000, 50_000];
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let gclk0 = clocks.gclk0();
+    let tc2_3 = clocks.tc2_tc3(&gclk0).unwrap();
+    let mut capture = TimerCounter::tc3_(&tc2_3, peripherals.tc3, &mut peripherals.mclk);
+    capture.start_pulse_width_capture();
+
+    let gclk2 = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Dfll, false)
+        .unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // The event must follow the level of the pin for the capture to see both
+    // edges, so sense the high level rather than an edge
+    let input: Pin<_, PullDownInterrupt> = pins.d0.into();
+    let mut extint = eic_channels.7.with_pin(input);
+    extint.sense(Sense::High);
+    extint.enable_event();
+
+    peripherals
+        .mclk
+        .apbbmask()
+        .modify(|_, w| w.evsys_().set_bit());
+    let evsys = peripherals.evsys;
+    // USER.CHANNEL holds the event channel number plus one
+    evsys
+        .user(USER_TC3_EVU)
+        .write(|w| unsafe { w.channel().bits(1) });
+    evsys.channels(0).channel().write(|w| unsafe {
+        w.evgen().bits(EVGEN_EIC_EXTINT_7);
+        w.path().asynchronous()
+    });
+
+    let mut output: Pin<_, PushPullOutput> = pins.d1.into();
+    output.set_low().unwrap();
+    // Discard anything captured while the pins were set up
+    delay.delay_ms(1);
+    capture.pulse_width();
+
+    let baseline = measure(&mut capture, &mut delay, |delay| {
+        output.set_high().unwrap();
+        delay.delay_cycles(0);
+        output.set_low().unwrap();
+    });
+    defmt::info!("baseline pulse: {} cycles", baseline);
+
+    for cycles in CYCLES {
+        let width = measure(&mut capture, &mut delay, |delay| {
+            output.set_high().unwrap();
+            delay.delay_cycles(cycles);
+            output.set_low().unwrap();
+        });
+        report("delay_cycles", cycles, width - baseline);
+    }
+
+    let sysclock: hal::time::Hertz = gclk0.into();
+    let cycles_per_us = sysclock.to_MHz();
+    for us in [1, 2, 10, 100] {
+        let width = measure(&mut capture, &mut delay, |delay| {
+            output.set_high().unwrap();
+            delay.delay_us(us);
+            output.set_low().unwrap();
+        });
+        report("delay_us", us * cycles_per_us, width - baseline);
+    }
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
+/// Produce a single pulse with `pulse`, and return its width in core clock
+/// cycles
+fn measure(
+    capture: &mut TimerCounter<pac::Tc3>,
+    delay: &mut Delay,
+    pulse: impl FnOnce(&mut Delay),
+) -> i32 {
+    pulse(delay);
+    // Leave time for the falling edge to go through the EIC and EVSYS
+    delay.delay_us(10);
+    match capture.pulse_width() {
+        Some(width) => width as i32,
+        None => defmt::panic!("no pulse captured, is D1 connected to D0?"),
+    }
+}
+
+fn report(name: &str, requested: u32, measured: i32) {
+    defmt::info!(
+        "{}: requested {} cycles, measured {}, error {}",
+        name,
+        requested,
+        measured,
+        measured - requested as i32
+    );
+}
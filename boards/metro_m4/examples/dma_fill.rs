@@ -0,0 +1,70 @@
+//! Clear a framebuffer with a DMA fill, and compare it to a CPU loop.
+//!
+//! The same 16 KiB buffer of `u32` is filled with a volatile write loop, with
+//! `slice::fill`, and with `dmac::fill`. The cycle counts, measured with the
+//! DWT cycle counter, are logged over RTT.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m_rt::entry;
+use hal::clock::GenericClockController;
+use hal::dmac::{self, DmaController, PriorityLevel};
+use hal::dwt::CycleCounter;
+use metro_m4 as bsp;
+
+const LEN: usize = 4096;
+const COLOR: u32 = 0x00ff_8000;
+
+static mut FRAMEBUFFER: [u32; LEN] = [0; LEN];
+static mut FILL_VALUE: u32 = COLOR;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+
+    let _clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let mut dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+    let channels = dmac.split();
+    let channel = channels.0.init(PriorityLevel::Lvl0);
+
+    let counter = CycleCounter::new(&mut core.DCB, core.DWT);
+    // SAFETY: FRAMEBUFFER and FILL_VALUE are only ever accessed here
+    let framebuffer = unsafe { &mut *core::ptr::addr_of_mut!(FRAMEBUFFER) };
+    let value = unsafe { &mut *core::ptr::addr_of_mut!(FILL_VALUE) };
+
+    let cycles = counter.measure(|| {
+        for pixel in framebuffer.iter_mut() {
+            // Keep the compiler from optimizing the loop
+            unsafe { core::ptr::write_volatile(pixel, COLOR) };
+        }
+    });
+    defmt::info!("volatile loop: {} cycles", cycles);
+
+    let cycles = counter.measure(|| framebuffer.fill(COLOR));
+    defmt::info!("slice::fill: {} cycles", cycles);
+
+    let mut result = None;
+    let cycles = counter.measure(|| result = Some(dmac::fill(channel, value, framebuffer).wait()));
+    defmt::info!("dmac::fill: {} cycles", cycles);
+
+    let (_channel, _value, framebuffer) = result.unwrap();
+    assert!(framebuffer.iter().all(|&pixel| pixel == COLOR));
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -0,0 +1,65 @@
+//! Measure the speed of a PC fan from its tachometer output.
+//!
+//! Most fans pull their tachometer line low twice per revolution. Connect the
+//! tachometer wire to D0 (the fan ground to GND); the internal pull-up is
+//! enough for most fans. The EIC interrupt counts the falling edges in the
+//! background, and the RPM is logged over RTT once per second.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m_rt::entry;
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::eic::{Eic, Sense};
+use hal::gpio::{Pin, PullUpInterrupt};
+use metro_m4 as bsp;
+
+/// Tachometer pulses per fan revolution
+const PULSES_PER_REV: u32 = 2;
+
+atsamd_hal::bind_interrupts!(struct Irqs {
+    EIC_EXTINT_7 => atsamd_hal::eic::EdgeCounterHandler;
+});
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let _internal_clock = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Osculp32k, false)
+        .unwrap();
+    let gclk2 = clocks.get_gclk(ClockGenId::Gclk2).unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    let tach: Pin<_, PullUpInterrupt> = pins.d0.into();
+    let mut tach = eic_channels.7.with_pin(tach);
+    // Filter out the ringing on long fan wires
+    tach.filter(true);
+    let mut counter = tach.into_edge_counter(Sense::Fall, Irqs);
+
+    loop {
+        delay.delay_ms(1000);
+        let pulses = counter.reset();
+        defmt::info!("{} RPM", pulses * 60 / PULSES_PER_REV);
+    }
+}
@@ -0,0 +1,64 @@
+//! Count in binary on a bar of eight LEDs connected to D2 to D9.
+//!
+//! The header pins are taken as an array of `DynPin`s with a single
+//! `pin_alias!` call, so the bar can be driven with a loop. The onboard red LED
+//! and a ninth "carry" LED on D10 are taken as a tuple of typed pins: the red
+//! LED blinks at every step, and the carry LED toggles when the count wraps
+//! around.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::{hal, pac, pin_alias};
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::entry;
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, StatefulOutputPin};
+use hal::gpio::DynPin;
+use pac::{CorePeripherals, Peripherals};
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let (red_led, carry) = pin_alias!(pins.(red_led, d10));
+    let mut red_led: bsp::RedLed = red_led.into();
+    let mut carry = carry.into_push_pull_output();
+
+    let mut bar: [DynPin; 8] = pin_alias!(pins.[d2, d3, d4, d5, d6, d7, d8, d9]);
+    for led in bar.iter_mut() {
+        led.into_push_pull_output();
+    }
+
+    let mut count: u8 = 0;
+    loop {
+        for (bit, led) in bar.iter_mut().enumerate() {
+            led.set_state((count & (1 << bit) != 0).into()).unwrap();
+        }
+        red_led.toggle().unwrap();
+        count = count.wrapping_add(1);
+        if count == 0 {
+            carry.toggle().unwrap();
+        }
+        delay.delay_ms(250);
+    }
+}
@@ -0,0 +1,93 @@
+//! Self-test the UART and SPI peripherals using internal loopback.
+//!
+//! Both SERCOMs are switched to loopback mode, so no wiring is needed: every
+//! byte sent on the UART TX pin and on the SPI MOSI pin is received back
+//! internally, and compared against the byte that was sent. The red LED turns
+//! on steadily when both round trips succeed, and blinks quickly otherwise.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, StatefulOutputPin};
+use hal::ehal::spi::SpiBus;
+use hal::ehal_nb::serial::{Read, Write};
+use hal::fugit::RateExtU32;
+use hal::nb;
+use hal::pac::{CorePeripherals, Peripherals};
+
+const PATTERN: [u8; 4] = [0x00, 0x5A, 0xA5, 0xFF];
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    let (uart_rx, uart_tx) = pin_alias!(pins.(uart_rx, uart_tx));
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+    let mut uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    );
+    uart.reconfigure(|c| c.set_loopback(true));
+
+    let (sclk, mosi, miso) = pin_alias!(pins.(sclk, mosi, miso));
+    let spi_sercom = periph_alias!(peripherals.spi_sercom);
+    let mut spi = bsp::spi_master(
+        &mut clocks,
+        1.MHz(),
+        spi_sercom,
+        &mut peripherals.mclk,
+        sclk,
+        mosi,
+        miso,
+    );
+    spi.reconfigure(|c| c.set_loopback(true));
+
+    let uart_ok = PATTERN.iter().all(|&byte| {
+        nb::block!(uart.write(byte)).unwrap();
+        nb::block!(uart.read()) == Ok(byte)
+    });
+
+    let mut buf = PATTERN;
+    let spi_ok = spi.transfer_in_place(&mut buf).is_ok() && buf == PATTERN;
+
+    if uart_ok && spi_ok {
+        red_led.set_high().unwrap();
+        loop {
+            cortex_m::asm::wfi();
+        }
+    }
+
+    loop {
+        red_led.toggle().unwrap();
+        delay.delay_ms(100);
+    }
+}
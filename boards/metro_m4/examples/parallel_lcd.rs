@@ -0,0 +1,109 @@
+//! Drive an HD44780 character LCD through its 8-bit parallel interface.
+//!
+//! The data lines DB0 to DB7 are connected to D2 to D9, which are spread
+//! across port groups A and B. They are driven together by an
+//! [`OutputBus`](hal::gpio::OutputBus). RS is connected to D10, E to D11, and
+//! R/W is tied to ground, since the display is only written to.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::entry;
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::OutputPin;
+use hal::gpio::{OutputBus, Pin, PushPullOutput};
+use pac::{CorePeripherals, Peripherals};
+
+/// 8-bit interface, two lines, 5x8 font
+const FUNCTION_SET: u8 = 0x38;
+/// Display on, cursor off
+const DISPLAY_ON: u8 = 0x0C;
+/// Clear the display, and move the cursor home
+const CLEAR: u8 = 0x01;
+/// Move the cursor right after each character
+const ENTRY_MODE: u8 = 0x06;
+
+struct Lcd<Rs, E> {
+    data: OutputBus<8>,
+    rs: Rs,
+    e: E,
+}
+
+impl<Rs: OutputPin, E: OutputPin> Lcd<Rs, E> {
+    fn write(&mut self, byte: u8, is_data: bool, delay: &mut Delay) {
+        self.rs.set_state(is_data.into()).ok();
+        self.data.write(byte as u32);
+        // The display latches the data on the falling edge of E
+        self.e.set_high().ok();
+        delay.delay_us(1);
+        self.e.set_low().ok();
+        // Most instructions take 37 us to execute
+        delay.delay_us(50);
+    }
+
+    fn command(&mut self, command: u8, delay: &mut Delay) {
+        self.write(command, false, delay);
+    }
+
+    fn print(&mut self, text: &str, delay: &mut Delay) {
+        for byte in text.bytes() {
+            self.write(byte, true, delay);
+        }
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let data = OutputBus::new([
+        pins.d2.into(),
+        pins.d3.into(),
+        pins.d4.into(),
+        pins.d5.into(),
+        pins.d6.into(),
+        pins.d7.into(),
+        pins.d8.into(),
+        pins.d9.into(),
+    ]);
+    let rs: Pin<_, PushPullOutput> = pins.d10.into();
+    let e: Pin<_, PushPullOutput> = pins.d11.into();
+    let mut lcd = Lcd { data, rs, e };
+
+    // Wait for the display to power up, then initialize it
+    delay.delay_ms(50);
+    lcd.command(FUNCTION_SET, &mut delay);
+    lcd.command(DISPLAY_ON, &mut delay);
+    lcd.command(CLEAR, &mut delay);
+    // Clearing the display takes 1.52 ms
+    delay.delay_ms(2);
+    lcd.command(ENTRY_MODE, &mut delay);
+
+    lcd.print("Hello, parallel!", &mut delay);
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -0,0 +1,72 @@
+//! Generate a complementary pair of PWM outputs with dead-time, as used to
+//! drive a half-bridge.
+//!
+//! TCC0 channel 0 runs a 20 kHz, 50% duty cycle PWM. With dead-time insertion
+//! enabled, D7 (`TCC0/WO0`) carries the low side output and D3 (`TCC0/WO4`)
+//! its complement, the high side output. Every edge is delayed by 1 µs, so
+//! the two outputs are never high at the same time.
+//!
+//! Dead-time insertion is toggled every two seconds. Probe D7 and D3 on a
+//! scope: while it is disabled, D3 goes back to following compare channel 4,
+//! which is left at its reset value.
+
+#![no_std]
+#![no_main]
+
+use cortex_m_rt::entry;
+use panic_halt as _;
+
+use bsp::hal;
+use bsp::pac;
+use metro_m4 as bsp;
+
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::gpio::G;
+use hal::prelude::*;
+use hal::pwm::{Channel, DeadTime, TCC0Pinout, Tcc0Pwm};
+use pac::{CorePeripherals, Peripherals};
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let pins = bsp::Pins::new(peripherals.port);
+
+    // The pinout only takes the low side pin, the high side pin must be muxed
+    // to TCC0 manually
+    let low_side = pins.d7.into_alternate::<G>();
+    let _high_side = pins.d3.into_alternate::<G>();
+
+    let gclk0 = clocks.gclk0();
+    let tcc0_clock = clocks.tcc0_tcc1(&gclk0).unwrap();
+    // Dead-time is counted in cycles of the TCC clock
+    let cycles_per_us = (tcc0_clock.freq().to_Hz() / 1_000_000) as u8;
+
+    let mut pwm = Tcc0Pwm::new(
+        &tcc0_clock,
+        20.kHz(),
+        peripherals.tcc0,
+        TCC0Pinout::Pb12(low_side),
+        &mut peripherals.mclk,
+    );
+    let max_duty = pwm.get_max_duty();
+    pwm.set_duty(Channel::_0, max_duty / 2);
+    pwm.set_dead_time(DeadTime::symmetric(cycles_per_us));
+
+    loop {
+        pwm.enable_dead_time(Channel::_0).unwrap();
+        delay.delay_ms(2000u16);
+        pwm.disable_dead_time(Channel::_0).unwrap();
+        delay.delay_ms(2000u16);
+    }
+}
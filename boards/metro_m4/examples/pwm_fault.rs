@@ -0,0 +1,108 @@
+//! Cut off a PWM output in hardware when a fault input is asserted.
+//!
+//! TCC0 drives a 20 kHz PWM on D7 (`TCC0/WO0`). A button on D0, standing in
+//! for an overcurrent comparator, is routed through the EIC and the event
+//! system to TCC0's `EV0` input, which is configured as a non-recoverable
+//! fault. Pressing the button forces D7 low immediately, without any CPU
+//! involvement.
+//!
+//! The fault is latched: the red LED (D13) lights up while it is. The firmware
+//! tries to clear it once per second, which only succeeds after the button
+//! has been released, and restarts the PWM.
+
+#![no_std]
+#![no_main]
+
+use cortex_m_rt::entry;
+use panic_halt as _;
+
+use bsp::hal;
+use bsp::pac;
+use bsp::pin_alias;
+use metro_m4 as bsp;
+
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::delay::Delay;
+use hal::eic::{Eic, Sense};
+use hal::gpio::{Pin, PullUpInterrupt, G};
+use hal::prelude::*;
+use hal::pwm::{Channel, FaultInput, SafeState, TCC0Pinout, Tcc0Pwm};
+use pac::{CorePeripherals, Peripherals};
+
+/// Event generator ID of `EIC/EXTINT7`
+const EVGEN_EIC_EXTINT_7: u8 = 0x12 + 7;
+
+/// Event user ID of `TCC0/EV0`
+const USER_TCC0_EV0: usize = 16;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    // Configure a clock for the EIC peripheral
+    let _internal_clock = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Osculp32k, false)
+        .unwrap();
+    let gclk2 = clocks.get_gclk(ClockGenId::Gclk2).unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // The event is active for as long as the button is held down
+    let button: Pin<_, PullUpInterrupt> = pins.d0.into();
+    let mut extint = eic_channels.7.with_pin(button);
+    extint.sense(Sense::Low);
+    extint.enable_event();
+
+    // Route EXTINT7 to TCC0/EV0 through event channel 0. The asynchronous path
+    // does not require a clock, so the cutoff is as fast as possible.
+    peripherals
+        .mclk
+        .apbbmask()
+        .modify(|_, w| w.evsys_().set_bit());
+    let evsys = peripherals.evsys;
+    // USER.CHANNEL holds the event channel number plus one
+    evsys
+        .user(USER_TCC0_EV0)
+        .write(|w| unsafe { w.channel().bits(1) });
+    evsys.channels(0).channel().write(|w| unsafe {
+        w.evgen().bits(EVGEN_EIC_EXTINT_7);
+        w.path().asynchronous()
+    });
+
+    let gclk0 = clocks.gclk0();
+    let mut pwm = Tcc0Pwm::new(
+        &clocks.tcc0_tcc1(&gclk0).unwrap(),
+        20.kHz(),
+        peripherals.tcc0,
+        TCC0Pinout::Pb12(pins.d7.into_alternate::<G>()),
+        &mut peripherals.mclk,
+    );
+    let max_duty = pwm.get_max_duty();
+    pwm.set_duty(Channel::_0, max_duty / 2);
+    pwm.on_fault(FaultInput::Ev0, SafeState::new().low(0));
+
+    loop {
+        if pwm.is_fault(FaultInput::Ev0) {
+            red_led.set_high().unwrap();
+            delay.delay_ms(1000u16);
+            // Clearing has no effect while the button is still pressed
+            pwm.clear_fault(FaultInput::Ev0);
+        } else {
+            red_led.set_low().unwrap();
+            delay.delay_ms(10u16);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Change the PWM duty cycle without glitching the output.
+//!
+//! TCC0 runs a slow 100 Hz PWM on D7 (`TCC0/WO0`), and its duty cycle is
+//! toggled between 10% and 90% at an arbitrary point in the period. The new
+//! duty cycle is written to the buffer register, and only takes effect at the
+//! start of the next period. On a scope triggered on D7, every period is
+//! either 1 ms or 9 ms high, never a truncated or stretched pulse.
+//!
+//! Hold D0 low to call `force_update` after each change instead. The duty
+//! cycle then applies mid-period, and the occasional truncated or stretched
+//! pulse shows up on the scope.
+
+#![no_std]
+#![no_main]
+
+use cortex_m_rt::entry;
+use panic_halt as _;
+
+use bsp::hal;
+use bsp::pac;
+use metro_m4 as bsp;
+
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::gpio::G;
+use hal::prelude::*;
+use hal::pwm::{Channel, TCC0Pinout, Tcc0Pwm};
+use pac::{CorePeripherals, Peripherals};
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let pins = bsp::Pins::new(peripherals.port);
+    let force = pins.d0.into_pull_up_input();
+
+    let gclk0 = clocks.gclk0();
+    let tcc0_clock = clocks.tcc0_tcc1(&gclk0).unwrap();
+    let mut pwm = Tcc0Pwm::new(
+        &tcc0_clock,
+        100.Hz(),
+        peripherals.tcc0,
+        TCC0Pinout::Pb12(pins.d7.into_alternate::<G>()),
+        &mut peripherals.mclk,
+    );
+    let max_duty = pwm.get_max_duty();
+
+    let mut high = false;
+    loop {
+        high = !high;
+        let duty = if high {
+            max_duty / 10 * 9
+        } else {
+            max_duty / 10
+        };
+        pwm.set_duty(Channel::_0, duty);
+        if force.is_low().unwrap() {
+            pwm.force_update();
+        }
+        // Not a multiple of the 10 ms period, so the change lands at a
+        // different point of the period every time
+        delay.delay_ms(37u16);
+    }
+}
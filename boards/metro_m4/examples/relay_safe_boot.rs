@@ -0,0 +1,70 @@
+//! Keep a relay and a high-side switch off while the board boots.
+//!
+//! Every pin is a floating input after reset. On a relay board, a floating
+//! driver input could briefly energize the relay before the application gets
+//! to configure the pin. This example drives the control pins to their safe
+//! level with `Pins::new_with_safe_outputs`, before configuring the clocks,
+//! then toggles the relay every two seconds.
+//!
+//! It assumes:
+//!
+//! * A relay driver on pin D5, energized when the pin is high
+//! * A high-side switch on pin D6, with an active-low enable
+//!
+//! Each control line should also have an external pull resistor towards its
+//! safe level (a pull-down on D5, and a pull-up on D6), to hold it during the
+//! reset and until `main` runs.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::entry;
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, PinState, StatefulOutputPin};
+use hal::gpio::{PinId, PB14, PB15};
+use pac::{CorePeripherals, Peripherals};
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+
+    // Do this first, so the control lines float for as short as possible
+    let pins = bsp::Pins::new_with_safe_outputs(
+        peripherals.port,
+        &[(PB14::DYN, PinState::Low), (PB15::DYN, PinState::High)],
+    );
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    // These conversions keep driving the safe levels
+    let mut relay = pins.d5.into_push_pull_output();
+    let mut switch_enable = pins.d6.into_push_pull_output();
+
+    // Enable the high-side switch once the rest of the system is ready
+    switch_enable.set_low().unwrap();
+
+    loop {
+        delay.delay_ms(2000);
+        relay.toggle().unwrap();
+    }
+}
@@ -0,0 +1,200 @@
+//! Compare byte-by-byte and 32-bit SPI transfers while reading consecutive
+//! SD card sectors.
+//!
+//! Wire an SD card breakout (or a datalogger shield) to the 2x3 SPI header,
+//! with its chip select on D10. After initializing the card, the example reads
+//! the same run of consecutive sectors twice with `READ_MULTIPLE_BLOCK`:
+//! once using [`SpiBus::read`], which accesses the `DATA` register once per
+//! byte, and once using [`Spi::read_32bit`], which uses the hardware `LENGTH`
+//! counter to move four bytes per access. The elapsed CPU cycles and the
+//! resulting throughput of both runs are logged over RTT.
+//!
+//! [`Spi::read_32bit`]: hal::sercom::spi::Spi::read_32bit
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m_rt::entry;
+use hal::clock::GenericClockController;
+use hal::dwt::CycleCounter;
+use hal::ehal::digital::OutputPin;
+use hal::ehal::spi::SpiBus;
+use hal::fugit::Hertz;
+use hal::fugit::RateExtU32;
+use metro_m4 as bsp;
+
+/// Length of an SD card sector, in bytes
+const SECTOR_LEN: usize = 512;
+
+/// Number of consecutive sectors read by each run
+const SECTORS: u32 = 64;
+
+/// Token sent by the card before the contents of each data block
+const DATA_START_BLOCK: u8 = 0xfe;
+
+/// R1 response bit indicating the card is still initializing
+const R1_IDLE_STATE: u8 = 0x01;
+
+/// SPI clock used once the card is initialized
+const FAST_BAUD_MHZ: u32 = 12;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let cpu_freq: Hertz<u32> = clocks.gclk0().into();
+    let cpu_freq = cpu_freq.to_Hz();
+
+    let counter = CycleCounter::new(&mut core.DCB, core.DWT);
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut cs = pins.d10.into_push_pull_output();
+    cs.set_high().unwrap();
+
+    // SD cards must be initialized at 400 kHz or less, and expect MOSI to be
+    // held high while they are sending data.
+    let spi_sercom = bsp::periph_alias!(peripherals.spi_sercom);
+    let mut spi = bsp::spi_master(
+        &mut clocks,
+        400.kHz(),
+        spi_sercom,
+        &mut peripherals.mclk,
+        pins.sclk,
+        pins.mosi,
+        pins.miso,
+    );
+    spi.reconfigure(|c| c.set_nop_word(0xff));
+
+    // At least 74 clock cycles with CS deasserted put the card in native mode
+    spi.write(&[0xff; 10]).unwrap();
+    cs.set_low().unwrap();
+
+    // CMD0: GO_IDLE_STATE, then CMD8: SEND_IF_COND
+    command(&mut spi, 0, 0, 0x95);
+    command(&mut spi, 8, 0x1aa, 0x87);
+    let mut r7 = [0; 4];
+    spi.read(&mut r7).unwrap();
+
+    // ACMD41: SD_SEND_OP_COND with HCS set, until the card leaves idle state
+    loop {
+        command(&mut spi, 55, 0, 0x01);
+        if command(&mut spi, 41, 0x4000_0000, 0x01) & R1_IDLE_STATE == 0 {
+            break;
+        }
+    }
+
+    // Sector 0 has the same address with both byte and block addressing
+    let start = 0;
+
+    spi.reconfigure(|c| c.set_baud(FAST_BAUD_MHZ.MHz()));
+    defmt::info!("Reading {} sectors at {} MHz SCK", SECTORS, FAST_BAUD_MHZ);
+
+    let mut sector = [0; SECTOR_LEN];
+
+    let cycles = read_sectors(
+        &counter,
+        &mut spi,
+        start,
+        &mut |spi, buf| spi.read(buf).unwrap(),
+        &mut sector,
+    );
+    report("byte-by-byte", cycles, cpu_freq);
+
+    let cycles = read_sectors(
+        &counter,
+        &mut spi,
+        start,
+        &mut |spi, buf| spi.read_32bit(buf).unwrap(),
+        &mut sector,
+    );
+    report("32-bit", cycles, cpu_freq);
+
+    cs.set_high().unwrap();
+    spi.write(&[0xff]).unwrap();
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
+/// Read [`SECTORS`] consecutive sectors starting at address `start`, using
+/// `read` to receive the contents of each block, and return the number of CPU
+/// cycles elapsed.
+fn read_sectors(
+    counter: &CycleCounter,
+    spi: &mut bsp::Spi,
+    start: u32,
+    read: &mut impl FnMut(&mut bsp::Spi, &mut [u8]),
+    sector: &mut [u8; SECTOR_LEN],
+) -> u32 {
+    let begin = counter.cycle_count();
+
+    // CMD18: READ_MULTIPLE_BLOCK
+    command(spi, 18, start, 0x01);
+    for _ in 0..SECTORS {
+        let mut token = [0xff];
+        while token[0] == 0xff {
+            spi.read(&mut token).unwrap();
+        }
+        if token[0] != DATA_START_BLOCK {
+            defmt::panic!("Read failed with error token {:#x}", token[0]);
+        }
+        read(spi, sector);
+        // Discard the data block's CRC
+        let mut crc = [0; 2];
+        spi.read(&mut crc).unwrap();
+    }
+
+    // CMD12: STOP_TRANSMISSION, then wait for the card to release busy
+    command(spi, 12, 0, 0x01);
+    let mut busy = [0];
+    while busy[0] != 0xff {
+        spi.read(&mut busy).unwrap();
+    }
+
+    counter.elapsed(begin)
+}
+
+fn report(name: &str, cycles: u32, cpu_freq: u32) {
+    let bytes = (SECTORS as u64) * SECTOR_LEN as u64;
+    let micros = cycles as u64 * 1_000_000 / cpu_freq as u64;
+    let kib_per_sec = bytes * 1_000_000 / 1024 / micros.max(1);
+    defmt::info!(
+        "{}: {} cycles, {} us, {} KiB/s",
+        name,
+        cycles,
+        micros,
+        kib_per_sec
+    );
+}
+
+/// Send a command frame and return the card's R1 response.
+fn command(spi: &mut bsp::Spi, index: u8, arg: u32, crc: u8) -> u8 {
+    let arg = arg.to_be_bytes();
+    let frame = [0x40 | index, arg[0], arg[1], arg[2], arg[3], crc];
+    spi.write(&frame).unwrap();
+
+    // The response arrives within 8 bytes, and always has its MSB cleared.
+    // CMD12 is followed by a stuff byte, which is skipped the same way.
+    let mut r1 = [0xff];
+    for _ in 0..8 {
+        spi.read(&mut r1).unwrap();
+        if r1[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    r1[0]
+}
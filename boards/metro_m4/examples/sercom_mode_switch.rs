@@ -0,0 +1,82 @@
+//! Switch SERCOM3 between UART and SPI at runtime.
+//!
+//! The labelled RX and TX pins are first used as a UART. The UART is then
+//! disabled and freed, which returns the SERCOM3 PAC struct along with the
+//! pads. They are reused to build a transmit-only SPI, with TX as data out
+//! (pad 0) and RX as clock (pad 1). Finally, the SPI is torn down the same way,
+//! and the UART is set up again. No `steal()` is needed along the way.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, hal, pac, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::ehal::spi::SpiBus;
+use hal::prelude::*;
+use hal::sercom::{spi, uart};
+use hal::time::Hertz;
+use metro_m4 as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let gclk0 = clocks.gclk0();
+    let freq: Hertz = gclk0.into();
+    let mut mclk = peripherals.mclk;
+
+    // Start as a UART. This also sets up the SERCOM3 core clock.
+    let mut uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        periph_alias!(peripherals.uart_sercom),
+        &mut mclk,
+        pin_alias!(pins.uart_rx),
+        pin_alias!(pins.uart_tx),
+    );
+    uart.write_all_blocking(b"UART mode\r\n").unwrap();
+
+    // Uart -> Config -> (Sercom, Pads) -> pins
+    let (sercom, pads) = uart.disable().free();
+    let (rx, tx, _, _) = pads.free();
+
+    // Reuse the SERCOM and pins as a transmit-only SPI
+    let pads = spi::Pads::default().data_out(tx).sclk(rx);
+    let mut spi = spi::Config::new(&mclk, sercom, pads, freq)
+        .baud(1.MHz())
+        .spi_mode(spi::MODE_0)
+        .enable()
+        .into_panic_on_read();
+    spi.write(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+    spi.flush().unwrap();
+
+    // Spi -> Config -> (Sercom, Pads) -> pins, and back to a UART
+    let spi: spi::Spi<_, spi::Tx> = spi.into();
+    let (sercom, pads) = spi.disable().free();
+    let (_, tx, rx, _) = pads.free();
+    let pads = uart::Pads::default().rx(rx).tx(tx);
+    let mut uart = uart::Config::new(&mclk, sercom, pads, freq)
+        .baud(
+            115200.Hz(),
+            uart::BaudMode::Fractional(uart::Oversampling::Bits16),
+        )
+        .enable();
+    uart.write_all_blocking(b"UART mode again\r\n").unwrap();
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -14,9 +14,7 @@ use bsp::{entry, periph_alias, pin_alias};
 use hal::clock::GenericClockController;
 use hal::delay::Delay;
 use hal::ehal::delay::DelayNs;
-use hal::ehal_nb::serial::Write;
 use hal::fugit::RateExtU32;
-use hal::nb;
 use hal::pac::{CorePeripherals, Peripherals};
 
 #[entry]
@@ -47,11 +45,9 @@ fn main() -> ! {
     );
 
     loop {
-        for byte in b"Hello, world!" {
-            // NOTE `block!` blocks until `uart.write()` completes and returns
-            // `Result<(), Error>`
-            nb::block!(uart.write(*byte)).unwrap();
-        }
+        // NOTE `write_all_blocking` blocks until every byte has been queued
+        // for transmission
+        uart.write_all_blocking(b"Hello, world!").unwrap();
         delay.delay_ms(1000);
     }
 }
@@ -0,0 +1,80 @@
+//! Clock three SERCOMs from a single clock generator.
+//!
+//! GCLK2 is configured once, at 48 MHz from the DFLL, and then only borrowed
+//! to clock the UART, SPI and I2C SERCOMs. Each SERCOM derives its own baud
+//! rate from the shared 48 MHz, which is high enough for all of them.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::ehal::i2c::I2c;
+use hal::ehal::spi::SpiBus;
+use hal::fugit::RateExtU32;
+use pac::Peripherals;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let gclk2 = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Dfll, false)
+        .unwrap();
+
+    let mut uart = bsp::uart_with_gclk(
+        &mut clocks,
+        &gclk2,
+        115200.Hz(),
+        periph_alias!(peripherals.uart_sercom),
+        &mut peripherals.mclk,
+        pin_alias!(pins.uart_rx),
+        pin_alias!(pins.uart_tx),
+    );
+    let mut spi = bsp::spi_master_with_gclk(
+        &mut clocks,
+        &gclk2,
+        1.MHz(),
+        periph_alias!(peripherals.spi_sercom),
+        &mut peripherals.mclk,
+        pins.sclk,
+        pins.mosi,
+        pins.miso,
+    );
+    let mut i2c = bsp::i2c_master_with_gclk(
+        &mut clocks,
+        &gclk2,
+        100.kHz(),
+        periph_alias!(peripherals.i2c_sercom),
+        &mut peripherals.mclk,
+        pins.sda,
+        pins.scl,
+    );
+
+    uart.write_all_blocking(b"Hello from GCLK2\r\n").unwrap();
+    spi.write(&[0x01, 0x02, 0x03]).unwrap();
+    // Ignore the error if nothing is connected to the I2C bus
+    let _ = i2c.write(0x76, &[0x00]);
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -0,0 +1,69 @@
+//! Read a MAX1241 ADC, which needs a long chip select setup time.
+//!
+//! The MAX1241 starts a conversion on the falling edge of its chip select, and
+//! its result can only be clocked out once the conversion is complete, up to
+//! 9µs later. A `CsTimingDevice` inserts this delay after asserting the chip
+//! select on pin D10, so the driver code only has to read the result.
+//!
+//! Connect the SCK, MISO and D10 pins to the SCLK, DOUT and CS pins of the
+//! MAX1241. The conversion result is logged over RTT every 100ms.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::spi::SpiDevice;
+use hal::fugit::{ExtU32, RateExtU32};
+use metro_m4 as bsp;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let core = pac::CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let (sclk, mosi, miso) = pin_alias!(pins.(sclk, mosi, miso));
+    let spi_sercom = periph_alias!(peripherals.spi_sercom);
+    let spi = bsp::spi_master(
+        &mut clocks,
+        1.MHz(),
+        spi_sercom,
+        &mut peripherals.mclk,
+        sclk,
+        mosi,
+        miso,
+    );
+
+    let cs = pins.d10.into_push_pull_output();
+    let delay = Delay::new(core.SYST, &mut clocks);
+    let mut adc = spi
+        .with_cs_timing(cs, delay, 10.micros(), 1.micros())
+        .unwrap();
+
+    loop {
+        // The end of conversion flag is clocked out first, followed by the
+        // 12-bit result, MSB first
+        let mut sample = [0; 2];
+        match adc.read(&mut sample) {
+            Ok(()) => defmt::info!("MAX1241: {}", (u16::from_be_bytes(sample) >> 3) & 0xFFF),
+            Err(_) => defmt::warn!("SPI error"),
+        }
+
+        adc.delay_mut().delay_ms(100);
+    }
+}
@@ -0,0 +1,96 @@
+//! Compare SPI DMA with two channels and with a single shared channel.
+//!
+//! A typical SD card block read is emulated: a 6 byte command is written,
+//! then a 512 byte response is read, in one transaction. A 4 KiB write, as
+//! used to refresh a display, is also measured. Both are run with separate RX
+//! and TX channels, and then with only a TX channel, where reads fall back to
+//! word-by-word transfers. The cycle counts are logged over RTT.
+//!
+//! No device needs to be connected, since only the timing is of interest.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m_rt::entry;
+use hal::clock::GenericClockController;
+use hal::dmac::{DmaController, PriorityLevel};
+use hal::dwt::CycleCounter;
+use hal::ehal::spi::SpiBus;
+use hal::fugit::RateExtU32;
+use metro_m4 as bsp;
+
+const COMMAND: [u8; 6] = [0x51, 0x00, 0x00, 0x00, 0x00, 0xff];
+const DISPLAY_LEN: usize = 4096;
+
+static FRAME: [u8; DISPLAY_LEN] = [0xa5; DISPLAY_LEN];
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let mut dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+    let channels = dmac.split();
+    let chan0 = channels.0.init(PriorityLevel::Lvl0);
+    let chan1 = channels.1.init(PriorityLevel::Lvl0);
+
+    let counter = CycleCounter::new(&mut core.DCB, core.DWT);
+    let mut response = [0u8; 512];
+
+    let mut spi = bsp::spi_master(
+        &mut clocks,
+        12.MHz(),
+        peripherals.sercom2,
+        &mut peripherals.mclk,
+        pins.sclk,
+        pins.mosi,
+        pins.miso,
+    )
+    .with_dma_channels(chan0, chan1);
+
+    let cycles = counter.measure(|| {
+        spi.write(&COMMAND).unwrap();
+        spi.read(&mut response).unwrap();
+    });
+    defmt::info!("two channels, command + 512 byte read: {} cycles", cycles);
+
+    let cycles = counter.measure(|| {
+        spi.write(&FRAME).unwrap();
+        spi.flush().unwrap();
+    });
+    defmt::info!("two channels, 4 KiB write: {} cycles", cycles);
+
+    // Give the RX channel back, and keep only the TX channel
+    let (spi, chan0, _chan1) = spi.take_dma_channels();
+    let mut spi = spi.with_tx_channel(chan0);
+
+    let cycles = counter.measure(|| {
+        spi.write(&COMMAND).unwrap();
+        spi.read(&mut response).unwrap();
+    });
+    defmt::info!("one channel, command + 512 byte read: {} cycles", cycles);
+
+    let cycles = counter.measure(|| {
+        spi.write(&FRAME).unwrap();
+        spi.flush().unwrap();
+    });
+    defmt::info!("one channel, 4 KiB write: {} cycles", cycles);
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -0,0 +1,121 @@
+//! Count pulses on a pin in hardware with a timer in event counter mode.
+//!
+//! TC3 is set up to count events instead of clock ticks. As a self test, a
+//! known number of software events is first fed to it through event channel
+//! 0, and the count is checked. The channel is then switched to the EIC, so
+//! that every falling edge on D0 is counted without any CPU involvement. The
+//! number of pulses per second is printed over semihosting.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use cortex_m_semihosting::hprintln;
+
+use bsp::entry;
+use hal::clock::{ClockGenId, ClockSource, GenericClockController};
+use hal::delay::Delay;
+use hal::eic::{Eic, Sense};
+use hal::gpio::{Pin, PullUpInterrupt};
+use hal::prelude::*;
+use hal::timer::TimerCounter;
+use pac::{CorePeripherals, Peripherals};
+
+/// Event generator ID of `EIC/EXTINT7`
+const EVGEN_EIC_EXTINT_7: u8 = 0x12 + 7;
+
+/// Event user ID of `TC3/EVU`
+const USER_TC3_EVU: usize = 46;
+
+/// Number of software events fed to the counter in the self test
+const SELF_TEST_EVENTS: u16 = 1000;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let gclk0 = clocks.gclk0();
+    let tc2_3 = clocks.tc2_tc3(&gclk0).unwrap();
+    // Software events need a clock for the event channel
+    let _evsys0 = clocks.evsys0(&gclk0).unwrap();
+    let mut counter = TimerCounter::tc3_(&tc2_3, peripherals.tc3, &mut peripherals.mclk);
+    counter.start_event_counter();
+
+    peripherals
+        .mclk
+        .apbbmask()
+        .modify(|_, w| w.evsys_().set_bit());
+    let evsys = peripherals.evsys;
+    // USER.CHANNEL holds the event channel number plus one
+    evsys
+        .user(USER_TC3_EVU)
+        .write(|w| unsafe { w.channel().bits(1) });
+
+    // Self test: no generator, software events only
+    evsys.channels(0).channel().write(|w| {
+        w.path().synchronous();
+        w.edgsel().rising_edge()
+    });
+    for _ in 0..SELF_TEST_EVENTS {
+        evsys.swevt().write(|w| w.channel0().set_bit());
+        // Leave time for the event to propagate before firing the next one
+        cortex_m::asm::delay(16);
+    }
+    let counted = counter.count();
+    hprintln!(
+        "self test: sent {} events, counted {}",
+        SELF_TEST_EVENTS,
+        counted
+    )
+    .ok();
+    assert_eq!(counted, SELF_TEST_EVENTS);
+
+    // The EIC samples the pin with its own clock, which limits the pulse
+    // rate that can be detected. Clock it at 48 MHz rather than 32 kHz.
+    let gclk2 = clocks
+        .configure_gclk_divider_and_source(ClockGenId::Gclk2, 1, ClockSource::Dfll, false)
+        .unwrap();
+    let eic_clock = clocks.eic(&gclk2).unwrap();
+    let eic_channels = Eic::new(&mut peripherals.mclk, &eic_clock, peripherals.eic).split();
+
+    // Generate an event on every falling edge. The filter is left disabled,
+    // since it would further limit the pulse rate.
+    let input: Pin<_, PullUpInterrupt> = pins.d0.into();
+    let mut extint = eic_channels.7.with_pin(input);
+    extint.sense(Sense::Fall);
+    extint.enable_event();
+
+    // Route EXTINT7 to TC3 through the asynchronous path instead
+    evsys.channels(0).channel().write(|w| unsafe {
+        w.evgen().bits(EVGEN_EIC_EXTINT_7);
+        w.path().asynchronous()
+    });
+
+    let mut last = counter.count();
+    loop {
+        delay.delay_ms(1000u32);
+        let now = counter.count();
+        // The counter wraps around after 0xFFFF pulses
+        hprintln!("{} pulses/s", now.wrapping_sub(last)).ok();
+        last = now;
+    }
+}
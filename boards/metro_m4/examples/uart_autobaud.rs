@@ -0,0 +1,90 @@
+//! Detect the baud rate of the host from a `0x55` sync byte.
+//!
+//! Connect a USB-serial adapter to pins D0 (RX) and D1 (TX), open it at either
+//! 9600 or 115200 baud, and send `U` (`0x55`). The board times the edges of the
+//! character on the RX pin with the DWT cycle counter, selects the closest
+//! supported rate, and then echoes every received byte at that rate. The
+//! detection results are also logged over RTT.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::dwt::CycleCounter;
+use hal::ehal_nb::serial::{Read, Write};
+use hal::fugit::Hertz;
+use hal::nb;
+use hal::sercom::uart::{self, AutoBaudError};
+use metro_m4 as bsp;
+
+/// Baud rates the host may use
+const SUPPORTED: [u32; 2] = [9600, 115_200];
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let cpu_freq: Hertz<u32> = clocks.gclk0().into();
+    let counter = CycleCounter::new(&mut core.DCB, core.DWT);
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut rx = pin_alias!(pins.uart_rx).into_floating_input();
+
+    // Give up on a character if the line doesn't change for longer than a bit
+    // at 1200 baud
+    let timeout = cpu_freq.to_Hz() / 1200;
+
+    let baud = loop {
+        // Nothing must delay the sampling loop while the edges are captured
+        let edges = cortex_m::interrupt::free(|_| {
+            uart::capture_sync_edges(&mut rx, || counter.cycle_count(), timeout)
+        });
+        match edges.and_then(|edges| uart::measure_sync_byte(&edges, cpu_freq)) {
+            Ok(measured) => match closest_supported(measured) {
+                Ok(baud) => {
+                    defmt::info!("Measured {} baud, using {}", measured.to_Hz(), baud);
+                    break baud;
+                }
+                Err(e) => defmt::warn!("{}", defmt::Debug2Format(&e)),
+            },
+            Err(e) => defmt::warn!("No sync byte: {}", defmt::Debug2Format(&e)),
+        }
+    };
+
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+    let mut uart = bsp::uart(
+        &mut clocks,
+        Hertz::<u32>::from_raw(baud),
+        uart_sercom,
+        &mut peripherals.mclk,
+        rx,
+        pin_alias!(pins.uart_tx),
+    );
+
+    loop {
+        let byte = nb::block!(uart.read()).unwrap();
+        nb::block!(uart.write(byte)).unwrap();
+    }
+}
+
+/// Select the supported baud rate within 5% of `measured`
+fn closest_supported(measured: Hertz<u32>) -> Result<u32, AutoBaudError> {
+    let measured = measured.to_Hz();
+    SUPPORTED
+        .into_iter()
+        .find(|&baud| measured.abs_diff(baud) <= baud / 20)
+        .ok_or(AutoBaudError::OutOfRange(Hertz::<u32>::from_raw(measured)))
+}
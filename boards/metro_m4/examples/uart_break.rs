@@ -0,0 +1,64 @@
+//! Send a LIN-style header: a break followed by a `0x55` sync byte.
+//!
+//! Every 100 ms, the UART on pins D0 (RX) and D1 (TX) holds its TX line low
+//! for 13 bit times at 19200 baud, and then sends the sync byte. A logic
+//! analyzer on D1 shows the break, and a receiver at the same baud rate
+//! reports it as a framing error.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal_nb::serial::Write;
+use hal::fugit::RateExtU32;
+use hal::nb;
+use pac::{CorePeripherals, Peripherals};
+
+/// Length of a LIN break, in bit times
+const BREAK_BITS: u8 = 13;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut uart = bsp::uart(
+        &mut clocks,
+        19200.Hz(),
+        periph_alias!(peripherals.uart_sercom),
+        &mut peripherals.mclk,
+        pin_alias!(pins.uart_rx),
+        pin_alias!(pins.uart_tx),
+    );
+
+    loop {
+        // The baud rate is changed during the break, so nothing may be left in
+        // the transmitter
+        nb::block!(uart.flush()).unwrap();
+        uart.send_break(BREAK_BITS);
+        nb::block!(uart.write(0x55)).unwrap();
+        delay.delay_ms(100);
+    }
+}
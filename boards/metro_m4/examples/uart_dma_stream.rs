@@ -0,0 +1,76 @@
+//! Stream a buffer over the UART with DMA.
+//!
+//! A 256-byte buffer is sent over and over with `Uart::send_with_dma`. The
+//! DMA channel moves each byte to the `DATA` register of the SERCOM when it
+//! is ready for the next one, so the CPU only refills the buffer between
+//! transfers, and is free to do other work meanwhile.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::dmac::{DmaController, PriorityLevel};
+use hal::fugit::RateExtU32;
+use pac::Peripherals;
+
+const LENGTH: usize = 256;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let mut dmac = DmaController::init(peripherals.dmac, &mut peripherals.pm);
+    let channels = dmac.split();
+    let mut channel = channels.0.init(PriorityLevel::Lvl0);
+
+    let uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        periph_alias!(peripherals.uart_sercom),
+        &mut peripherals.mclk,
+        pin_alias!(pins.uart_rx),
+        pin_alias!(pins.uart_tx),
+    );
+    let (_rx, mut tx) = uart.split();
+
+    // DMA transfers that outlive a function call require a 'static buffer
+    let mut buffer: &'static mut [u8; LENGTH] =
+        cortex_m::singleton!(: [u8; LENGTH] = [0x00; LENGTH]).unwrap();
+
+    let mut frame: u8 = 0;
+    loop {
+        // Printable characters, shifted by one for every frame
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            *byte = b' ' + (i as u8).wrapping_add(frame) % 95;
+        }
+        buffer[LENGTH - 2] = b'\r';
+        buffer[LENGTH - 1] = b'\n';
+
+        let mut transfer = tx.send_with_dma(buffer, channel);
+        while !transfer.complete() {
+            // The CPU is free to do other work here
+        }
+        (channel, buffer, tx) = transfer.wait();
+
+        frame = frame.wrapping_add(1);
+    }
+}
@@ -0,0 +1,83 @@
+//! Self-test the UART receive error path using error injection.
+//!
+//! The UART is switched to the framing error loopback provided by the
+//! `error-injection` feature of the HAL, so no wiring is needed. Every byte
+//! sent is received back as a framing error, which is then cleared. The UART
+//! is finally returned to a regular loopback, to check that it recovered. The
+//! red LED turns on steadily when the test succeeds, and blinks quickly
+//! otherwise.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, StatefulOutputPin};
+use hal::fugit::RateExtU32;
+use hal::pac::{CorePeripherals, Peripherals};
+use hal::sercom::uart::Error;
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    let (uart_rx, uart_tx) = pin_alias!(pins.(uart_rx, uart_tx));
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+    let mut uart = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    );
+
+    // Inject a framing error, and check that the application sees it
+    uart.reconfigure(|c| c.set_frame_error_loopback(true));
+    uart.write_byte(0x00).unwrap();
+    let error_seen = uart.read_byte() == Err(Error::FrameError);
+    uart.reset_rx();
+
+    // Check that the UART recovered
+    uart.reconfigure(|c| {
+        c.set_frame_error_loopback(false);
+        c.set_loopback(true);
+    });
+    uart.write_byte(0x5A).unwrap();
+    let recovered = uart.read_byte() == Ok(0x5A);
+
+    if error_seen && recovered {
+        red_led.set_high().unwrap();
+        loop {
+            cortex_m::asm::wfi();
+        }
+    }
+
+    loop {
+        red_led.toggle().unwrap();
+        delay.delay_ms(100);
+    }
+}
@@ -0,0 +1,101 @@
+//! Check that UART parity errors are reported, with mismatched parity.
+//!
+//! The board UART on SERCOM3 transmits with even parity on D1. A second,
+//! receive-only UART on SERCOM0 listens on A2 with odd parity. Connect D1 to
+//! A2 with a jumper wire.
+//!
+//! Every byte sent is received with a parity error, which is then cleared.
+//! The receiver is finally switched to even parity, to check that it
+//! recovered. The red LED turns on steadily when the test succeeds, and
+//! blinks quickly otherwise.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::ehal::delay::DelayNs;
+use hal::ehal::digital::{OutputPin, StatefulOutputPin};
+use hal::fugit::RateExtU32;
+use hal::pac::{CorePeripherals, Peripherals};
+use hal::sercom::uart::{self, BaudMode, Error, Oversampling, Parity};
+use hal::sercom::Sercom0;
+
+const PATTERN: [u8; 4] = [0x00, 0x5A, 0xA5, 0xFF];
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+
+    let pins = bsp::Pins::new(peripherals.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    let mut red_led: bsp::RedLed = pin_alias!(pins.red_led).into();
+
+    // Transmitter, with even parity
+    let (uart_rx, uart_tx) = pin_alias!(pins.(uart_rx, uart_tx));
+    let uart_sercom = periph_alias!(peripherals.uart_sercom);
+    let mut tx = bsp::uart(
+        &mut clocks,
+        115200.Hz(),
+        uart_sercom,
+        &mut peripherals.mclk,
+        uart_rx,
+        uart_tx,
+    );
+    tx.reconfigure(|c| c.set_parity(Parity::Even));
+
+    // Receiver, with odd parity
+    let gclk0 = clocks.gclk0();
+    let clock = clocks.sercom0_core(&gclk0).unwrap();
+    let pads = uart::Pads::<Sercom0>::default().rx(pins.a2);
+    let mut rx = uart::Config::new(&peripherals.mclk, peripherals.sercom0, pads, clock.freq())
+        .baud(115200.Hz(), BaudMode::Fractional(Oversampling::Bits16))
+        .parity(Parity::Odd)
+        .enable();
+    rx.flush_rx_buffer();
+
+    // Every byte must fail the parity check
+    let errors_seen = PATTERN.iter().all(|&byte| {
+        tx.write_byte(byte).unwrap();
+        let error_seen = rx.read_byte() == Err(Error::ParityError);
+        rx.clear_errors();
+        error_seen
+    });
+
+    // Check that the receiver recovered, once the parity settings match
+    rx.reconfigure(|c| c.set_parity(Parity::Even));
+    let recovered = PATTERN.iter().all(|&byte| {
+        tx.write_byte(byte).unwrap();
+        rx.read_byte() == Ok(byte)
+    });
+
+    if errors_seen && recovered {
+        red_led.set_high().unwrap();
+        loop {
+            cortex_m::asm::wfi();
+        }
+    }
+
+    loop {
+        red_led.toggle().unwrap();
+        delay.delay_ms(100);
+    }
+}
@@ -0,0 +1,63 @@
+//! Change the baud rate of a disabled UART, keeping its pads.
+//!
+//! The UART on pins D0 (RX) and D1 (TX) first greets the host at 9600 baud.
+//! It is then disabled, switched to 115200 baud with
+//! [`Config::reconfigure`](hal::sercom::uart::Config::reconfigure), enabled
+//! again, and greets the host at the new rate. Switch the terminal to 115200
+//! baud to read the second message.
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use bsp::{entry, hal, pac, periph_alias, pin_alias};
+use hal::clock::GenericClockController;
+use hal::ehal_nb::serial::Write;
+use hal::fugit::RateExtU32;
+use hal::nb;
+use hal::sercom::uart::{BaudMode, Oversampling};
+use metro_m4 as bsp;
+
+fn write_str<W: Write<u8>>(uart: &mut W, text: &str) {
+    for byte in text.bytes() {
+        nb::block!(uart.write(byte)).ok();
+    }
+    nb::block!(uart.flush()).ok();
+}
+
+#[entry]
+fn main() -> ! {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    let mut uart = bsp::uart(
+        &mut clocks,
+        9600.Hz(),
+        periph_alias!(peripherals.uart_sercom),
+        &mut peripherals.mclk,
+        pin_alias!(pins.uart_rx),
+        pin_alias!(pins.uart_tx),
+    );
+    write_str(&mut uart, "Hello at 9600 baud\r\n");
+
+    let mut uart = uart
+        .disable()
+        .reconfigure(|c| c.set_baud(115_200.Hz(), BaudMode::Fractional(Oversampling::Bits16)))
+        .enable();
+    write_str(&mut uart, "Hello at 115200 baud\r\n");
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
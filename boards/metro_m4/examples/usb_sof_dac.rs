@@ -0,0 +1,184 @@
+//! Stream samples from a USB serial port to the DAC, in step with the USB
+//! frame clock.
+//!
+//! The board enumerates as a USB serial port. Every byte written to it is an
+//! unsigned 8-bit sample, which is queued and played on pin A0 at the start of
+//! the next free USB frame, so the output runs at exactly 1000 samples per
+//! second as measured by the host clock. When the queue is full, the USB
+//! endpoint NAKs further data until samples have been played.
+//!
+//! Real audio streams need a higher rate, for example 48 samples per frame,
+//! played by a timer and DMA. The SOF is then used the same way, to measure and
+//! correct the drift of the timer against the host.
+//!
+//! Endpoints are serviced from the `USB_OTHER`, `USB_TRCPT0` and `USB_TRCPT1`
+//! interrupts, while the SOF has its own interrupt, bound to the HAL handler.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use bsp::hal;
+use bsp::pac;
+use cortex_m::peripheral::NVIC;
+use hal::clock::GenericClockController;
+use hal::dac::{Channel, Dac, Reference};
+use hal::usb::{SofInterruptHandler, UsbBus};
+use metro_m4 as bsp;
+use pac::gclk::{genctrl::Srcselect, pchctrl::Genselect};
+use pac::interrupt;
+use usb_device::bus::UsbBusAllocator;
+use usb_device::prelude::*;
+use usbd_serial::{SerialPort, USB_CLASS_CDC};
+
+atsamd_hal::bind_interrupts!(struct Irqs {
+    USB_SOF_HSOF => SofInterruptHandler;
+});
+
+/// Number of queued samples, a power of two
+const QUEUE_LEN: usize = 256;
+
+static mut USB_ALLOCATOR: Option<UsbBusAllocator<UsbBus>> = None;
+static mut USB_BUS: Option<UsbDevice<UsbBus>> = None;
+static mut USB_SERIAL: Option<SerialPort<UsbBus>> = None;
+
+/// Samples received from the host, written by the USB interrupts and read by
+/// the main task. Only accessed inside critical sections.
+static mut QUEUE: [u8; QUEUE_LEN] = [0; QUEUE_LEN];
+static mut HEAD: usize = 0;
+static mut TAIL: usize = 0;
+
+#[embassy_executor::main]
+async fn main(_s: embassy_executor::Spawner) {
+    let mut peripherals = pac::Peripherals::take().unwrap();
+    let mut core = pac::CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_external_32kosc(
+        peripherals.gclk,
+        &mut peripherals.mclk,
+        &mut peripherals.osc32kctrl,
+        &mut peripherals.oscctrl,
+        &mut peripherals.nvmctrl,
+    );
+    let pins = bsp::Pins::new(peripherals.port);
+
+    // The DAC clock must not exceed 12MHz
+    let gclk4 = clocks
+        .configure_gclk_divider_and_source(Genselect::Gclk4, 4, Srcselect::Dfll, false)
+        .unwrap();
+    let dac_clock = clocks.dac(&gclk4).unwrap();
+    let mut dac = Dac::new(
+        peripherals.dac,
+        &mut peripherals.mclk,
+        &dac_clock,
+        Some(pins.a0.into()),
+        None,
+        Reference::Vddana,
+        3300,
+    );
+
+    let bus_allocator = unsafe {
+        USB_ALLOCATOR = Some(bsp::usb_allocator(
+            peripherals.usb,
+            &mut clocks,
+            &mut peripherals.mclk,
+            pins.usb_dm,
+            pins.usb_dp,
+        ));
+        USB_ALLOCATOR.as_ref().unwrap()
+    };
+
+    let mut sof = unsafe {
+        USB_SERIAL = Some(SerialPort::new(bus_allocator));
+        let usb_dev = UsbDeviceBuilder::new(bus_allocator, UsbVidPid(0x2222, 0x3333))
+            .strings(&[StringDescriptors::new(LangID::EN)
+                .manufacturer("Fake company")
+                .product("SOF-synchronized DAC")
+                .serial_number("TEST")])
+            .expect("Failed to set strings")
+            .device_class(USB_CLASS_CDC)
+            .build();
+        let sof = usb_dev.bus().sof_waiter(Irqs);
+        USB_BUS = Some(usb_dev);
+        sof
+    };
+
+    unsafe {
+        core.NVIC.set_priority(interrupt::USB_TRCPT0, 1);
+        NVIC::unmask(interrupt::USB_TRCPT0);
+        core.NVIC.set_priority(interrupt::USB_TRCPT1, 1);
+        NVIC::unmask(interrupt::USB_TRCPT1);
+        core.NVIC.set_priority(interrupt::USB_OTHER, 1);
+        NVIC::unmask(interrupt::USB_OTHER);
+    }
+
+    let mut last_frame = sof.frame_number();
+    loop {
+        let frame = sof.wait_sof().await;
+
+        // Frame numbers are 11 bits wide
+        let skipped = frame.wrapping_sub(last_frame) & 0x7FF;
+        if skipped > 1 {
+            defmt::warn!("Missed {} frames", skipped - 1);
+        }
+        last_frame = frame;
+
+        if let Some(sample) = pop_sample() {
+            // Scale the 8-bit sample to the 12-bit DAC
+            dac.set_value(Channel::Ch0, (sample as u16) << 4);
+        }
+
+        // Refill the queue with the data the serial port may have held back
+        // while it was full. No USB interrupt fires for it in the meantime.
+        poll_usb();
+    }
+}
+
+fn pop_sample() -> Option<u8> {
+    cortex_m::interrupt::free(|_| unsafe {
+        if HEAD == TAIL {
+            None
+        } else {
+            let sample = QUEUE[TAIL % QUEUE_LEN];
+            TAIL = TAIL.wrapping_add(1);
+            Some(sample)
+        }
+    })
+}
+
+fn poll_usb() {
+    cortex_m::interrupt::free(|_| unsafe {
+        let (Some(usb_dev), Some(serial)) = (USB_BUS.as_mut(), USB_SERIAL.as_mut()) else {
+            return;
+        };
+        usb_dev.poll(&mut [serial]);
+
+        // Only read as many samples as the queue can hold, so that the host is
+        // flow-controlled by the USB NAKs instead of losing data
+        let free = QUEUE_LEN - HEAD.wrapping_sub(TAIL);
+        let mut buf = [0u8; 64];
+        let len = free.min(buf.len());
+        if let Ok(count) = serial.read(&mut buf[..len]) {
+            for &sample in &buf[..count] {
+                QUEUE[HEAD % QUEUE_LEN] = sample;
+                HEAD = HEAD.wrapping_add(1);
+            }
+        }
+    });
+}
+
+#[interrupt]
+fn USB_TRCPT0() {
+    poll_usb();
+}
+
+#[interrupt]
+fn USB_TRCPT1() {
+    poll_usb();
+}
+
+#[interrupt]
+fn USB_OTHER() {
+    poll_usb();
+}
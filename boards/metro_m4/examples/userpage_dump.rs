@@ -0,0 +1,72 @@
+//! Print the fuses stored in the NVM user page.
+//!
+//! The user page holds the bootloader protection, brown-out detector,
+//! watchdog and SmartEEPROM settings, which are loaded on reset. This example
+//! only reads them, and prints them over semihosting.
+
+#![no_std]
+#![no_main]
+
+use metro_m4 as bsp;
+
+use bsp::hal;
+use bsp::pac;
+
+#[cfg(not(feature = "use_semihosting"))]
+use panic_halt as _;
+#[cfg(feature = "use_semihosting")]
+use panic_semihosting as _;
+
+use cortex_m_semihosting::hprintln;
+
+use bsp::entry;
+use hal::nvm::Nvm;
+use pac::Peripherals;
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take().unwrap();
+    let nvm = Nvm::new(peripherals.nvmctrl);
+    let userpage = nvm.read_userpage();
+
+    hprintln!(
+        "Bootloader: BOOTPROT = {:#x}, {} bytes protected",
+        userpage.nvm_bootloader_size(),
+        userpage.bootloader_size()
+    )
+    .ok();
+    hprintln!(
+        "BOD33: disabled = {}, level = {}, action = {}, hysteresis = {}",
+        userpage.bod33_disable(),
+        userpage.bod33_level(),
+        userpage.bod33_action(),
+        userpage.bod33_hysteresis()
+    )
+    .ok();
+    hprintln!(
+        "WDT: enabled = {}, always on = {}, period = {}, window = {}, early warning = {}, window mode = {}",
+        userpage.wdt_enable(),
+        userpage.wdt_always_on(),
+        userpage.wdt_period(),
+        userpage.wdt_window(),
+        userpage.wdt_ewoffset(),
+        userpage.wdt_wen()
+    )
+    .ok();
+    hprintln!(
+        "SmartEEPROM: SBLK = {}, PSZ = {}",
+        userpage.see_sblk(),
+        userpage.see_psz()
+    )
+    .ok();
+    hprintln!(
+        "RAM ECC disabled = {}, region locks = {:#010x}",
+        userpage.ram_ecc_disable(),
+        userpage.nvm_locks()
+    )
+    .ok();
+
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
@@ -13,7 +13,7 @@ pub use hal::pac;
 pub use cortex_m_rt::entry;
 
 use hal::{
-    clock::GenericClockController,
+    clock::{GClock, GenericClockController},
     qspi::{OneShot, Qspi},
     sercom::{
         i2c, spi,
@@ -247,6 +247,9 @@ pub type Spi = spi::Spi<spi::Config<SpiPads>, spi::Duplex>;
 /// Convenience for setting up the 2x3 header block for SPI.
 /// This powers up SERCOM2 and configures it for use as an
 /// SPI Master in SPI Mode 0.
+///
+/// The SERCOM is clocked from GCLK0. Use [`spi_master_with_gclk`] to select
+/// another generator.
 pub fn spi_master(
     clocks: &mut GenericClockController,
     baud: Hertz,
@@ -257,7 +260,26 @@ pub fn spi_master(
     miso: impl Into<Miso>,
 ) -> Spi {
     let gclk0 = clocks.gclk0();
-    let clock = clocks.sercom2_core(&gclk0).unwrap();
+    spi_master_with_gclk(clocks, &gclk0, baud, sercom, mclk, sclk, mosi, miso)
+}
+
+/// Same as [`spi_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+#[allow(clippy::too_many_arguments)]
+pub fn spi_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: Hertz,
+    sercom: SpiSercom,
+    mclk: &mut pac::Mclk,
+    sclk: impl Into<Sclk>,
+    mosi: impl Into<Mosi>,
+    miso: impl Into<Miso>,
+) -> Spi {
+    let clock = clocks.sercom2_core(gclk).unwrap();
     let freq = clock.freq();
     let (miso, mosi, sclk) = (miso.into(), mosi.into(), sclk.into());
     let pads = spi::Pads::default().data_in(miso).data_out(mosi).sclk(sclk);
@@ -307,6 +329,9 @@ pub type I2c = i2c::I2c<i2c::Config<I2cPads>>;
 
 /// Convenience for setting up the labelled SDA, SCL pins to
 /// operate as an I2C master running at the specified frequency.
+///
+/// The SERCOM is clocked from GCLK0. Use [`i2c_master_with_gclk`] to select
+/// another generator.
 pub fn i2c_master(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -316,7 +341,24 @@ pub fn i2c_master(
     scl: impl Into<Scl>,
 ) -> I2c {
     let gclk0 = clocks.gclk0();
-    let clock = &clocks.sercom5_core(&gclk0).unwrap();
+    i2c_master_with_gclk(clocks, &gclk0, baud, sercom, mclk, sda, scl)
+}
+
+/// Same as [`i2c_master`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn i2c_master_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom: I2cSercom,
+    mclk: &mut pac::Mclk,
+    sda: impl Into<Sda>,
+    scl: impl Into<Scl>,
+) -> I2c {
+    let clock = &clocks.sercom5_core(gclk).unwrap();
     let freq = clock.freq();
     let baud = baud.into();
     let pads = i2c::Pads::new(sda.into(), scl.into());
@@ -333,6 +375,9 @@ pub type Uart = uart::Uart<uart::Config<UartPads>, uart::Duplex>;
 
 /// Convenience for setting up the labelled RX, TX pins to
 /// operate as a UART device running at the specified baud.
+///
+/// The SERCOM is clocked from GCLK0. Use [`uart_with_gclk`] to select another
+/// generator.
 pub fn uart(
     clocks: &mut GenericClockController,
     baud: impl Into<Hertz>,
@@ -342,7 +387,24 @@ pub fn uart(
     uart_tx: impl Into<UartTx>,
 ) -> Uart {
     let gclk0 = clocks.gclk0();
-    let clock = &clocks.sercom3_core(&gclk0).unwrap();
+    uart_with_gclk(clocks, &gclk0, baud, sercom, mclk, uart_rx, uart_tx)
+}
+
+/// Same as [`uart`], but clocks the SERCOM from `gclk` instead of GCLK0
+///
+/// # Panics
+///
+/// Panics if the SERCOM core clock has already been configured.
+pub fn uart_with_gclk(
+    clocks: &mut GenericClockController,
+    gclk: &GClock,
+    baud: impl Into<Hertz>,
+    sercom: UartSercom,
+    mclk: &mut pac::Mclk,
+    uart_rx: impl Into<UartRx>,
+    uart_tx: impl Into<UartTx>,
+) -> Uart {
+    let clock = &clocks.sercom3_core(gclk).unwrap();
     let baud = baud.into();
     let pads = uart::Pads::default().rx(uart_rx.into()).tx(uart_tx.into());
     uart::Config::new(mclk, sercom, pads, clock.freq())
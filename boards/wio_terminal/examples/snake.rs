@@ -86,7 +86,7 @@ fn main() -> ! {
             &mut clocks,
             peripherals.sercom7,
             &mut peripherals.mclk,
-            100.MHz(),
+            60.MHz(),
             &mut delay,
         )
         .unwrap();
@@ -18,14 +18,42 @@
 //! feature. Note that this uses a certain amount of static RAM in order to
 //! initialize wakers for each peripheral.
 //!
+//! The [`interrupts`] module and the binding macros are available without the
+//! `async` feature, for interrupt-driven drivers which don't need an executor,
+//! such as [`BufferedUart`](crate::sercom::uart::BufferedUart).
+//!
 //! ## Supported peripherals
 //!
-//! * [`UART`](crate::sercom::uart)
-//! * [`SPI`](crate::sercom::spi)
-//! * [`I2C`](crate::sercom::i2c)
-//! * [`DMAC`](crate::dmac)
-//! * [`EIC`](crate::eic) (external GPIO interrupts)
-//! * [`Timers`](crate::timer)
+//! Every async peripheral is driven by an interrupt handler provided by the
+//! HAL. The table below lists the handler to bind for each of them, and the
+//! interrupt source it must be bound to:
+//!
+//! | Peripheral | Handler | Interrupt source |
+//! |------------|---------|------------------|
+//! | [`UART`](crate::sercom::uart) | [`uart::InterruptHandler`](crate::sercom::uart::InterruptHandler) | `SERCOMx` |
+//! | [Buffered `UART`](crate::sercom::uart::BufferedUart) | [`uart::BufferedInterruptHandler`](crate::sercom::uart::BufferedInterruptHandler) | `SERCOMx` |
+//! | [`SPI`](crate::sercom::spi) | [`spi::InterruptHandler`](crate::sercom::spi::InterruptHandler) | `SERCOMx` |
+//! | [`I2C`](crate::sercom::i2c) | [`i2c::InterruptHandler`](crate::sercom::i2c::InterruptHandler) | `SERCOMx` |
+//! | [`I2C` target](crate::sercom::i2c::target) | [`target::InterruptHandler`](crate::sercom::i2c::target::InterruptHandler) | `SERCOMx` |
+//! | [`DMAC`](crate::dmac) | `dmac::InterruptHandler` | `DMAC` |
+//! | [`EIC`](crate::eic) (external GPIO interrupts) | [`eic::InterruptHandler`](crate::eic::InterruptHandler) | `EIC`, or `EIC_EXTINT_n` on `thumbv7em` |
+//! | [Edge counter](crate::eic::EdgeCounter) | [`eic::EdgeCounterHandler`](crate::eic::EdgeCounterHandler) | `EIC`, or `EIC_EXTINT_n` on `thumbv7em` |
+//! | [`ADC`](crate::adc) | `adc::InterruptHandler` | `ADCx` |
+//! | [`Timers`](crate::timer) | `timer::InterruptHandler` | `TCx` |
+//! | [`PWM`](crate::pwm) (TCC) | `pwm::InterruptHandler` | `TCCx` |
+//! | USB Start Of Frame (`usb`) | `usb::SofInterruptHandler` | `USB_SOF_HSOF`, on `thumbv7em` |
+//!
+//! The SERCOM handlers wake the task waiting on the peripheral, and disable the
+//! interrupts which fired. The flags themselves are cleared by the future once
+//! it is polled again, so that it can tell which event completed the
+//! operation.
+//!
+//! When a SERCOM driver uses DMA, the completion of the transfer is signaled
+//! by the DMA channel rather than by the SERCOM, so both the SERCOM handler
+//! and the `DMAC` handler must be bound, as in the [complete
+//! example](#complete-example-using-the-feather_m0-bsp-and-the-embassy-executor)
+//! below. The SERCOM handler is then still used by `flush`, which waits for the
+//! last word to leave the shift register.
 //!
 //!  **Note**: The asynchronous APIs for the individual peripherals are provided
 //! in their respective modules. This module only deals with the generalities of
@@ -61,6 +89,56 @@
 //! * `SERCOMx: [SERCOMx_0, SERCOMx_1, SERCOMx_2, SERCOMx_OTHER]`
 //! * `DMAC: [DMAC_0, DMAC_1, DMAC_2, DMAC_OTHER]`
 //!
+//! ## Interrupt priorities
+//!
+//! The `into_future` methods enable the interrupts they are given, but leave
+//! their NVIC priority untouched, at the highest level after reset. Each
+//! interrupt source in [`interrupts`] implements
+//! [`InterruptSource::set_priority`](interrupts::InterruptSource::set_priority),
+//! which should be called before `into_future`. For sources with multiple
+//! interrupts, such as the `SERCOMx` sources on `thumbv7em` targets, every
+//! interrupt of the source gets the same priority.
+//!
+//! ```no_run
+//! use atsamd_hal::async_hal::interrupts::{InterruptSource, Priority, SERCOM0};
+//!
+//! // Let more urgent interrupts preempt the SERCOM0 handler
+//! SERCOM0::set_priority(Priority::P1);
+//! ```
+//!
+//! [`Priority`](interrupts::Priority) is a logical level: `P1` is the least
+//! urgent, which matches the convention used by RTIC.
+//!
+//! The handlers provided by the HAL only wake tasks and mask the interrupts
+//! which fired, so they stay short at any priority. The futures share state
+//! with the handlers through [`critical_section`], which on single core chips
+//! masks every interrupt with `PRIMASK`. Those critical sections are brief, but
+//! they delay all interrupts, whatever their priority. On the other hand, the
+//! resource locks of RTIC on `thumbv7em` targets only raise `BASEPRI` to the
+//! ceiling of the resource, so an async handler with a priority above that
+//! ceiling keeps running while the lock is held. See the
+//! [`interrupt`](crate::interrupt) module for more details on critical
+//! sections, and `examples/async_priorities.rs` in the `metro_m4` BSP for two
+//! async peripherals running at different priorities.
+//!
+//! ## Time sources and SysTick
+//!
+//! Neither the async APIs of the HAL nor the thread mode executor of Embassy
+//! use SysTick, so it remains available for a blocking
+//! [`Delay`](crate::delay::Delay). SysTick is only claimed when it is also
+//! picked as the time source for async delays, e.g. by the `Systick`
+//! monotonic of `rtic-monotonics` in the example below. The HAL provides two
+//! time sources which leave SysTick alone:
+//!
+//! - The futures returned by `TimerCounter::into_future`, whose `delay` method
+//!   waits on a TC. Each TC serves a single delay at a time.
+//! - The RTC monotonic created by `rtc_monotonic!`, with the `rtic` feature.
+//!   It implements `rtic_time::Monotonic`, so any number of tasks can wait on
+//!   it, and it keeps running in standby.
+//!
+//! `examples/async_blocking_delay.rs` in the `metro_m4` BSP uses a TC for async
+//! delays and SysTick for blocking ones in the same program.
+//!
 //! ## Complete example using the `feather_m0` BSP and the [Embassy executor](https://crates.io/crates/embassy-executor)
 //! ```no_run
 //! #![no_std]
@@ -55,20 +55,53 @@ impl Delay {
     }
 }
 
-impl DelayNs for Delay {
-    // The default method is delay_ns. If we don't provide implementations for
-    // delay_us and delay_ms, the trait impl will use delay_ns to implement the
-    // other two methods. As the delay implementation is actually defined in terms
-    // of microseconds, we need to provide implementations for all three methods.
-    fn delay_ns(&mut self, ns: u32) {
-        self.delay_us(ns / 1000);
-    }
-
-    fn delay_us(&mut self, us: u32) {
+impl Delay {
+    /// Core clock cycles spent reprogramming SysTick and polling it for
+    /// completion, which are subtracted from every requested delay.
+    ///
+    /// Counted from the Cortex-M0+ instruction timings at zero flash wait
+    /// states: 11 cycles to load the SysTick address, write `RVR` and `CVR`
+    /// and set `ENABLE` (stores and loads take 2 cycles each), and 14 cycles
+    /// from the wrap to the return (the final poll, clearing `ENABLE`, the
+    /// loop test and `bx lr`). Call overhead, if not inlined, and wait states
+    /// only lengthen the delay, so this is a lower bound and delays are never
+    /// shorter than requested. The `delay_accuracy` example of the
+    /// `feather_m0` BSP measures the actual error with a TC pulse-width
+    /// capture.
+    #[hal_cfg(any("rtc-d11", "rtc-d21"))]
+    const OVERHEAD_CYCLES: u32 = 25;
+
+    /// Core clock cycles spent reprogramming SysTick and polling it for
+    /// completion, which are subtracted from every requested delay.
+    ///
+    /// Counted from the Cortex-M4 instruction timings at zero flash wait
+    /// states: 8 cycles to load the SysTick address, write `RVR` and `CVR`
+    /// and set `ENABLE` (pipelined stores take a single cycle), and 13 cycles
+    /// from the wrap to the return (the final poll, clearing `ENABLE`, the
+    /// loop test and `bx lr` with its pipeline refill). Call overhead, if not
+    /// inlined, and wait states only lengthen the delay, so this is a lower
+    /// bound and delays are never shorter than requested. The
+    /// `delay_accuracy` example of the `metro_m4` BSP measures the actual
+    /// error with a TC pulse-width capture.
+    #[hal_cfg("rtc-d5x")]
+    const OVERHEAD_CYCLES: u32 = 21;
+
+    /// Busy-wait for the given number of core clock cycles
+    ///
+    /// The wait is timed by SysTick, which is clocked from the core clock, so
+    /// it is unaffected by flash wait states or interrupts that are serviced
+    /// while waiting (an interrupt may still lengthen the delay, but never
+    /// shorten it). The time spent setting up SysTick is accounted for, so
+    /// requests shorter than that overhead return immediately. The resolution
+    /// is one iteration of the polling loop, a handful of cycles.
+    ///
+    /// This is the primitive used by all the [`DelayNs`] methods, and can be
+    /// used directly for cycle-level timing, e.g. in bit-banged protocols.
+    pub fn delay_cycles(&mut self, cycles: u32) {
         // The SysTick Reload Value register supports values between 1 and 0x00FFFFFF.
         const MAX_RVR: u32 = 0x00FF_FFFF;
 
-        let mut total_rvr = us * (self.sysclock.to_Hz() / 1_000_000);
+        let mut total_rvr = cycles.saturating_sub(Self::OVERHEAD_CYCLES);
 
         while total_rvr != 0 {
             let current_rvr = if total_rvr <= MAX_RVR {
@@ -90,14 +123,43 @@ impl DelayNs for Delay {
         }
     }
 
+    /// Busy-wait for `time`, expressed in `1 / units_per_sec` seconds
+    fn wait(&mut self, time: u32, units_per_sec: u64) {
+        let mut cycles = cycles(self.sysclock, time, units_per_sec);
+        while cycles > u32::MAX as u64 {
+            self.delay_cycles(u32::MAX);
+            cycles -= u32::MAX as u64;
+        }
+        self.delay_cycles(cycles as u32);
+    }
+}
+
+/// Convert a duration, expressed in `1 / units_per_sec` seconds, to a number of
+/// core clock cycles. The result is rounded up so that delays are never
+/// shorter than requested.
+fn cycles(sysclock: Hertz, time: u32, units_per_sec: u64) -> u64 {
+    (time as u64 * sysclock.to_Hz() as u64).div_ceil(units_per_sec)
+}
+
+impl DelayNs for Delay {
+    // The default method is delay_ns. We also provide delay_us and delay_ms so
+    // that longer delays aren't limited by the range of a u32 nanosecond count.
+    fn delay_ns(&mut self, ns: u32) {
+        self.wait(ns, 1_000_000_000);
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.wait(us, 1_000_000);
+    }
+
     fn delay_ms(&mut self, ms: u32) {
-        self.delay_us(ms * 1000);
+        self.wait(ms, 1_000);
     }
 }
 
 impl ehal_02::blocking::delay::DelayMs<u32> for Delay {
     fn delay_ms(&mut self, ms: u32) {
-        <Self as DelayNs>::delay_us(self, ms * 1_000);
+        <Self as DelayNs>::delay_ms(self, ms);
     }
 }
 
@@ -130,3 +192,25 @@ impl ehal_02::blocking::delay::DelayUs<u8> for Delay {
         <Self as ehal_02::blocking::delay::DelayUs<u32>>::delay_us(self, us as u32);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::cycles;
+    use crate::time::Hertz;
+
+    #[test]
+    fn short_delays_round_up() {
+        let clk = Hertz::MHz(48);
+        assert_eq!(cycles(clk, 1, 1_000_000), 48);
+        assert_eq!(cycles(clk, 1, 1_000_000_000), 1);
+        assert_eq!(cycles(clk, 500, 1_000_000_000), 24);
+        assert_eq!(cycles(Hertz::MHz(120), 1, 1_000_000_000), 1);
+        assert_eq!(cycles(clk, 0, 1_000_000), 0);
+    }
+
+    #[test]
+    fn long_delays_do_not_overflow() {
+        let clk = Hertz::MHz(120);
+        assert_eq!(cycles(clk, u32::MAX, 1_000), u32::MAX as u64 * 120_000);
+    }
+}
@@ -271,6 +271,12 @@ impl<Id: ChId, S: Status> Channel<Id, S> {
     /// Enable the transfer, and emit a compiler fence.
     #[inline]
     fn _enable_private(&mut self) {
+        // Until the DMAC first fetches the descriptor, the writeback section
+        // still holds the count left over by the previous transfer.
+        let btcnt = self.descriptor_mut().btcnt;
+        // SAFETY: We own the channel, and it is still disabled
+        unsafe { sram::write_writeback_btcnt(Id::USIZE, btcnt) };
+
         // Prevent the compiler from re-ordering read/write
         // operations beyond this fence.
         // (see https://docs.rust-embedded.org/embedonomicon/dma.html#compiler-misoptimizations)
@@ -300,6 +306,24 @@ impl<Id: ChId, S: Status> Channel<Id, S> {
         self.regs.chctrla.read().enable().bit_is_clear()
     }
 
+    /// Returns the number of beats remaining in the current block transfer.
+    ///
+    /// The count is taken from the `ACTIVE` register while the channel is
+    /// being served, and from the channel's writeback descriptor otherwise.
+    /// The DMAC only updates the writeback descriptor when the channel leaves
+    /// the active slot, so the count may lag behind by up to one burst.
+    #[inline]
+    pub(crate) fn remaining_beats(&mut self) -> u16 {
+        if let Some(btcnt) = self.regs.active.btcnt() {
+            return btcnt;
+        }
+        // SAFETY: We own the channel, and only read the writeback descriptor
+        let btcnt = unsafe { sram::read_writeback_btcnt(Id::USIZE) };
+        // The channel may have been granted access between both reads, in which
+        // case the live count is more accurate
+        self.regs.active.btcnt().unwrap_or(btcnt)
+    }
+
     /// Returns the transfer's success status.
     #[allow(dead_code)]
     #[inline]
@@ -273,6 +273,33 @@ reg_proxy!(busych, bit, r);
 reg_proxy!(pendch, bit, r);
 reg_proxy!(swtrigctrl, bit, rw);
 
+/// Read-only proxy for the `ACTIVE` register, which describes the channel
+/// currently being served by the DMAC
+pub(super) struct ActiveProxy<Id: ChId> {
+    dmac: Dmac,
+    _id: PhantomData<Id>,
+}
+
+impl<Id: ChId> ActiveProxy<Id> {
+    /// Create a new register proxy
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            // SAFETY: This is safe because the `ACTIVE` register is read-only
+            dmac: unsafe { Peripherals::steal().dmac },
+            _id: PhantomData,
+        }
+    }
+
+    /// Get the live block transfer count of the channel, or `None` if the
+    /// channel is not the one being served
+    #[inline]
+    pub fn btcnt(&self) -> Option<u16> {
+        let active = self.dmac.active().read();
+        (active.abusy().bit_is_set() && active.id().bits() == Id::U8).then(|| active.btcnt().bits())
+    }
+}
+
 /// Acts as a proxy to the PAC DMAC object. Only registers and bits
 /// within registers that should be readable/writable by specific
 /// [`Channel`]s are exposed.
@@ -292,6 +319,7 @@ pub(super) struct RegisterBlock<Id: ChId> {
     pub busych: BusychProxy<Id, Busych>,
     pub pendch: PendchProxy<Id, Pendch>,
     pub swtrigctrl: SwtrigctrlProxy<Id, Swtrigctrl>,
+    pub active: ActiveProxy<Id>,
     #[hal_cfg("dmac-d5x")]
     pub chprilvl: ChprilvlProxy<Id, Chprilvl>,
 }
@@ -310,6 +338,7 @@ impl<Id: ChId> RegisterBlock<Id> {
             busych: BusychProxy::new(),
             pendch: PendchProxy::new(),
             swtrigctrl: SwtrigctrlProxy::new(),
+            active: ActiveProxy::new(),
             #[hal_cfg("dmac-d5x")]
             chprilvl: ChprilvlProxy::new(),
         }
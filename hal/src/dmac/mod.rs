@@ -245,6 +245,27 @@
 //! }
 //! ```
 //! [RTIC]: https://rtic.rs
+//!
+//! # Cancelling `async` transfers
+//!
+//! The future returned by `Channel::transfer_future`, and the futures of
+//! the peripheral drivers built on top of it, stop the channel when they are
+//! dropped. Losing a `select!` race, or any timeout combinator, therefore
+//! aborts the transfer in hardware before the buffers are released:
+//!
+//! * The channel is disabled, and `drop` waits for the ongoing burst to
+//!   finish. No beat is written to, or read from, the buffers afterwards.
+//! * The transfer is partial. The beats transferred before the cancellation
+//!   are in the destination buffer, and the rest of the buffer is left
+//!   untouched. The number of beats transferred is not reported.
+//! * The channel stays `ReadyFuture`, and the next transfer starts from a
+//!   fresh descriptor. Nothing needs to be reset.
+//!
+//! The peripheral, however, is not reset. For example, when a SERCOM read
+//! times out, words received after the cancellation stay in the SERCOM's
+//! receive buffer, and may have raised an overflow. Flush or drain it before
+//! the next read if stale data matters. The `async_uart_timeout` example of
+//! the `metro_m4` BSP shows a timeout-guarded UART read.
 
 // This is necessary until modular_bitfield fixes all their identity_op warnings
 #![allow(clippy::identity_op)]
@@ -464,9 +485,10 @@ pub(crate) mod sram {
     ///
     /// # Safety
     ///
-    /// This variable should never be accessed. The only thing we need
-    /// to know about it is its starting address, given by
-    /// [`writeback_addr`].
+    /// This variable is written by the DMAC, and should never be accessed
+    /// directly. Apart from its starting address, given by [`writeback_addr`],
+    /// only the `BTCNT` fields may be accessed, through
+    /// [`read_writeback_btcnt`] and [`write_writeback_btcnt`].
     static WRITEBACK: [DescriptorCell; NUM_CHANNELS] =
         [const { DescriptorCell::default() }; NUM_CHANNELS];
 
@@ -475,6 +497,30 @@ pub(crate) mod sram {
         WRITEBACK[0].get()
     }
 
+    /// Read the `BTCNT` field of the specified channel's writeback descriptor
+    ///
+    /// # Safety
+    ///
+    /// The caller must own the channel.
+    #[inline]
+    pub(super) unsafe fn read_writeback_btcnt(channel_id: usize) -> u16 {
+        let writeback = WRITEBACK[channel_id].get();
+        core::ptr::addr_of!((*writeback).btcnt).read_volatile()
+    }
+
+    /// Overwrite the `BTCNT` field of the specified channel's writeback
+    /// descriptor
+    ///
+    /// # Safety
+    ///
+    /// The caller must own the channel, and the channel must be disabled, as
+    /// the DMAC writes back the descriptors of enabled channels.
+    #[inline]
+    pub(super) unsafe fn write_writeback_btcnt(channel_id: usize, btcnt: u16) {
+        let writeback = WRITEBACK[channel_id].get();
+        core::ptr::addr_of_mut!((*writeback).btcnt).write_volatile(btcnt);
+    }
+
     /// Descriptor section.
     ///
     /// # Safety
@@ -28,7 +28,37 @@
 //! * Word-per-word (32 bit beats);
 //!
 //! The correct beat size will automatically be selected in function of the type
-//! of the source and destination buffers.
+//! of the source and destination buffers. Use `u16` or `u32` buffers with
+//! peripherals whose data register is 16 or 32 bits wide, such as an ADC
+//! result register or the 32-bit extension of a SAMx5x SERCOM, so that each
+//! beat moves a whole sample. The selected size is returned by
+//! [`Transfer::beat_size`].
+//!
+//! # Endianness
+//!
+//! The DMAC copies each beat as is, and can't reorder the bytes within it.
+//! Since the chip is little-endian, a 16 or 32-bit beat read from a register
+//! stores its least significant byte first in memory. When the peripheral
+//! receives the first byte on the wire into the least significant byte of the
+//! register (for example, a SERCOM with a 32-bit `DATA` register), multi-byte
+//! samples from a big-endian device end up byte-swapped.
+//!
+//! There are two ways to handle this:
+//!
+//! * Transfer into a `u8` buffer, and convert each sample with
+//!   [`u16::from_be_bytes`] or [`u32::from_be_bytes`]. This keeps the wire
+//!   order, at the cost of one beat per byte.
+//! * Transfer into a `u16` or `u32` buffer, to move one sample per beat, and
+//!   call [`swap_bytes`] on the buffer once the transfer is complete:
+//!
+//! ```
+//! use atsamd_hal::dmac::swap_bytes;
+//!
+//! // Big-endian samples 0x1234 and 0xABCD, as stored by 16-bit beats
+//! let mut samples: [u16; 2] = [0x3412, 0xCDAB];
+//! swap_bytes(&mut samples);
+//! assert_eq!(samples, [0x1234, 0xABCD]);
+//! ```
 //!
 //! # One-shot vs circular transfers
 //!
@@ -40,6 +70,12 @@
 //! to periodically retreive a sample from an ADC and send it to a circular
 //! buffer, or send a sample to a DAC.
 //!
+//! # Filling memory
+//!
+//! [`fill`] sets up a fixed-source to incrementing-destination transfer from
+//! a single value, to quickly set a whole buffer to a constant, such as when
+//! clearing a framebuffer.
+//!
 //! # Starting a transfer
 //!
 //! A transfer is started by calling [`Transfer::begin`]. You will be
@@ -95,7 +131,7 @@ use modular_bitfield::prelude::*;
 //==============================================================================
 
 /// Useable beat sizes for DMA transfers
-#[derive(Clone, Copy, BitfieldSpecifier)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BitfieldSpecifier)]
 #[bits = 2]
 pub enum BeatSize {
     /// Byte = [`u8`](core::u8)
@@ -113,9 +149,12 @@ pub enum BeatSize {
 ///
 /// This trait should not be implemented outside of the crate-provided
 /// implementations
-pub unsafe trait Beat: Sealed {
+pub unsafe trait Beat: Sealed + Copy {
     /// Convert to BeatSize enum
     const BEATSIZE: BeatSize;
+
+    /// Reverse the order of the bytes of the beat
+    fn swap_bytes(self) -> Self;
 }
 
 macro_rules! impl_beat {
@@ -123,6 +162,11 @@ macro_rules! impl_beat {
         $(
             unsafe impl Beat for $Type {
                 const BEATSIZE: BeatSize = BeatSize::$Size;
+
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    <$Type>::swap_bytes(self)
+                }
             }
         )+
     };
@@ -134,10 +178,30 @@ impl_beat!(
     (u16, HalfWord),
     (i16, HalfWord),
     (u32, Word),
-    (i32, Word),
-    (f32, Word)
+    (i32, Word)
 );
 
+unsafe impl Beat for f32 {
+    const BEATSIZE: BeatSize = BeatSize::Word;
+
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+/// Reverse the order of the bytes within each beat of `buffer`
+///
+/// This converts samples transferred with 16 or 32-bit beats between big and
+/// little-endian. It has no effect on 8-bit beats. See the
+/// [module-level](self#endianness) documentation for details.
+#[inline]
+pub fn swap_bytes<T: Beat>(buffer: &mut [T]) {
+    if T::BEATSIZE != BeatSize::Byte {
+        buffer.iter_mut().for_each(|beat| *beat = beat.swap_bytes());
+    }
+}
+
 //==============================================================================
 // Buffer
 //==============================================================================
@@ -477,12 +541,49 @@ where
     }
 }
 
+/// Fill `destination` with the value referenced by `value`
+///
+/// The DMAC reads `value` as a fixed source, and writes it to every beat of
+/// `destination`, which is typically much faster than a CPU loop for large
+/// buffers such as framebuffers. The beat size, 8, 16 or 32 bits, follows the
+/// type of `value`.
+///
+/// The transfer is started with a software trigger. [`wait`](Transfer::wait)
+/// for it to complete and recover the channel and buffers. Since `value` is
+/// read for every beat, it must not be modified until then, which its
+/// `'static` mutable borrow guarantees.
+///
+/// A single transfer can fill at most 65535 beats.
+#[inline]
+pub fn fill<C, T, D>(
+    chan: C,
+    value: &'static mut T,
+    destination: D,
+) -> Transfer<Channel<ChannelId<C>, Busy>, BufferPair<&'static mut T, D>>
+where
+    C: AnyChannel<Status = Ready>,
+    T: Beat,
+    D: Buffer<Beat = T> + 'static,
+{
+    // SAFETY: Both buffers are 'static, and a single-beat source is compatible
+    // with any destination length
+    unsafe { Transfer::new_unchecked(chan, value, destination, false) }
+        .begin(TriggerSource::Disable, TriggerAction::Block)
+}
+
 impl<S, D, C> Transfer<C, BufferPair<S, D>>
 where
     S: Buffer,
     D: Buffer<Beat = S::Beat>,
     C: AnyChannel<Status = Busy>,
 {
+    /// Get the beat size used by the transfer, selected from the type of its
+    /// buffers
+    #[inline]
+    pub fn beat_size(&self) -> BeatSize {
+        S::Beat::BEATSIZE
+    }
+
     /// Issue a software trigger request to the corresponding channel.
     /// Note that is not guaranteed that the trigger request will register,
     /// if a trigger request is already pending for the channel.
@@ -536,6 +637,21 @@ where
         self.complete
     }
 
+    /// Get the number of beats which remain to be transferred
+    ///
+    /// This does not block, and can be used to report the progress of a long
+    /// transfer. For circular transfers, the count restarts with every block.
+    /// The count is read while the DMAC is running, so it may lag behind by up
+    /// to one burst. Use [`complete`](Self::complete) to check whether the
+    /// transfer has completed.
+    #[inline]
+    pub fn remaining(&mut self) -> usize {
+        if self.complete() {
+            return 0;
+        }
+        self.chan.as_mut().remaining_beats() as usize
+    }
+
     /// Checks and clears the block transfer complete interrupt flag
     #[inline]
     pub fn block_transfer_interrupt(&mut self) -> bool {
@@ -636,3 +752,30 @@ where
         (chan, self.buffers.source, self.buffers.destination)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_bytes_converts_big_endian_samples() {
+        // Samples as they arrive on the wire, most significant byte first
+        let wire = [0x12, 0x34, 0xAB, 0xCD];
+
+        // Memory layout after a transfer with 16-bit beats
+        let mut samples = [
+            u16::from_le_bytes([wire[0], wire[1]]),
+            u16::from_le_bytes([wire[2], wire[3]]),
+        ];
+        swap_bytes(&mut samples);
+        assert_eq!(samples, [0x1234, 0xABCD]);
+
+        let mut words = [u32::from_le_bytes(wire)];
+        swap_bytes(&mut words);
+        assert_eq!(words, [0x1234_ABCD]);
+
+        let mut bytes = wire;
+        swap_bytes(&mut bytes);
+        assert_eq!(bytes, wire);
+    }
+}
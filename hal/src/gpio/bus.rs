@@ -0,0 +1,106 @@
+//! # Parallel output buses
+//!
+//! An [`OutputBus`] drives an ordered set of output pins as a single value,
+//! for parallel displays, LED matrices or other parallel interfaces. Bit `i`
+//! of the value written with [`OutputBus::write`] drives pin `i` of the bus.
+//!
+//! The pins may be spread across several port groups, in any order. The pins
+//! of each group are updated with a single register write, so they all change
+//! at the same time. Pins in different groups change one group after the
+//! other, a few clock cycles apart. Latch the bus with a separate strobe pin
+//! if that matters.
+//!
+//! ```no_run
+//! # use atsamd_hal::gpio::{OutputBus, Pins};
+//! # let peripherals = atsamd_hal::pac::Peripherals::take().unwrap();
+//! let pins = Pins::new(peripherals.port);
+//! let mut bus = OutputBus::new([
+//!     pins.pa02.into(),
+//!     pins.pa03.into(),
+//!     pins.pa04.into(),
+//!     pins.pa05.into(),
+//! ]);
+//! bus.write(0b1010);
+//! ```
+
+use super::dynpin::{DynGroup, DynPin};
+use super::reg::{group_index, write_group_outputs};
+
+/// Maximum number of port groups on any chip
+const MAX_GROUPS: usize = 4;
+
+//==============================================================================
+//  OutputBus
+//==============================================================================
+
+/// An ordered set of up to 32 output pins, written as a single value
+///
+/// See the [module-level documentation](self) for more details.
+pub struct OutputBus<const N: usize> {
+    pins: [DynPin; N],
+    groups: [Option<DynGroup>; MAX_GROUPS],
+    masks: [u32; MAX_GROUPS],
+}
+
+impl<const N: usize> OutputBus<N> {
+    /// Create a new [`OutputBus`] from `pins`
+    ///
+    /// Each pin is configured as a push-pull output, without changing the
+    /// level of its output latch. The first pin drives bit 0 of the bus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is larger than 32.
+    #[inline]
+    pub fn new(mut pins: [DynPin; N]) -> Self {
+        assert!(N <= 32, "An OutputBus can't have more than 32 pins");
+        let mut groups = [None; MAX_GROUPS];
+        let mut masks = [0; MAX_GROUPS];
+        for pin in pins.iter_mut() {
+            pin.into_push_pull_output();
+            let id = pin.id();
+            let index = group_index(id.group);
+            groups[index] = Some(id.group);
+            masks[index] |= 1 << id.num;
+        }
+        OutputBus {
+            pins,
+            groups,
+            masks,
+        }
+    }
+
+    /// Drive every pin of the bus to the corresponding bit of `value`
+    ///
+    /// Bits of `value` beyond the width of the bus are ignored.
+    #[inline]
+    pub fn write(&mut self, value: u32) {
+        let mut levels = [0; MAX_GROUPS];
+        for (i, pin) in self.pins.iter().enumerate() {
+            if value & (1 << i) != 0 {
+                let id = pin.id();
+                levels[group_index(id.group)] |= 1 << id.num;
+            }
+        }
+        for ((group, mask), levels) in self.groups.iter().zip(self.masks).zip(levels) {
+            if let Some(group) = group {
+                // Safety: The bus owns every pin selected by `mask`
+                unsafe { write_group_outputs(*group, mask, levels) };
+            }
+        }
+    }
+
+    /// Return the number of pins in the bus
+    #[inline]
+    pub const fn width(&self) -> usize {
+        N
+    }
+
+    /// Consume the [`OutputBus`] and return its pins, in order
+    ///
+    /// The pins are left configured as push-pull outputs.
+    #[inline]
+    pub fn free(self) -> [DynPin; N] {
+        self.pins
+    }
+}
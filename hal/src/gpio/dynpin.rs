@@ -60,7 +60,9 @@
 
 use atsamd_hal_macros::{hal_cfg, hal_macro_helper};
 
-use crate::ehal::digital::{ErrorKind, ErrorType, InputPin, OutputPin, StatefulOutputPin};
+use crate::ehal::digital::{
+    ErrorKind, ErrorType, InputPin, OutputPin, PinState, StatefulOutputPin,
+};
 use paste::paste;
 
 use super::pin::*;
@@ -193,8 +195,8 @@ dyn_alternate!(I, J, K, L, M, N);
 //==============================================================================
 
 /// Value-level `enum` for pin groups
-#[derive(PartialEq, Clone, Copy)]
 #[hal_macro_helper]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DynGroup {
     A,
     #[hal_cfg("pin-group-b")]
@@ -206,7 +208,7 @@ pub enum DynGroup {
 }
 
 /// Value-level `struct` representing pin IDs
-#[derive(PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct DynPinId {
     pub group: DynGroup,
     pub num: u8,
@@ -246,6 +248,22 @@ impl DynRegisters {
     }
 }
 
+/// Configure a pin as a push-pull output, driving `state`
+///
+/// The output latch is written before the output driver is enabled, so that
+/// the pin never drives the opposite level.
+///
+/// # Safety
+///
+/// No [`Pin`] or [`DynPin`] may exist for `id`, since it would no longer
+/// match the configuration of the pin.
+#[inline]
+pub(super) unsafe fn drive_unowned_pin(id: DynPinId, state: PinState) {
+    let mut regs = DynRegisters::new(id);
+    regs.write_pin(state == PinState::High);
+    regs.change_mode(DYN_PUSH_PULL_OUTPUT);
+}
+
 //==============================================================================
 //  Error
 //==============================================================================
@@ -378,12 +396,32 @@ impl DynPin {
         self.into_mode(DYN_PUSH_PULL_OUTPUT);
     }
 
+    /// Configure the pin to operate as a push-pull output, driving `state`
+    ///
+    /// The output level is set before the output driver is enabled, so the
+    /// pin never briefly drives the previous level of its output latch.
+    #[inline]
+    pub fn into_push_pull_output_in_state(&mut self, state: PinState) {
+        self.regs.write_pin(state == PinState::High);
+        self.into_mode(DYN_PUSH_PULL_OUTPUT);
+    }
+
     /// Configure the pin to operate as a readable push pull output
     #[inline]
     pub fn into_readable_output(&mut self) {
         self.into_mode(DYN_READABLE_OUTPUT);
     }
 
+    /// Configure the pin to operate as a readable push pull output, driving
+    /// `state`
+    ///
+    /// The output level is set before the output driver is enabled.
+    #[inline]
+    pub fn into_readable_output_in_state(&mut self, state: PinState) {
+        self.regs.write_pin(state == PinState::High);
+        self.into_mode(DYN_READABLE_OUTPUT);
+    }
+
     /// Configure the pin to operate as the corresponding peripheral function.
     ///
     /// The `config` argument indicates the desired peripheral function.
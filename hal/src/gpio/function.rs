@@ -0,0 +1,534 @@
+//! # Type-level pin-mux function table
+//!
+//! [`Pin::into_alternate`] accepts any [`AlternateConfig`], so nothing prevents
+//! a pin from being muxed to a peripheral function that doesn't carry the
+//! intended signal. That mistake only shows up at run-time, as a dead pin.
+//!
+//! This module describes the pin-mux table at the type level instead. Each
+//! peripheral signal is represented by a [`Function`] type, and each pin which
+//! can carry that signal implements [`HasFunction`], which names the
+//! corresponding [`AlternateConfig`]. [`Pin::into_function`] then selects the
+//! right mux position, and refuses to compile for pins that can't carry the
+//! signal:
+//!
+//! ```
+//! use atsamd_hal::gpio::function::{GclkIo4, Tcc0Wo1};
+//! use atsamd_hal::gpio::{AlternateG, AlternateM, Pin, PushPullOutput, PA10, PB13};
+//!
+//! fn route(
+//!     pa10: Pin<PA10, PushPullOutput>,
+//!     pb13: Pin<PB13, PushPullOutput>,
+//! ) -> (Pin<PA10, AlternateM>, Pin<PB13, AlternateG>) {
+//!     (
+//!         pa10.into_function::<GclkIo4>(),
+//!         pb13.into_function::<Tcc0Wo1>(),
+//!     )
+//! }
+//! ```
+//!
+//! `PA10` can only output `GCLK_IO[4]`, so asking it for another generator
+//! is rejected:
+//!
+//! ```compile_fail
+//! use atsamd_hal::gpio::function::GclkIo0;
+//! use atsamd_hal::gpio::{Pin, PushPullOutput, PA10};
+//!
+//! fn route(pa10: Pin<PA10, PushPullOutput>) {
+//!     let _ = pa10.into_function::<GclkIo0>();
+//! }
+//! ```
+//!
+//! Likewise, `PB13` is not connected to any `TCC4` output:
+//!
+//! ```compile_fail
+//! use atsamd_hal::gpio::function::Tcc4Wo1;
+//! use atsamd_hal::gpio::{Pin, PushPullOutput, PB13};
+//!
+//! fn route(pb13: Pin<PB13, PushPullOutput>) {
+//!     let _ = pb13.into_function::<Tcc4Wo1>();
+//! }
+//! ```
+//!
+//! Generic code can use [`HasFunction`] as a bound, in the same way the
+//! SERCOM `GetPad` trait is used to check pads.
+//!
+//! The table currently covers the `GCLK_IO`, `TC` and `TCC` waveform output
+//! signals of SAMx5x chips. On SAMD11 and SAMD21 chips, [`Function`] and
+//! [`HasFunction`] exist but no function is defined yet, and
+//! [`Pin::into_alternate`] must be used directly.
+
+use atsamd_hal_macros::hal_cfg;
+#[hal_cfg("port-d5x")]
+use paste::paste;
+
+use crate::typelevel::Sealed;
+
+use super::{Alternate, AlternateConfig, Pin, PinId, PinMode};
+
+//==============================================================================
+// Function
+//==============================================================================
+
+/// Type-level enum of peripheral signals which can be routed to a pin
+///
+/// See the [module-level documentation](self) for more details.
+pub trait Function: Sealed {}
+
+/// Mapping from a [`PinId`] to the [`AlternateConfig`] which routes
+/// [`Function`] `F` to it
+///
+/// This trait is only implemented when the pin can carry `F`.
+pub trait HasFunction<F: Function>: PinId {
+    /// Mux position selecting `F` on this pin
+    type Mode: AlternateConfig;
+}
+
+impl<I: PinId, M: PinMode> Pin<I, M> {
+    /// Configure the pin to carry the peripheral signal `F`
+    ///
+    /// Unlike [`into_alternate`](Self::into_alternate), the mux position is
+    /// looked up in the pin-mux table, and pins which can't carry `F` are
+    /// rejected at compile-time.
+    #[inline]
+    pub fn into_function<F: Function>(self) -> Pin<I, Alternate<<I as HasFunction<F>>::Mode>>
+    where
+        I: HasFunction<F>,
+    {
+        self.into_alternate()
+    }
+}
+
+#[hal_cfg("port-d5x")]
+macro_rules! function {
+    (
+        $(
+            $Func:ident: $signal:literal
+        ),+ $(,)?
+    ) => {
+        paste! {
+            $(
+                #[doc = "Type-level variant of [`Function`] for the `" $signal "` signal"]
+                pub enum $Func {}
+                impl Sealed for $Func {}
+                impl Function for $Func {}
+            )+
+        }
+    };
+}
+
+//==============================================================================
+// Functions
+//==============================================================================
+
+#[hal_cfg("gclk-d5x")]
+function!(
+    GclkIo0: "GCLK_IO[0]",
+    GclkIo1: "GCLK_IO[1]",
+    GclkIo2: "GCLK_IO[2]",
+    GclkIo3: "GCLK_IO[3]",
+    GclkIo4: "GCLK_IO[4]",
+    GclkIo5: "GCLK_IO[5]",
+    GclkIo6: "GCLK_IO[6]",
+    GclkIo7: "GCLK_IO[7]",
+);
+
+#[hal_cfg("tc0-d5x")]
+function!(Tc0Wo0: "TC0/WO[0]", Tc0Wo1: "TC0/WO[1]");
+
+#[hal_cfg("tc1-d5x")]
+function!(Tc1Wo0: "TC1/WO[0]", Tc1Wo1: "TC1/WO[1]");
+
+#[hal_cfg("tc2-d5x")]
+function!(Tc2Wo0: "TC2/WO[0]", Tc2Wo1: "TC2/WO[1]");
+
+#[hal_cfg("tc3-d5x")]
+function!(Tc3Wo0: "TC3/WO[0]", Tc3Wo1: "TC3/WO[1]");
+
+#[hal_cfg("tc4-d5x")]
+function!(Tc4Wo0: "TC4/WO[0]", Tc4Wo1: "TC4/WO[1]");
+
+#[hal_cfg("tc5-d5x")]
+function!(Tc5Wo0: "TC5/WO[0]", Tc5Wo1: "TC5/WO[1]");
+
+#[hal_cfg("tc6-d5x")]
+function!(Tc6Wo0: "TC6/WO[0]", Tc6Wo1: "TC6/WO[1]");
+
+#[hal_cfg("tc7-d5x")]
+function!(Tc7Wo0: "TC7/WO[0]", Tc7Wo1: "TC7/WO[1]");
+
+#[hal_cfg("tcc0-d5x")]
+function!(
+    Tcc0Wo0: "TCC0/WO[0]",
+    Tcc0Wo1: "TCC0/WO[1]",
+    Tcc0Wo2: "TCC0/WO[2]",
+    Tcc0Wo3: "TCC0/WO[3]",
+    Tcc0Wo4: "TCC0/WO[4]",
+    Tcc0Wo5: "TCC0/WO[5]",
+    Tcc0Wo6: "TCC0/WO[6]",
+    Tcc0Wo7: "TCC0/WO[7]",
+);
+
+#[hal_cfg("tcc1-d5x")]
+function!(
+    Tcc1Wo0: "TCC1/WO[0]",
+    Tcc1Wo1: "TCC1/WO[1]",
+    Tcc1Wo2: "TCC1/WO[2]",
+    Tcc1Wo3: "TCC1/WO[3]",
+    Tcc1Wo4: "TCC1/WO[4]",
+    Tcc1Wo5: "TCC1/WO[5]",
+    Tcc1Wo6: "TCC1/WO[6]",
+    Tcc1Wo7: "TCC1/WO[7]",
+);
+
+#[hal_cfg("tcc2-d5x")]
+function!(
+    Tcc2Wo0: "TCC2/WO[0]",
+    Tcc2Wo1: "TCC2/WO[1]",
+    Tcc2Wo2: "TCC2/WO[2]",
+);
+
+#[hal_cfg("tcc3-d5x")]
+function!(
+    Tcc3Wo0: "TCC3/WO[0]",
+    Tcc3Wo1: "TCC3/WO[1]",
+);
+
+#[hal_cfg("tcc4-d5x")]
+function!(
+    Tcc4Wo0: "TCC4/WO[0]",
+    Tcc4Wo1: "TCC4/WO[1]",
+);
+
+//==============================================================================
+// Pin-mux table
+//==============================================================================
+
+// These implementations are much easier to read with `#[rustfmt::skip]`
+#[rustfmt::skip]
+#[hal_cfg("port-d5x")]
+mod function_impl {
+    use atsamd_hal_macros::hal_cfg;
+
+    use super::*;
+    use crate::gpio::{self, E, F, G, M};
+
+
+    #[hal_cfg("pa10")]
+    impl HasFunction<GclkIo4> for gpio::PA10 { type Mode = M; }
+    #[hal_cfg("pa11")]
+    impl HasFunction<GclkIo5> for gpio::PA11 { type Mode = M; }
+    #[hal_cfg("pa14")]
+    impl HasFunction<GclkIo0> for gpio::PA14 { type Mode = M; }
+    #[hal_cfg("pa15")]
+    impl HasFunction<GclkIo1> for gpio::PA15 { type Mode = M; }
+    #[hal_cfg("pa16")]
+    impl HasFunction<GclkIo2> for gpio::PA16 { type Mode = M; }
+    #[hal_cfg("pa17")]
+    impl HasFunction<GclkIo3> for gpio::PA17 { type Mode = M; }
+    #[hal_cfg("pa27")]
+    impl HasFunction<GclkIo1> for gpio::PA27 { type Mode = M; }
+    #[hal_cfg("pa30")]
+    impl HasFunction<GclkIo0> for gpio::PA30 { type Mode = M; }
+    #[hal_cfg("pb10")]
+    impl HasFunction<GclkIo4> for gpio::PB10 { type Mode = M; }
+    #[hal_cfg("pb11")]
+    impl HasFunction<GclkIo5> for gpio::PB11 { type Mode = M; }
+    #[hal_cfg("pb12")]
+    impl HasFunction<GclkIo6> for gpio::PB12 { type Mode = M; }
+    #[hal_cfg("pb13")]
+    impl HasFunction<GclkIo7> for gpio::PB13 { type Mode = M; }
+    #[hal_cfg("pb14")]
+    impl HasFunction<GclkIo0> for gpio::PB14 { type Mode = M; }
+    #[hal_cfg("pb15")]
+    impl HasFunction<GclkIo1> for gpio::PB15 { type Mode = M; }
+    #[hal_cfg("pb16")]
+    impl HasFunction<GclkIo2> for gpio::PB16 { type Mode = M; }
+    #[hal_cfg("pb17")]
+    impl HasFunction<GclkIo3> for gpio::PB17 { type Mode = M; }
+    #[hal_cfg("pb18")]
+    impl HasFunction<GclkIo4> for gpio::PB18 { type Mode = M; }
+    #[hal_cfg("pb19")]
+    impl HasFunction<GclkIo5> for gpio::PB19 { type Mode = M; }
+    #[hal_cfg("pb20")]
+    impl HasFunction<GclkIo6> for gpio::PB20 { type Mode = M; }
+    #[hal_cfg("pb21")]
+    impl HasFunction<GclkIo7> for gpio::PB21 { type Mode = M; }
+    #[hal_cfg("pb22")]
+    impl HasFunction<GclkIo0> for gpio::PB22 { type Mode = M; }
+    #[hal_cfg("pb23")]
+    impl HasFunction<GclkIo1> for gpio::PB23 { type Mode = M; }
+
+    #[hal_cfg(all("tc0", "pa04"))]
+    impl HasFunction<Tc0Wo0> for gpio::PA04 { type Mode = E; }
+    #[hal_cfg(all("tc0", "pa05"))]
+    impl HasFunction<Tc0Wo1> for gpio::PA05 { type Mode = E; }
+    #[hal_cfg(all("tc0", "pa08"))]
+    impl HasFunction<Tc0Wo0> for gpio::PA08 { type Mode = E; }
+    #[hal_cfg(all("tc0", "pa09"))]
+    impl HasFunction<Tc0Wo1> for gpio::PA09 { type Mode = E; }
+    #[hal_cfg(all("tc0", "pb30"))]
+    impl HasFunction<Tc0Wo0> for gpio::PB30 { type Mode = E; }
+    #[hal_cfg(all("tc0", "pb31"))]
+    impl HasFunction<Tc0Wo1> for gpio::PB31 { type Mode = E; }
+    #[hal_cfg(all("tc1", "pa06"))]
+    impl HasFunction<Tc1Wo0> for gpio::PA06 { type Mode = E; }
+    #[hal_cfg(all("tc1", "pa07"))]
+    impl HasFunction<Tc1Wo1> for gpio::PA07 { type Mode = E; }
+    #[hal_cfg(all("tc1", "pa10"))]
+    impl HasFunction<Tc1Wo0> for gpio::PA10 { type Mode = E; }
+    #[hal_cfg(all("tc1", "pa11"))]
+    impl HasFunction<Tc1Wo1> for gpio::PA11 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa00"))]
+    impl HasFunction<Tc2Wo0> for gpio::PA00 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa01"))]
+    impl HasFunction<Tc2Wo1> for gpio::PA01 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa12"))]
+    impl HasFunction<Tc2Wo0> for gpio::PA12 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa13"))]
+    impl HasFunction<Tc2Wo1> for gpio::PA13 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa16"))]
+    impl HasFunction<Tc2Wo0> for gpio::PA16 { type Mode = E; }
+    #[hal_cfg(all("tc2", "pa17"))]
+    impl HasFunction<Tc2Wo1> for gpio::PA17 { type Mode = E; }
+    #[hal_cfg(all("tc3", "pa14"))]
+    impl HasFunction<Tc3Wo0> for gpio::PA14 { type Mode = E; }
+    #[hal_cfg(all("tc3", "pa15"))]
+    impl HasFunction<Tc3Wo1> for gpio::PA15 { type Mode = E; }
+    #[hal_cfg(all("tc3", "pa18"))]
+    impl HasFunction<Tc3Wo0> for gpio::PA18 { type Mode = E; }
+    #[hal_cfg(all("tc3", "pa19"))]
+    impl HasFunction<Tc3Wo1> for gpio::PA19 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pa22"))]
+    impl HasFunction<Tc4Wo0> for gpio::PA22 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pa23"))]
+    impl HasFunction<Tc4Wo1> for gpio::PA23 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pb08"))]
+    impl HasFunction<Tc4Wo0> for gpio::PB08 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pb09"))]
+    impl HasFunction<Tc4Wo1> for gpio::PB09 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pb12"))]
+    impl HasFunction<Tc4Wo0> for gpio::PB12 { type Mode = E; }
+    #[hal_cfg(all("tc4", "pb13"))]
+    impl HasFunction<Tc4Wo1> for gpio::PB13 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pa24"))]
+    impl HasFunction<Tc5Wo0> for gpio::PA24 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pa25"))]
+    impl HasFunction<Tc5Wo1> for gpio::PA25 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pb10"))]
+    impl HasFunction<Tc5Wo0> for gpio::PB10 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pb11"))]
+    impl HasFunction<Tc5Wo1> for gpio::PB11 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pb14"))]
+    impl HasFunction<Tc5Wo0> for gpio::PB14 { type Mode = E; }
+    #[hal_cfg(all("tc5", "pb15"))]
+    impl HasFunction<Tc5Wo1> for gpio::PB15 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pb02"))]
+    impl HasFunction<Tc6Wo0> for gpio::PB02 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pb03"))]
+    impl HasFunction<Tc6Wo1> for gpio::PB03 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pb16"))]
+    impl HasFunction<Tc6Wo0> for gpio::PB16 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pb17"))]
+    impl HasFunction<Tc6Wo1> for gpio::PB17 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pa30"))]
+    impl HasFunction<Tc6Wo0> for gpio::PA30 { type Mode = E; }
+    #[hal_cfg(all("tc6", "pa31"))]
+    impl HasFunction<Tc6Wo1> for gpio::PA31 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pa20"))]
+    impl HasFunction<Tc7Wo0> for gpio::PA20 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pa21"))]
+    impl HasFunction<Tc7Wo1> for gpio::PA21 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pb22"))]
+    impl HasFunction<Tc7Wo0> for gpio::PB22 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pb23"))]
+    impl HasFunction<Tc7Wo1> for gpio::PB23 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pb00"))]
+    impl HasFunction<Tc7Wo0> for gpio::PB00 { type Mode = E; }
+    #[hal_cfg(all("tc7", "pb01"))]
+    impl HasFunction<Tc7Wo1> for gpio::PB01 { type Mode = E; }
+
+    #[hal_cfg(all("tcc0", "pa08"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PA08 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa09"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PA09 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa10"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PA10 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa11"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PA11 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa12"))]
+    impl HasFunction<Tcc0Wo6> for gpio::PA12 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa13"))]
+    impl HasFunction<Tcc0Wo7> for gpio::PA13 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pa16"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PA16 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa17"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PA17 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa18"))]
+    impl HasFunction<Tcc0Wo6> for gpio::PA18 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa19"))]
+    impl HasFunction<Tcc0Wo7> for gpio::PA19 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa20"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PA20 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa21"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PA21 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa22"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PA22 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pa23"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PA23 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb10"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PB10 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pb11"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PB11 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pb12"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PB12 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb13"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PB13 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb14"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PB14 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb15"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PB15 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb16"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PB16 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb17"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PB17 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb30"))]
+    impl HasFunction<Tcc0Wo6> for gpio::PB30 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pb31"))]
+    impl HasFunction<Tcc0Wo7> for gpio::PB31 { type Mode = G; }
+    #[hal_cfg(all("tcc0", "pc04"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PC04 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc10"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PC10 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc11"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PC11 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc12"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PC12 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc13"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PC13 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc14"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PC14 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc15"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PC15 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc16"))]
+    impl HasFunction<Tcc0Wo0> for gpio::PC16 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc17"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PC17 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc18"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PC18 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc19"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PC19 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc20"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PC20 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc21"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PC21 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc22"))]
+    impl HasFunction<Tcc0Wo6> for gpio::PC22 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pc23"))]
+    impl HasFunction<Tcc0Wo7> for gpio::PC23 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pd08"))]
+    impl HasFunction<Tcc0Wo1> for gpio::PD08 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pd09"))]
+    impl HasFunction<Tcc0Wo2> for gpio::PD09 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pd10"))]
+    impl HasFunction<Tcc0Wo3> for gpio::PD10 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pd11"))]
+    impl HasFunction<Tcc0Wo4> for gpio::PD11 { type Mode = F; }
+    #[hal_cfg(all("tcc0", "pd12"))]
+    impl HasFunction<Tcc0Wo5> for gpio::PD12 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa08"))]
+    impl HasFunction<Tcc1Wo4> for gpio::PA08 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa09"))]
+    impl HasFunction<Tcc1Wo5> for gpio::PA09 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa10"))]
+    impl HasFunction<Tcc1Wo6> for gpio::PA10 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa11"))]
+    impl HasFunction<Tcc1Wo7> for gpio::PA11 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa12"))]
+    impl HasFunction<Tcc1Wo2> for gpio::PA12 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa13"))]
+    impl HasFunction<Tcc1Wo3> for gpio::PA13 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa14"))]
+    impl HasFunction<Tcc1Wo2> for gpio::PA14 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa15"))]
+    impl HasFunction<Tcc1Wo3> for gpio::PA15 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pa16"))]
+    impl HasFunction<Tcc1Wo0> for gpio::PA16 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa17"))]
+    impl HasFunction<Tcc1Wo1> for gpio::PA17 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa18"))]
+    impl HasFunction<Tcc1Wo2> for gpio::PA18 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa19"))]
+    impl HasFunction<Tcc1Wo3> for gpio::PA19 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa20"))]
+    impl HasFunction<Tcc1Wo4> for gpio::PA20 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa21"))]
+    impl HasFunction<Tcc1Wo5> for gpio::PA21 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa22"))]
+    impl HasFunction<Tcc1Wo6> for gpio::PA22 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pa23"))]
+    impl HasFunction<Tcc1Wo7> for gpio::PA23 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb10"))]
+    impl HasFunction<Tcc1Wo0> for gpio::PB10 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pb11"))]
+    impl HasFunction<Tcc1Wo1> for gpio::PB11 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pb18"))]
+    impl HasFunction<Tcc1Wo0> for gpio::PB18 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb19"))]
+    impl HasFunction<Tcc1Wo1> for gpio::PB19 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb20"))]
+    impl HasFunction<Tcc1Wo2> for gpio::PB20 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb21"))]
+    impl HasFunction<Tcc1Wo3> for gpio::PB21 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb26"))]
+    impl HasFunction<Tcc1Wo2> for gpio::PB26 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb27"))]
+    impl HasFunction<Tcc1Wo3> for gpio::PB27 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb28"))]
+    impl HasFunction<Tcc1Wo4> for gpio::PB28 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pb29"))]
+    impl HasFunction<Tcc1Wo5> for gpio::PB29 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pc10"))]
+    impl HasFunction<Tcc1Wo4> for gpio::PC10 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pc11"))]
+    impl HasFunction<Tcc1Wo5> for gpio::PC11 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pc12"))]
+    impl HasFunction<Tcc1Wo6> for gpio::PC12 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pc13"))]
+    impl HasFunction<Tcc1Wo7> for gpio::PC13 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pc14"))]
+    impl HasFunction<Tcc1Wo0> for gpio::PC14 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pc15"))]
+    impl HasFunction<Tcc1Wo1> for gpio::PC15 { type Mode = G; }
+    #[hal_cfg(all("tcc1", "pd20"))]
+    impl HasFunction<Tcc1Wo0> for gpio::PD20 { type Mode = F; }
+    #[hal_cfg(all("tcc1", "pd21"))]
+    impl HasFunction<Tcc1Wo1> for gpio::PD21 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pa14"))]
+    impl HasFunction<Tcc2Wo0> for gpio::PA14 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pa15"))]
+    impl HasFunction<Tcc2Wo1> for gpio::PA15 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pa24"))]
+    impl HasFunction<Tcc2Wo2> for gpio::PA24 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pa30"))]
+    impl HasFunction<Tcc2Wo0> for gpio::PA30 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pa31"))]
+    impl HasFunction<Tcc2Wo1> for gpio::PA31 { type Mode = F; }
+    #[hal_cfg(all("tcc2", "pb02"))]
+    impl HasFunction<Tcc2Wo2> for gpio::PB02 { type Mode = F; }
+    #[hal_cfg(all("tcc3", "pb12"))]
+    impl HasFunction<Tcc3Wo0> for gpio::PB12 { type Mode = F; }
+    #[hal_cfg(all("tcc3", "pb13"))]
+    impl HasFunction<Tcc3Wo1> for gpio::PB13 { type Mode = F; }
+    #[hal_cfg(all("tcc3", "pb16"))]
+    impl HasFunction<Tcc3Wo0> for gpio::PB16 { type Mode = F; }
+    #[hal_cfg(all("tcc3", "pb17"))]
+    impl HasFunction<Tcc3Wo1> for gpio::PB17 { type Mode = F; }
+    #[hal_cfg(all("tcc4", "pb14"))]
+    impl HasFunction<Tcc4Wo0> for gpio::PB14 { type Mode = F; }
+    #[hal_cfg(all("tcc4", "pb15"))]
+    impl HasFunction<Tcc4Wo1> for gpio::PB15 { type Mode = F; }
+    #[hal_cfg(all("tcc4", "pb30"))]
+    impl HasFunction<Tcc4Wo0> for gpio::PB30 { type Mode = F; }
+    #[hal_cfg(all("tcc4", "pb31"))]
+    impl HasFunction<Tcc4Wo1> for gpio::PB31 { type Mode = F; }
+}
@@ -30,6 +30,12 @@
 //!   with GPIO pins. See the [`AnyPin`] documentation for more details.
 //!
 //! - Offers a type-erased, [`DynPin`] type, for run-time tracking of pins.
+//! - [`Pins::into_groups`] sorts the pins by group, so that all the pins of a
+//!   port can be handed over to another module at once.
+//! - [`Pins::new_with_safe_outputs`] drives selected pins to a safe level as
+//!   soon as the pins are split, for pins which must not float at boot.
+//! - [`OutputBus`] writes a value to a set of output pins at once, for
+//!   parallel displays and other parallel interfaces.
 //! - Provides a new [`bsp_pins`] macro to help BSP authors provide meaningful
 //!   names and type aliases for their GPIO pins.
 //!
@@ -58,4 +64,10 @@ pub use pin::*;
 pub mod dynpin;
 pub use dynpin::*;
 
+pub mod bus;
+pub use bus::OutputBus;
+
+pub mod function;
+pub use function::{Function, HasFunction};
+
 mod reg;
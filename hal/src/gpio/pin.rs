@@ -62,22 +62,38 @@
 //!
 //! # Embedded HAL traits
 //!
-//! This module implements all of the embedded HAL GPIO traits for each [`Pin`]
-//! in the corresponding [`PinMode`]s, namely: [`InputPin`], [`OutputPin`],
-//! [`ToggleableOutputPin`] and [`StatefulOutputPin`].
+//! This module implements the `embedded-hal` 1.0 GPIO traits for each [`Pin`]
+//! in the corresponding [`PinMode`]s, namely: [`InputPin`], [`OutputPin`] and
+//! [`StatefulOutputPin`]. Toggling is provided by `StatefulOutputPin` itself.
+//! Since a `Pin` encodes its mode in its type, none of these operations can
+//! fail, and the [`ErrorType`] of every `Pin` is [`Infallible`]. Drivers
+//! written against `embedded-hal` 1.0 can therefore take a `Pin` directly:
 //!
-//! For example, you can control the logic level of an `OutputPin` like so
-//!
-//! ```
-//! use atsamd_hal::pac::Peripherals;
+//! ```no_run
+//! use atsamd_hal::ehal::digital::{OutputPin, StatefulOutputPin};
 //! use atsamd_hal::gpio::Pins;
-//! use crate::ehal_02::digital::v2::OutputPin;
+//! use atsamd_hal::pac::Peripherals;
+//!
+//! // A driver which only knows about embedded-hal 1.0
+//! fn blink<P: StatefulOutputPin>(led: &mut P) -> Result<(), P::Error> {
+//!     led.set_high()?;
+//!     led.toggle()
+//! }
 //!
 //! let mut peripherals = Peripherals::take().unwrap();
-//! let mut pins = Pins::new(peripherals.Port);
-//! pins.pa27.set_high();
+//! let pins = Pins::new(peripherals.port);
+//! let mut led = pins.pa27.into_push_pull_output();
+//! // The error type is `Infallible`, so this can't panic
+//! blink(&mut led).unwrap();
 //! ```
 //!
+//! The `embedded-hal` 0.2 traits, including [`ToggleableOutputPin`], are still
+//! implemented for drivers which haven't migrated yet.
+//!
+//! A [`DynPin`] checks its mode at run time instead, so its
+//! implementations of the same traits return a
+//! [`gpio::Error`](super::dynpin::Error) when the pin is in the wrong mode.
+//!
 //! # Type-level features
 //!
 //! This module also provides additional, type-level tools to work with GPIO
@@ -103,7 +119,7 @@ use core::convert::Infallible;
 use core::marker::PhantomData;
 use core::mem::transmute;
 
-use crate::ehal::digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin};
+use crate::ehal::digital::{ErrorType, InputPin, OutputPin, PinState, StatefulOutputPin};
 use paste::paste;
 
 use crate::pac::Port;
@@ -596,12 +612,35 @@ where
         self.into_mode()
     }
 
+    /// Configure the pin to operate as a push-pull output, driving `state`
+    ///
+    /// Unlike [`into_push_pull_output`](Self::into_push_pull_output) followed
+    /// by a call to `set_high` or `set_low`, the output level is set before
+    /// the output driver is enabled, so the pin never briefly drives the
+    /// previous level of its output latch.
+    #[inline]
+    pub fn into_push_pull_output_in_state(mut self, state: PinState) -> Pin<I, PushPullOutput> {
+        self.regs.write_pin(state == PinState::High);
+        self.into_mode()
+    }
+
     /// Configure the pin to operate as a readable push pull output
     #[inline]
     pub fn into_readable_output(self) -> Pin<I, ReadableOutput> {
         self.into_mode()
     }
 
+    /// Configure the pin to operate as a readable push pull output, driving
+    /// `state`
+    ///
+    /// Like [`into_push_pull_output_in_state`](Self::into_push_pull_output_in_state),
+    /// the output level is set before the output driver is enabled.
+    #[inline]
+    pub fn into_readable_output_in_state(mut self, state: PinState) -> Pin<I, ReadableOutput> {
+        self.regs.write_pin(state == PinState::High);
+        self.into_mode()
+    }
+
     /// Configure the pin to operate as the corresponding peripheral function.
     ///
     /// The type `C` indicates the desired peripheral function.
@@ -1073,18 +1112,27 @@ where
 macro_rules! pins{
     (
         $(
-            #[$cfg:meta]
-            $Id:ident,
+            $Group:ident {
+                $(
+                    #[$cfg:meta]
+                    $Id:ident,
+                )+
+            }
         )+
     ) => {
         paste! {
             /// Collection of all the individual [`Pin`]s
+            ///
+            /// Use [`into_groups`](Self::into_groups) to sort the pins by
+            /// group instead.
             pub struct Pins {
                 port: Option<Port>,
                 $(
-                    #[doc = "Pin " $Id]
-                    #[$cfg]
-                    pub [<$Id:lower>]: Pin<$Id, Reset>,
+                    $(
+                        #[doc = "Pin " $Id]
+                        #[$cfg]
+                        pub [<$Id:lower>]: Pin<$Id, Reset>,
+                    )+
                 )+
             }
             impl Pins {
@@ -1097,11 +1145,111 @@ macro_rules! pins{
                         port: Some(port),
                         // Safe because we only create one `Pin` per `PinId`
                         $(
-                            #[$cfg]
-                            [<$Id:lower>]: unsafe { Pin::new() },
+                            $(
+                                #[$cfg]
+                                [<$Id:lower>]: unsafe { Pin::new() },
+                            )+
+                        )+
+                    }
+                }
+                /// Take ownership of the PAC
+                /// [`Port`](crate::pac::Port), drive the given pins to a safe
+                /// level, and split it into discrete [`Pin`]s
+                ///
+                /// Every pin is an input after reset, so a pin controlling a
+                /// relay or a high-side switch floats until it is configured
+                /// as an output. Each of the `safe_outputs` is configured as a
+                /// push-pull output, driving the given level, before any other
+                /// pin is handed out. Call this as early as possible in
+                /// `main`, before configuring the clocks.
+                ///
+                /// The returned pins are still typed as [`Reset`], since it
+                /// is not possible to choose their type from a slice. Every
+                /// mode conversion rewrites the whole configuration of a pin,
+                /// so this is harmless. Converting one of the `safe_outputs`
+                /// with [`Pin::into_push_pull_output`] keeps driving the safe
+                /// level, because that conversion doesn't change the output
+                /// latch.
+                ///
+                /// This only shortens the window during which the pins float.
+                /// They still float during the reset, and until this function
+                /// is called. Truly glitch-free behavior needs an external
+                /// pull resistor towards the safe level.
+                #[inline]
+                pub fn new_with_safe_outputs(
+                    port: Port,
+                    safe_outputs: &[(DynPinId, PinState)],
+                ) -> Pins {
+                    for &(id, state) in safe_outputs {
+                        // Safe because no `Pin` has been created yet
+                        unsafe { drive_unowned_pin(id, state) };
+                    }
+                    Pins::new(port)
+                }
+                /// Take the PAC [`Port`]
+                ///
+                /// The [`Port`] can only be taken once. Subsequent calls to
+                /// this function will panic.
+                ///
+                /// # Safety
+                ///
+                /// Direct access to the [`Port`] could allow you to invalidate
+                /// the compiler's type-level tracking, so it is unsafe.
+                ///
+                /// [`Port`](crate::pac::Port)
+                #[inline]
+                pub unsafe fn port(&mut self) -> Port {
+                    self.port.take().unwrap()
+                }
+                /// Sort the [`Pin`]s by group
+                ///
+                /// This must be called before moving any [`Pin`] out of the
+                /// [`Pins`]. Afterwards, each group can be consumed on its
+                /// own, so a whole group can be handed over to another module
+                /// even after taking a few pins from the other groups.
+                ///
+                /// ```no_run
+                /// # use atsamd_hal::gpio::{GroupB, Pin, Pins, PushPullOutput, PA17};
+                /// # fn init_display(pins: GroupB) {}
+                /// # let peripherals = atsamd_hal::pac::Peripherals::take().unwrap();
+                /// let groups = Pins::new(peripherals.port).into_groups();
+                /// let led: Pin<PA17, PushPullOutput> = groups.a.pa17.into();
+                /// init_display(groups.b);
+                /// ```
+                ///
+                /// The `Pins` struct generated by [`bsp_pins!`](crate::bsp_pins)
+                /// names the pins by function, so it cannot be sorted by group.
+                /// A BSP can still be used with the groups, since its pin
+                /// aliases are plain [`Pin`] types. For example,
+                /// `let led: bsp::RedLed = groups.a.pa17.into();` works in
+                /// place of `pin_alias!(pins.red_led).into()`.
+                #[inline]
+                pub fn into_groups(self) -> PinGroups {
+                    PinGroups {
+                        port: self.port,
+                        $(
+                            [<$Group:lower>]: [<Group $Group>] {
+                                $(
+                                    #[$cfg]
+                                    [<$Id:lower>]: self.[<$Id:lower>],
+                                )+
+                            },
                         )+
                     }
                 }
+            }
+            /// Collection of all the individual [`Pin`]s, sorted by group
+            ///
+            /// Created by [`Pins::into_groups`]. The groups of ports that are
+            /// not present on the selected chip are empty.
+            pub struct PinGroups {
+                port: Option<Port>,
+                $(
+                    #[doc = "Pins of group " $Group]
+                    pub [<$Group:lower>]: [<Group $Group>],
+                )+
+            }
+            impl PinGroups {
                 /// Take the PAC [`Port`]
                 ///
                 /// The [`Port`] can only be taken once. Subsequent calls to
@@ -1118,6 +1266,16 @@ macro_rules! pins{
                     self.port.take().unwrap()
                 }
             }
+            $(
+                #[doc = "The [`Pin`]s of group " $Group]
+                pub struct [<Group $Group>] {
+                    $(
+                        #[doc = "Pin " $Id]
+                        #[$cfg]
+                        pub [<$Id:lower>]: Pin<$Id, Reset>,
+                    )+
+                }
+            )+
         }
     };
 }
@@ -1141,10 +1299,12 @@ macro_rules! declare_pins {
         )+
         pins!(
             $(
-                $(
-                    #[$cfg]
-                    $Id,
-                )+
+                $Group {
+                    $(
+                        #[$cfg]
+                        $Id,
+                    )+
+                }
             )+
         );
     };
@@ -1450,6 +1610,17 @@ declare_pins!(
 /// let tx = pin_alias!(pins.uart_tx);
 /// ```
 ///
+/// Buses and groups of pins can be taken at once. Wrapping the names in
+/// parentheses returns a tuple of [`Pin`]s, while wrapping them in brackets
+/// returns an array of [`DynPin`](crate::gpio::DynPin)s, in the given order:
+///
+/// ```
+/// let mut peripherals = pac::Peripherals::take().unwrap();
+/// let pins = bsp::Pins::new(peripherals.Port);
+/// let (mosi, tx) = pin_alias!(pins.(spi_mosi, uart_tx));
+/// let data: [DynPin; 4] = pin_alias!(pins.[d0, d1, d2, d3]);
+/// ```
+///
 /// ### Type aliases
 ///
 /// Next, the macro defines several useful type aliases for each pin. It
@@ -1638,7 +1809,29 @@ macro_rules! __declare_pins_type {
             /// [`Pins`](atsamd_hal::gpio::Pins)
             #[inline]
             pub fn new(port: $crate::pac::Port) -> Self {
-                let mut pins = $crate::gpio::Pins::new(port);
+                Self::from_hal_pins($crate::gpio::Pins::new(port))
+            }
+
+            /// Take ownership of the PAC [`Port`], drive the given pins to a
+            /// safe level, and split it into discrete [`Pin`]s.
+            ///
+            /// See
+            /// [`Pins::new_with_safe_outputs`](atsamd_hal::gpio::Pins::new_with_safe_outputs)
+            /// for details. The `DynPinId` of each pin alias is available as
+            /// a constant, named after the alias.
+            ///
+            /// [`Port`](atsamd_hal::pac::Port)
+            /// [`Pin`](atsamd_hal::gpio::Pin)
+            #[inline]
+            pub fn new_with_safe_outputs(
+                port: $crate::pac::Port,
+                safe_outputs: &[($crate::gpio::DynPinId, $crate::ehal::digital::PinState)],
+            ) -> Self {
+                Self::from_hal_pins($crate::gpio::Pins::new_with_safe_outputs(port, safe_outputs))
+            }
+
+            #[inline]
+            fn from_hal_pins(mut pins: $crate::gpio::Pins) -> Self {
                 Self {
                     port: Some(unsafe{ pins.port() }),
                     $(
@@ -1727,49 +1920,83 @@ macro_rules! __define_pin_alias_macro {
             }
         )+
     ) => {
-        $crate::paste::paste! {
-            /// Refer to fields of the [`Pins`] struct by alternate names
-            ///
-            /// This macro can be used to access fields of the [`Pins`] struct
-            /// by alternate names. See the `Pins` documentation for a list of
-            /// the availabe pin aliases.
-            ///
-            /// For example. suppose `spi_mosi` were an alternate name for the
-            /// `serial_out` pin of the `Pins` struct. You could use the
-            /// `pin_alias!` macro to access it like this:
-            ///
-            /// ```
-            /// let mut peripherals = pac::Peripherals::take().unwrap();
-            /// let pins = bsp::Pins::new(peripherals.Port);
-            /// // Replace this
-            /// let mosi = pins.serial_out;
-            /// // With this
-            /// let mosi = pin_alias!(pins.spi_mosi);
-            /// ```
-            #[macro_export]
-            macro_rules! pin_alias {
-                $(
-                    // Always provide an identity "alias"
-                    ( $pins:ident . $name ) => { $pins.$name };
-                )+
-                $(
-                    $(
-                        ( $pins:ident . $alias ) => {
-                            {
-                                // Since attributes can't apply to expressions, only
-                                // items, apply any attributes to a dummy macro. This
-                                // lets us ensure the alias is only valid when the
-                                // corresponding attributes are valid.
-                                $( #[$attr] )*
-                                macro_rules! [<pin_alias_ $alias>] {
-                                    () => { $pins.$name };
-                                }
-                                [<pin_alias_ $alias>]!()
-                            }
+        // The grouped forms of `pin_alias!` repeat over their arguments, which
+        // requires a `$` token in the transcriber of this macro
+        $crate::__with_dollar_sign! {
+            ($d:tt) => {
+                $crate::paste::paste! {
+                    /// Refer to fields of the [`Pins`] struct by alternate names
+                    ///
+                    /// This macro can be used to access fields of the [`Pins`] struct
+                    /// by alternate names. See the `Pins` documentation for a list of
+                    /// the availabe pin aliases.
+                    ///
+                    /// For example. suppose `spi_mosi` were an alternate name for the
+                    /// `serial_out` pin of the `Pins` struct. You could use the
+                    /// `pin_alias!` macro to access it like this:
+                    ///
+                    /// ```
+                    /// let mut peripherals = pac::Peripherals::take().unwrap();
+                    /// let pins = bsp::Pins::new(peripherals.Port);
+                    /// // Replace this
+                    /// let mosi = pins.serial_out;
+                    /// // With this
+                    /// let mosi = pin_alias!(pins.spi_mosi);
+                    /// ```
+                    ///
+                    /// Several pins can be taken at once, either as a tuple of
+                    /// typed [`Pin`]s, or as an array of
+                    /// [`DynPin`](atsamd_hal::gpio::DynPin)s:
+                    ///
+                    /// ```
+                    /// let (mosi, sclk) = pin_alias!(pins.(spi_mosi, spi_sclk));
+                    /// let bus: [DynPin; 4] = pin_alias!(pins.[d0, d1, d2, d3]);
+                    /// ```
+                    ///
+                    /// The grouped forms expand to one `pin_alias!` call per
+                    /// pin, so this macro must be in scope under its own name.
+                    #[macro_export]
+                    macro_rules! pin_alias {
+                        ( $d pins:ident . ( $d ( $d field:ident ),+ $d (,)? ) ) => {
+                            ( $d ( pin_alias!( $d pins . $d field ) ),+ )
                         };
-                    )*
-                )+
+                        ( $d pins:ident . [ $d ( $d field:ident ),+ $d (,)? ] ) => {
+                            [ $d ( $crate::gpio::DynPin::from(pin_alias!( $d pins . $d field )) ),+ ]
+                        };
+                        $(
+                            // Always provide an identity "alias"
+                            ( $d pins:ident . $name ) => { $d pins.$name };
+                        )+
+                        $(
+                            $(
+                                ( $d pins:ident . $alias ) => {
+                                    {
+                                        // Since attributes can't apply to expressions, only
+                                        // items, apply any attributes to a dummy macro. This
+                                        // lets us ensure the alias is only valid when the
+                                        // corresponding attributes are valid.
+                                        $( #[$attr] )*
+                                        macro_rules! [<pin_alias_ $alias>] {
+                                            () => { $d pins.$name };
+                                        }
+                                        [<pin_alias_ $alias>]!()
+                                    }
+                                };
+                            )*
+                        )+
+                    }
+                }
             }
         }
     }
 }
+
+/// Call the macro rule given as input with a `$` token as its argument
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __with_dollar_sign {
+    ( $( $body:tt )* ) => {
+        macro_rules! __with_dollar_sign_inner { $( $body )* }
+        __with_dollar_sign_inner!($);
+    };
+}
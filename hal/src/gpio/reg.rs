@@ -188,6 +188,37 @@ pub(super) struct GROUP {
     _padding2: [u8; 32],
 }
 
+/// Index of a [`GROUP`] within the [`Port`] register block
+#[inline]
+#[hal_macro_helper]
+pub(super) fn group_index(group: DynGroup) -> usize {
+    match group {
+        DynGroup::A => 0,
+        #[hal_cfg("pin-group-b")]
+        DynGroup::B => 1,
+        #[hal_cfg("pin-group-c")]
+        DynGroup::C => 2,
+        #[hal_cfg("pin-group-d")]
+        DynGroup::D => 3,
+    }
+}
+
+/// Drive the output pins of `group` selected by `mask` to the levels in
+/// `value`, with a single write
+///
+/// The pins that must change are toggled through `OUTTGL`, so they all change
+/// at once, and the other pins of the group are left untouched.
+///
+/// # Safety
+///
+/// The caller must own every pin selected by `mask`.
+#[inline]
+pub(super) unsafe fn write_group_outputs(group: DynGroup, mask: u32, value: u32) {
+    let group = &*(Port::ptr() as *const GROUP).add(group_index(group));
+    let toggle = (group.out.read().bits() ^ value) & mask;
+    group.outtgl.write(|w| w.bits(toggle));
+}
+
 //==============================================================================
 //  RegisterInterface
 //==============================================================================
@@ -231,22 +262,12 @@ pub(super) unsafe trait RegisterInterface {
     const GROUPS: *const GROUP = Port::ptr() as *const _;
 
     #[inline]
-    #[hal_macro_helper]
     fn group(&self) -> &GROUP {
-        let offset = match self.id().group {
-            DynGroup::A => 0,
-            #[hal_cfg("pin-group-b")]
-            DynGroup::B => 1,
-            #[hal_cfg("pin-group-c")]
-            DynGroup::C => 2,
-            #[hal_cfg("pin-group-d")]
-            DynGroup::D => 3,
-        };
         // Safety: It is safe to create shared references to each PAC register
         // or register block, because all registers are wrapped in
         // `UnsafeCell`s. We should never create unique references to the
         // registers, to prevent any risk of UB.
-        unsafe { &*Self::GROUPS.add(offset) }
+        unsafe { &*Self::GROUPS.add(group_index(self.id().group)) }
     }
 
     #[inline]
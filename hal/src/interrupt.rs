@@ -1,4 +1,40 @@
 //! Primitives for manipulating interrupts
+//!
+//! # Critical sections
+//!
+//! The HAL, and many crates built on it, use the [`critical_section`] crate
+//! to access state shared with interrupt handlers. That crate only defines the
+//! API: exactly one crate in the final binary has to provide the
+//! implementation, otherwise linking fails with undefined
+//! `_critical_section_1_0_acquire` and `_critical_section_1_0_release`
+//! symbols.
+//!
+//! All supported chips are single core, so the `critical-section-single-core`
+//! feature of the HAL provides a suitable implementation, by enabling the one
+//! in the `cortex-m` crate. Acquiring a critical section saves the current
+//! state of `PRIMASK`, and disables all interrupts. Releasing it restores the
+//! saved state, rather than unconditionally enabling interrupts. This means
+//! that:
+//!
+//! * Critical sections can be nested. Interrupts stay disabled until the
+//!   outermost one is released.
+//! * Critical sections can be taken from interrupt handlers, including the
+//!   `InterruptHandler`s used by the async APIs of the HAL. A single core can't
+//!   wait for a critical section held by an interrupted context, so this
+//!   doesn't deadlock: the handler runs its critical section to completion, and
+//!   returns with interrupts in the same state as on entry.
+//! * Interrupts which become pending while a critical section is held are
+//!   serviced as soon as it is released, and are not lost.
+//!
+//! Since the implementation disables all interrupts, critical sections should
+//! be kept short: they delay every interrupt by their duration, regardless of
+//! its priority.
+//!
+//! Only one implementation may be linked. Board support crates usually enable
+//! the `critical-section-single-core` feature of `cortex-m` directly, which is
+//! equivalent, and may be combined with this feature. Applications using
+//! another implementation, such as the one provided by RTIC or a multi-core
+//! aware one, should leave this feature disabled.
 
 use core::sync::atomic::{compiler_fence, Ordering};
 
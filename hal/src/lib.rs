@@ -71,7 +71,7 @@ macro_rules! dbgprint {
     ($($arg:tt)*) => {{}};
 }
 
-#[cfg(feature = "async")]
+#[cfg(feature = "device")]
 pub mod async_hal;
 
 #[cfg(feature = "device")]
@@ -86,6 +86,8 @@ pub mod prelude;
 pub mod rtc;
 #[cfg(feature = "device")]
 pub mod sercom;
+#[cfg(feature = "device")]
+pub mod sleep;
 pub mod sleeping_delay;
 pub mod time;
 pub mod timer_params;
@@ -0,0 +1,297 @@
+//! Async APIs for the ADC.
+//!
+//! Use [`Adc::into_future`] to convert a regular [`Adc`] into an asynchronous
+//! [`AdcFuture`], which can perform single conversions, or wait for the
+//! window monitor to trigger, while the CPU sleeps.
+
+use core::{future::poll_fn, marker::PhantomData, ops::Deref, task::Poll};
+
+use atsamd_hal_macros::hal_cfg;
+use embassy_sync::waitqueue::AtomicWaker;
+
+use crate::{
+    async_hal::interrupts::{Binding, Handler, InterruptSource},
+    ehal_02::adc::Channel,
+    pac,
+    typelevel::Sealed,
+};
+
+use super::{
+    disable_window_monitor, enable_single_conversion, enable_window_monitor, start_free_running,
+    stop_free_running, stop_single_conversion, trigger_conversion, DISCARD_FIRST_CONVERSION,
+};
+use super::{Adc, WindowMode};
+
+#[hal_cfg(any("adc-d11", "adc-d21"))]
+type RegBlock = pac::adc::RegisterBlock;
+
+#[hal_cfg("adc-d5x")]
+type RegBlock = pac::adc0::RegisterBlock;
+
+/// Trait enabling the use of an ADC in async mode. Specifically, this trait
+/// enables us to register an ADC interrupt as a waker for ADC futures.
+///
+/// **⚠️ Warning** This trait should not be implemented outside of this crate!
+pub trait AsyncAdc: Deref<Target = RegBlock> + Sealed {
+    /// Index of this ADC in the `STATE` tracker
+    const STATE_ID: usize;
+
+    /// Get a reference to the ADC's register block
+    fn reg_block(peripherals: &pac::Peripherals) -> &RegBlock;
+
+    /// Interrupt type for this ADC
+    ///
+    /// On SAMx5x chips, the result ready interrupt has its own vector, so this
+    /// covers both the `ADCn_OTHER` and `ADCn_RESRDY` vectors.
+    type Interrupt: InterruptSource;
+}
+
+/// Interrupt handler for async ADC operations
+pub struct InterruptHandler<A: AsyncAdc> {
+    _private: (),
+    _adc: PhantomData<A>,
+}
+
+impl<A: AsyncAdc> Sealed for InterruptHandler<A> {}
+
+impl<A: AsyncAdc> Handler<A::Interrupt> for InterruptHandler<A> {
+    /// Callback function when the corresponding ADC interrupt is fired
+    ///
+    /// # Safety
+    ///
+    /// This method may [`steal`](crate::pac::Peripherals::steal) the `ADC`
+    /// peripheral instance to check the interrupt flags. The only
+    /// modification it is allowed to apply to the peripheral is to disable
+    /// the window monitor and result ready interrupts (to prevent re-firing).
+    /// This method should
+    /// ONLY be able to be called while an [`AdcFuture`] holds an unique
+    /// reference to the underlying `ADC` peripheral.
+    unsafe fn on_interrupt() {
+        let periph = unsafe { crate::pac::Peripherals::steal() };
+        let adc = A::reg_block(&periph);
+
+        if adc.intflag().read().winmon().bit_is_set() {
+            // Leave the flag set, so that the future can see it
+            adc.intenclr().write(|w| w.winmon().set_bit());
+            STATE[A::STATE_ID].wake();
+        }
+
+        // The result ready flag is also set in free-running mode, so only wake
+        // when a single conversion enabled its interrupt
+        if adc.intflag().read().resrdy().bit_is_set() && adc.intenset().read().resrdy().bit_is_set()
+        {
+            adc.intenclr().write(|w| w.resrdy().set_bit());
+            STATE[A::STATE_ID].wake();
+        }
+    }
+}
+
+macro_rules! impl_async_adc {
+    ($ADC: ident, $Interrupt: ident, $id: expr) => {
+        paste::paste! {
+            impl AsyncAdc for pac::$ADC {
+                const STATE_ID: usize = $id;
+
+                type Interrupt = crate::async_hal::interrupts::$Interrupt;
+
+                fn reg_block(peripherals: &pac::Peripherals) -> &RegBlock {
+                    &peripherals.[< $ADC:lower >]
+                }
+            }
+
+            impl Sealed for pac::$ADC {}
+        }
+    };
+}
+
+#[hal_cfg(any("adc-d11", "adc-d21"))]
+impl_async_adc!(Adc, ADC, 0);
+
+#[hal_cfg("adc-d5x")]
+impl_async_adc!(Adc0, ADC0, 0);
+
+#[hal_cfg("adc-d5x")]
+impl_async_adc!(Adc1, ADC1, 1);
+
+#[hal_cfg(any("adc-d11", "adc-d21"))]
+const NUM_ADCS: usize = 1;
+
+#[hal_cfg("adc-d5x")]
+const NUM_ADCS: usize = 2;
+
+impl<A: AsyncAdc> Adc<A> {
+    /// Transform an [`Adc`] into an [`AdcFuture`]
+    #[inline]
+    pub fn into_future<I>(self, _irq: I) -> AdcFuture<A>
+    where
+        I: Binding<A::Interrupt, InterruptHandler<A>>,
+    {
+        A::Interrupt::unpend();
+        unsafe { A::Interrupt::enable() };
+
+        AdcFuture {
+            adc: self,
+            power_down: true,
+        }
+    }
+}
+
+/// Wrapper around an [`Adc`] with an `async` interface
+pub struct AdcFuture<A: AsyncAdc> {
+    adc: Adc<A>,
+    power_down: bool,
+}
+
+impl<A: AsyncAdc> AdcFuture<A> {
+    /// Choose whether [`read`](Self::read) disables the ADC after each
+    /// conversion
+    ///
+    /// The ADC is disabled by default, which saves power between occasional
+    /// readings. Keeping it enabled avoids the startup time, and on SAMD11 and
+    /// SAMD21 the discarded first conversion, when the same pin is read
+    /// repeatedly.
+    #[inline]
+    pub fn set_power_down(&mut self, power_down: bool) {
+        self.power_down = power_down;
+        if power_down {
+            stop_single_conversion(&self.adc.adc);
+        }
+    }
+
+    /// Get whether [`read`](Self::read) disables the ADC after each conversion
+    #[inline]
+    pub fn get_power_down(&self) -> bool {
+        self.power_down
+    }
+
+    /// Perform a single conversion on `pin`, and return its result
+    ///
+    /// The ADC is enabled if needed, a conversion is started, and the task
+    /// sleeps until the result ready interrupt fires. Unless disabled with
+    /// [`set_power_down`](Self::set_power_down), the ADC is disabled again
+    /// before returning. If the future is dropped before completing, the
+    /// conversion is aborted and the ADC is disabled.
+    pub async fn read<PIN>(&mut self, _pin: &mut PIN) -> u16
+    where
+        PIN: Channel<A, ID = u8>,
+    {
+        let adc: &RegBlock = &self.adc.adc;
+        let enabled = enable_single_conversion(adc, PIN::channel());
+        let guard = AbortOnDrop(adc);
+
+        if enabled && DISCARD_FIRST_CONVERSION {
+            convert(adc, A::STATE_ID).await;
+        }
+        let result = convert(adc, A::STATE_ID).await;
+
+        if self.power_down {
+            drop(guard);
+        } else {
+            core::mem::forget(guard);
+        }
+        result
+    }
+
+    /// Configure the window monitor
+    ///
+    /// See [`WindowMode`] for the condition checked in each mode.
+    #[inline]
+    pub fn set_window(&mut self, mode: WindowMode, lower: u16, upper: u16) {
+        enable_window_monitor(&self.adc.adc, mode, lower, upper);
+    }
+
+    /// Disable the window monitor
+    #[inline]
+    pub fn disable_window(&mut self) {
+        disable_window_monitor(&self.adc.adc);
+    }
+
+    /// Sample `pin` continuously until a conversion result meets the window
+    /// monitor condition, and return that result
+    ///
+    /// The ADC runs in free-running mode while waiting, so no CPU involvement
+    /// is needed until the condition is met. The ADC is stopped again before
+    /// returning, or when the future is dropped. The window monitor must have
+    /// been configured with [`set_window`](Self::set_window) beforehand,
+    /// otherwise this never completes.
+    ///
+    /// The returned value is the latest result read after the interrupt was
+    /// serviced. With a fast ADC clock, it can already be a newer sample than
+    /// the one which triggered the window monitor.
+    pub async fn wait_window<PIN>(&mut self, _pin: &mut PIN) -> u16
+    where
+        PIN: Channel<A, ID = u8>,
+    {
+        let adc: &RegBlock = &self.adc.adc;
+        start_free_running(adc, PIN::channel());
+        let _guard = StopOnDrop(adc);
+
+        poll_fn(|cx| {
+            STATE[A::STATE_ID].register(cx.waker());
+            if adc.intflag().read().winmon().bit_is_set() {
+                return Poll::Ready(());
+            }
+
+            // The interrupt handler disables the interrupt when it fires
+            adc.intenset().write(|w| w.winmon().set_bit());
+            Poll::Pending
+        })
+        .await;
+
+        adc.result().read().result().bits()
+    }
+
+    /// Return the underlying [`Adc`]
+    #[inline]
+    pub fn free(self) -> Adc<A> {
+        A::Interrupt::disable();
+        stop_single_conversion(&self.adc.adc);
+        self.adc
+    }
+}
+
+/// Stop the ADC when a [`wait_window`](AdcFuture::wait_window) future
+/// completes or is dropped
+struct StopOnDrop<'a>(&'a RegBlock);
+
+impl Drop for StopOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        stop_free_running(self.0);
+    }
+}
+
+/// Start a single conversion and wait for its result
+async fn convert(adc: &RegBlock, state_id: usize) -> u16 {
+    trigger_conversion(adc);
+
+    poll_fn(|cx| {
+        STATE[state_id].register(cx.waker());
+        if adc.intflag().read().resrdy().bit_is_set() {
+            return Poll::Ready(());
+        }
+
+        // The interrupt handler disables the interrupt when it fires
+        adc.intenset().write(|w| w.resrdy().set_bit());
+        Poll::Pending
+    })
+    .await;
+
+    adc.intenclr().write(|w| w.resrdy().set_bit());
+    adc.result().read().result().bits()
+}
+
+/// Abort the conversion and disable the ADC when a [`read`](AdcFuture::read)
+/// future is dropped, or completes with power down enabled
+struct AbortOnDrop<'a>(&'a RegBlock);
+
+impl Drop for AbortOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        stop_single_conversion(self.0);
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const STATE_NEW: AtomicWaker = AtomicWaker::new();
+static STATE: [AtomicWaker; NUM_ADCS] = [STATE_NEW; NUM_ADCS];
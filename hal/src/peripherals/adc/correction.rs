@@ -0,0 +1,68 @@
+//! Digital gain and offset correction.
+
+/// `GAINCORR` value of a gain of exactly 1
+///
+/// The gain correction is a 1.11 fixed point number, so `2048` is a gain of 1.
+pub const UNITY_GAIN: u16 = 2048;
+
+/// Encode a gain and an offset correction into their `GAINCORR` and
+/// `OFFSETCORR` register values
+///
+/// # Panics
+///
+/// Panics if `gain` is outside `1024..=4095` (a gain between 1/2 and 2), or
+/// if `offset` doesn't fit the 12-bit two's complement `OFFSETCORR` field.
+#[inline]
+pub(super) fn correction_bits(gain: u16, offset: i16) -> (u16, u16) {
+    assert!(
+        (0x400..=0xFFF).contains(&gain),
+        "Gain correction must be between 1/2 and 2"
+    );
+    assert!(
+        (-2048..=2047).contains(&offset),
+        "Offset correction must fit in 12 bits"
+    );
+    (gain, offset as u16 & 0xFFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Correction performed by the hardware, as documented in the datasheet,
+    /// on a 12-bit result
+    fn corrected(raw: u16, gain: u16, offset: u16) -> u16 {
+        // Sign-extend the 12-bit offset
+        let offset = ((offset << 4) as i16 >> 4) as i32;
+        ((raw as i32 - offset) * gain as i32 / 2048).clamp(0, 4095) as u16
+    }
+
+    #[test]
+    fn fields_are_encoded() {
+        assert_eq!(correction_bits(UNITY_GAIN, 0), (0x800, 0));
+        assert_eq!(correction_bits(0xFFF, 2047), (0xFFF, 0x7FF));
+        // Negative offsets are stored in two's complement
+        assert_eq!(correction_bits(0x400, -1), (0x400, 0xFFF));
+        assert_eq!(correction_bits(0x400, -2048), (0x400, 0x800));
+    }
+
+    #[test]
+    fn correction_formula() {
+        // An input which should read 1920 reads 2060, with a 12 count offset
+        let gain = (1920 * UNITY_GAIN as u32 / (2060 - 12)) as u16;
+        let (gain, offset) = correction_bits(gain, 12);
+        assert_eq!(corrected(2060, gain, offset), 1920);
+        assert_eq!(corrected(12, gain, offset), 0);
+
+        let (gain, offset) = correction_bits(UNITY_GAIN, -5);
+        assert_eq!(corrected(100, gain, offset), 105);
+        // The result saturates instead of wrapping
+        assert_eq!(corrected(4095, gain, offset), 4095);
+    }
+
+    #[test]
+    #[should_panic]
+    fn offset_out_of_range() {
+        correction_bits(UNITY_GAIN, 2048);
+    }
+}
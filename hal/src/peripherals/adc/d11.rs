@@ -1,4 +1,43 @@
 //! Analogue-to-Digital Conversion
+//!
+//! # Sample time and source impedance
+//!
+//! During the sampling phase, the ADC's sampling capacitor charges through
+//! the impedance of the source, in series with the ADC's own input
+//! resistance. If the sampling phase ends before the capacitor has settled
+//! to within half an LSB of the input voltage, the reading droops towards the
+//! voltage left over from the previous conversion. For an `n`-bit result, the
+//! sampling time must be at least
+//!
+//! ```text
+//! t_samp >= (R_source + R_sample) * C_sample * (n + 2) * ln(2)
+//! ```
+//!
+//! where `R_sample` and `C_sample` are given in the electrical
+//! characteristics of the datasheet. The default sampling time suits sources
+//! of a few kilohms. For high impedance sources, such as large voltage
+//! dividers, lengthen it with [`Adc::set_sample_time`], lower the ADC clock
+//! frequency with [`Adc::prescaler`], or buffer the source with a capacitor
+//! that is large compared to `C_sample`. Longer sampling times reduce the
+//! maximum conversion rate accordingly.
+//!
+//! Blocking reads always discard their first conversion, which gives the
+//! reference and the input multiplexer time to settle after the ADC is
+//! enabled.
+//!
+//! # Input scan
+//!
+//! [`Adc::read_scan`] reads a bank of consecutive channels in one call. The
+//! ADC adds `INPUTCTRL.INPUTOFFSET` to the selected positive input, and
+//! increments the offset after each conversion. After `INPUTSCAN + 1`
+//! conversions, the offset wraps around to zero, so a further conversion
+//! would start over at the first channel. Each result is read out of the
+//! single `RESULT` register before the next conversion is started, and
+//! stored at the index of its offset.
+//!
+//! Only the positive input is scanned, and the channels must be numbered
+//! consecutively. Check the channel numbers of the pins in the datasheet, as
+//! neighbouring pins are not always neighbouring channels.
 use atsamd_hal_macros::hal_cfg;
 
 use crate::clock::GenericClockController;
@@ -6,6 +45,19 @@ use crate::ehal_02::adc::{Channel, OneShot};
 use crate::gpio::*;
 use crate::pac::{self, adc, Pm};
 
+use adc::winctrl::Winmodeselect;
+
+mod correction;
+pub use correction::UNITY_GAIN;
+
+mod window;
+pub use window::WindowMode;
+
+#[cfg(feature = "async")]
+mod async_api;
+#[cfg(feature = "async")]
+pub use async_api::*;
+
 /// Samples per reading
 pub use adc::avgctrl::Samplenumselect as SampleRate;
 /// Clock frequency relative to the system clock
@@ -84,6 +136,32 @@ impl Adc<pac::Adc> {
         while self.adc.status().read().syncbusy().bit_is_set() {}
     }
 
+    /// Set the length of the sampling phase
+    ///
+    /// The sampling phase lasts `length + 1` half ADC clock cycles. The
+    /// default `length` is 5. See the [module-level
+    /// documentation](self#sample-time-and-source-impedance) to choose it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is greater than 63.
+    pub fn set_sample_time(&mut self, length: u8) {
+        assert!(length < 64, "SAMPLEN is a 6-bit field");
+        self.adc
+            .sampctrl()
+            .modify(|_, w| unsafe { w.samplen().bits(length) });
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+    }
+
+    /// Enable or disable the reference buffer offset compensation
+    ///
+    /// This improves the accuracy of the internal reference, at the cost of a
+    /// longer startup time. Disabled by default.
+    pub fn set_reference_compensation(&mut self, enabled: bool) {
+        self.adc.refctrl().modify(|_, w| w.refcomp().bit(enabled));
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+    }
+
     /// Set the gain factor
     pub fn gain(&mut self, gain: Gain) {
         self.adc.inputctrl().modify(|_, w| w.gain().variant(gain));
@@ -114,6 +192,109 @@ impl Adc<pac::Adc> {
         while self.adc.status().read().syncbusy().bit_is_set() {}
     }
 
+    /// Configure the window monitor
+    ///
+    /// Once configured, every conversion result is compared against the
+    /// window in hardware. See [`WindowMode`] for the condition checked in
+    /// each mode. The thresholds are compared with the final result, so they
+    /// must use the same resolution and averaging settings.
+    pub fn set_window(&mut self, mode: WindowMode, lower: u16, upper: u16) {
+        enable_window_monitor(&self.adc, mode, lower, upper);
+    }
+
+    /// Disable the window monitor
+    pub fn disable_window(&mut self) {
+        disable_window_monitor(&self.adc);
+    }
+
+    /// Enable the digital gain and offset correction
+    ///
+    /// Every conversion result is then corrected in hardware, as:
+    ///
+    /// ```text
+    /// corrected = (raw - offset) * gain / 2048
+    /// ```
+    ///
+    /// `gain` is a 1.11 fixed point number between 1/2 and 2, so
+    /// [`UNITY_GAIN`] leaves the result unchanged, and `offset` is a 12-bit
+    /// signed value, in LSBs of a 12-bit result. The corrected result
+    /// saturates instead of wrapping around.
+    ///
+    /// To calibrate against known references, measure two inputs `v1` and
+    /// `v2` with the correction disabled, giving `raw1` and `raw2`. Then
+    /// `gain = 2048 * (v2 - v1) / (raw2 - raw1)`, and
+    /// `offset = raw1 - v1 * 2048 / gain`, with `v1` and `v2` expressed as
+    /// ideal conversion results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gain` is outside `1024..=4095`, or if `offset` is outside
+    /// `-2048..=2047`.
+    pub fn set_correction(&mut self, gain: u16, offset: i16) {
+        enable_correction(&self.adc, gain, offset);
+    }
+
+    /// Disable the digital gain and offset correction
+    pub fn disable_correction(&mut self) {
+        disable_correction(&self.adc);
+    }
+
+    /// Convert `results.len()` consecutive channels, starting at `start`
+    ///
+    /// The ADC runs in input scan mode, and adds an offset to the positive
+    /// input selected by `start` after each conversion. `results[i]` then
+    /// holds the reading of channel `start + i`. See the [module-level
+    /// documentation](self#input-scan) for the details.
+    ///
+    /// The pins of all scanned channels must already be in
+    /// [`AlternateB`] mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `results` is empty or holds more than 16 readings.
+    pub fn read_scan(&mut self, start: u8, results: &mut [u16]) {
+        assert!(
+            (1..=16).contains(&results.len()),
+            "INPUTSCAN converts between 1 and 16 channels"
+        );
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+        self.adc.inputctrl().modify(|_, w| unsafe {
+            w.muxpos().bits(start);
+            w.inputscan().bits(0);
+            w.inputoffset().bits(0)
+        });
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+        self.power_up();
+
+        // The first conversion after enabling the ADC must not be used. Do it
+        // before starting the scan, so that it doesn't advance the offset.
+        self.adc.swtrig().modify(|_, w| w.start().set_bit());
+        while self.adc.intflag().read().resrdy().bit_is_clear() {}
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+        self.adc.intflag().write(|w| w.resrdy().set_bit());
+
+        self.adc.inputctrl().modify(|_, w| unsafe {
+            w.inputscan().bits(results.len() as u8 - 1);
+            w.inputoffset().bits(0)
+        });
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+
+        for result in results.iter_mut() {
+            self.adc.swtrig().modify(|_, w| w.start().set_bit());
+            while self.adc.intflag().read().resrdy().bit_is_clear() {}
+            while self.adc.status().read().syncbusy().bit_is_set() {}
+            // Reading RESULT clears the RESRDY flag
+            *result = self.adc.result().read().result().bits();
+        }
+
+        self.power_down();
+        self.adc.inputctrl().modify(|_, w| unsafe {
+            w.inputscan().bits(0);
+            w.inputoffset().bits(0)
+        });
+        while self.adc.status().read().syncbusy().bit_is_set() {}
+    }
+
     fn power_up(&mut self) {
         while self.adc.status().read().syncbusy().bit_is_set() {}
         self.adc.ctrla().modify(|_, w| w.enable().set_bit());
@@ -166,6 +347,109 @@ where
     }
 }
 
+fn enable_window_monitor(adc: &adc::RegisterBlock, mode: WindowMode, lower: u16, upper: u16) {
+    adc.winlt().write(|w| unsafe { w.winlt().bits(lower) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.winut().write(|w| unsafe { w.winut().bits(upper) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.winctrl().write(|w| w.winmode().variant(mode.winmode()));
+    while adc.status().read().syncbusy().bit_is_set() {}
+}
+
+fn enable_correction(adc: &adc::RegisterBlock, gain: u16, offset: i16) {
+    let (gain, offset) = correction::correction_bits(gain, offset);
+    adc.gaincorr().write(|w| unsafe { w.gaincorr().bits(gain) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.offsetcorr()
+        .write(|w| unsafe { w.offsetcorr().bits(offset) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.corren().set_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+}
+
+fn disable_correction(adc: &adc::RegisterBlock) {
+    adc.ctrlb().modify(|_, w| w.corren().clear_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+}
+
+fn disable_window_monitor(adc: &adc::RegisterBlock) {
+    adc.winctrl()
+        .write(|w| w.winmode().variant(Winmodeselect::Disable));
+    while adc.status().read().syncbusy().bit_is_set() {}
+}
+
+/// Select `channel` and start converting it continuously, with the window
+/// monitor interrupt enabled
+#[cfg(feature = "async")]
+fn start_free_running(adc: &adc::RegisterBlock, channel: u8) {
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.inputctrl()
+        .modify(|_, w| unsafe { w.muxpos().bits(channel) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.freerun().set_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+
+    adc.intflag().write(|w| w.winmon().set_bit());
+    adc.intenset().write(|w| w.winmon().set_bit());
+
+    adc.ctrla().modify(|_, w| w.enable().set_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.swtrig().write(|w| w.start().set_bit());
+}
+
+/// Undo [`start_free_running`]
+#[cfg(feature = "async")]
+fn stop_free_running(adc: &adc::RegisterBlock) {
+    adc.intenclr().write(|w| w.winmon().set_bit());
+    adc.ctrla().modify(|_, w| w.enable().clear_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.freerun().clear_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.intflag().write(|w| w.winmon().set_bit());
+}
+
+/// The first conversion after enabling the ADC must not be used
+#[cfg(feature = "async")]
+const DISCARD_FIRST_CONVERSION: bool = true;
+
+/// Select `channel` and enable the ADC, unless it is already enabled on that
+/// channel. Return whether the ADC had to be enabled.
+#[cfg(feature = "async")]
+fn enable_single_conversion(adc: &adc::RegisterBlock, channel: u8) -> bool {
+    let enabled = adc.ctrla().read().enable().bit_is_set();
+    if enabled && adc.inputctrl().read().muxpos().bits() == channel {
+        return false;
+    }
+    if enabled {
+        adc.ctrla().modify(|_, w| w.enable().clear_bit());
+        while adc.status().read().syncbusy().bit_is_set() {}
+    }
+    adc.inputctrl()
+        .modify(|_, w| unsafe { w.muxpos().bits(channel) });
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.ctrla().modify(|_, w| w.enable().set_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+    true
+}
+
+/// Start a single conversion, with the result ready interrupt enabled
+#[cfg(feature = "async")]
+fn trigger_conversion(adc: &adc::RegisterBlock) {
+    adc.intflag().write(|w| w.resrdy().set_bit());
+    adc.intenset().write(|w| w.resrdy().set_bit());
+    adc.swtrig().write(|w| w.start().set_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+}
+
+/// Abort any ongoing conversion, and disable the ADC
+#[cfg(feature = "async")]
+fn stop_single_conversion(adc: &adc::RegisterBlock) {
+    adc.intenclr().write(|w| w.resrdy().set_bit());
+    adc.ctrla().modify(|_, w| w.enable().clear_bit());
+    while adc.status().read().syncbusy().bit_is_set() {}
+    adc.intflag().write(|w| w.resrdy().set_bit());
+}
+
 macro_rules! adc_pins {
     (
         $(
@@ -1,4 +1,36 @@
 //! Analogue-to-Digital Conversion
+//!
+//! # Sample time and source impedance
+//!
+//! During the sampling phase, the ADC's sampling capacitor charges through
+//! the impedance of the source, in series with the ADC's own input
+//! resistance. If the sampling phase ends before the capacitor has settled
+//! to within half an LSB of the input voltage, the reading droops towards the
+//! voltage left over from the previous conversion. For an `n`-bit result, the
+//! sampling time must be at least
+//!
+//! ```text
+//! t_samp >= (R_source + R_sample) * C_sample * (n + 2) * ln(2)
+//! ```
+//!
+//! where `R_sample` and `C_sample` are given in the electrical
+//! characteristics of the datasheet. The default sampling time suits sources
+//! of a few kilohms. For high impedance sources, such as large voltage
+//! dividers, lengthen it with [`Adc::set_sample_time`], lower the ADC clock
+//! frequency with [`Adc::prescaler`], or buffer the source with a capacitor
+//! that is large compared to `C_sample`. Longer sampling times reduce the
+//! maximum conversion rate accordingly.
+//!
+//! [`Adc::set_discard_first`] also discards the first conversion of every
+//! blocking read, which gives the reference and the input multiplexer time
+//! to settle after the ADC is enabled.
+//!
+//! # Input scan
+//!
+//! Unlike the SAMD11 and SAMD21 ADC, this ADC has no input scan mode. To read
+//! several channels, perform one [`OneShot::read`] per channel, or use the
+//! `DSEQ` register to let the DMA controller update `INPUTCTRL` between
+//! conversions.
 use atsamd_hal_macros::hal_cfg;
 
 use crate::clock::GenericClockController;
@@ -11,6 +43,19 @@ use crate::pac::{adc0, Adc0, Adc1, Mclk};
 
 use crate::calibration;
 
+use adc0::ctrlb::Winmodeselect;
+
+mod correction;
+pub use correction::UNITY_GAIN;
+
+mod window;
+pub use window::WindowMode;
+
+#[cfg(feature = "async")]
+mod async_api;
+#[cfg(feature = "async")]
+pub use async_api::*;
+
 /// Samples per reading
 pub use adc0::avgctrl::Samplenumselect as SampleRate;
 /// Clock frequency relative to the system clock
@@ -32,6 +77,7 @@ where
 /// `Adc` encapsulates the device ADC
 pub struct Adc<ADC> {
     adc: ADC,
+    discard_first: bool,
 }
 
 /// Describes how an interrupt-driven ADC should finalize the peripheral
@@ -45,6 +91,14 @@ pub trait ConversionMode<ADC> {
 pub struct SingleConversion;
 pub struct FreeRunning;
 
+/// Conversions are started by the `START` event input of the ADC
+///
+/// Use [`InterruptAdc::start_on_event`] to arm the ADC. Every event routed to
+/// the ADC's `START` event user then starts a conversion, without any CPU
+/// involvement, and the ADC stays armed until
+/// [`stop_conversion`](InterruptAdc::stop_conversion) is called.
+pub struct EventTriggered;
+
 macro_rules! adc_hal {
     ($($ADC:ident: ($init:ident, $mclk:ident, $apmask:ident, $compcal:ident, $refcal:ident, $r2rcal:ident),)+) => {
         $(
@@ -69,7 +123,7 @@ impl Adc<$ADC> {
             w.biasr2r().bits(calibration::$r2rcal())
         });
 
-        let mut newadc = Self { adc };
+        let mut newadc = Self { adc, discard_first: false };
         newadc.samples(adc0::avgctrl::Samplenumselect::_1);
         newadc.reference(adc0::refctrl::Refselselect::Intvcc1);
 
@@ -104,6 +158,39 @@ impl Adc<$ADC> {
         while self.adc.syncbusy().read().refctrl().bit_is_set() {}
     }
 
+    /// Set the length of the sampling phase
+    ///
+    /// The sampling phase lasts `length + 1` ADC clock cycles. The default
+    /// `length` is 5. See the [module-level
+    /// documentation](self#sample-time-and-source-impedance) to choose it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is greater than 63.
+    pub fn set_sample_time(&mut self, length: u8) {
+        assert!(length < 64, "SAMPLEN is a 6-bit field");
+        self.adc.sampctrl().modify(|_, w| unsafe { w.samplen().bits(length) });
+        while self.adc.syncbusy().read().sampctrl().bit_is_set() {}
+    }
+
+    /// Enable or disable the reference buffer offset compensation
+    ///
+    /// This improves the accuracy of the internal reference, at the cost of a
+    /// longer startup time. Disabled by default.
+    pub fn set_reference_compensation(&mut self, enabled: bool) {
+        self.adc.refctrl().modify(|_, w| w.refcomp().bit(enabled));
+        while self.adc.syncbusy().read().refctrl().bit_is_set() {}
+    }
+
+    /// Discard the first conversion of every blocking read
+    ///
+    /// When set, each [`OneShot::read`] converts twice and only returns the
+    /// second result, so the reference and the input have settled. This
+    /// doubles the duration of every read. Disabled by default.
+    pub fn set_discard_first(&mut self, discard: bool) {
+        self.discard_first = discard;
+    }
+
     /// Set the prescaler for adjusting the clock relative to the system clock
     pub fn prescaler(&mut self, prescaler: Prescaler) {
         self.adc
@@ -120,6 +207,53 @@ impl Adc<$ADC> {
         while self.adc.syncbusy().read().ctrlb().bit_is_set() {}
     }
 
+    /// Configure the window monitor
+    ///
+    /// Once configured, every conversion result is compared against the
+    /// window in hardware. See [`WindowMode`] for the condition checked in
+    /// each mode. The thresholds are compared with the final result, so they
+    /// must use the same resolution and averaging settings.
+    pub fn set_window(&mut self, mode: WindowMode, lower: u16, upper: u16) {
+        enable_window_monitor(&self.adc, mode, lower, upper);
+    }
+
+    /// Disable the window monitor
+    pub fn disable_window(&mut self) {
+        disable_window_monitor(&self.adc);
+    }
+
+    /// Enable the digital gain and offset correction
+    ///
+    /// Every conversion result is then corrected in hardware, as:
+    ///
+    /// ```text
+    /// corrected = (raw - offset) * gain / 2048
+    /// ```
+    ///
+    /// `gain` is a 1.11 fixed point number between 1/2 and 2, so
+    /// [`UNITY_GAIN`] leaves the result unchanged, and `offset` is a 12-bit
+    /// signed value, in LSBs of a 12-bit result. The corrected result
+    /// saturates instead of wrapping around.
+    ///
+    /// To calibrate against known references, measure two inputs `v1` and
+    /// `v2` with the correction disabled, giving `raw1` and `raw2`. Then
+    /// `gain = 2048 * (v2 - v1) / (raw2 - raw1)`, and
+    /// `offset = raw1 - v1 * 2048 / gain`, with `v1` and `v2` expressed as
+    /// ideal conversion results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gain` is outside `1024..=4095`, or if `offset` is outside
+    /// `-2048..=2047`.
+    pub fn set_correction(&mut self, gain: u16, offset: i16) {
+        enable_correction(&self.adc, gain, offset);
+    }
+
+    /// Disable the digital gain and offset correction
+    pub fn disable_correction(&mut self) {
+        disable_correction(&self.adc);
+    }
+
     fn power_up(&mut self) {
         while self.adc.syncbusy().read().enable().bit_is_set() {}
         self.adc.ctrla().modify(|_, w| w.enable().set_bit());
@@ -140,17 +274,32 @@ impl Adc<$ADC> {
         self.adc.swtrig().modify(|_, w| w.start().set_bit());
     }
 
+    /// Enables the `START` event input. `EVCTRL` is enable-protected, so this
+    /// must be called while the peripheral is disabled.
+    fn enable_start_event(&mut self) {
+        self.adc.evctrl().modify(|_, w| w.startei().set_bit());
+    }
+
+    fn disable_start_event(&mut self) {
+        self.adc.evctrl().modify(|_, w| w.startei().clear_bit());
+    }
+
     fn enable_freerunning(&mut self) {
         self.adc.ctrlb().modify(|_, w| w.freerun().set_bit());
         while self.adc.syncbusy().read().ctrlb().bit_is_set() {}
     }
 
     fn disable_freerunning(&mut self) {
-        self.adc.ctrlb().modify(|_, w| w.freerun().set_bit());
+        self.adc.ctrlb().modify(|_, w| w.freerun().clear_bit());
         while self.adc.syncbusy().read().ctrlb().bit_is_set() {}
     }
 
     fn synchronous_convert(&mut self) -> u16 {
+        if self.discard_first {
+            self.start_conversion();
+            while self.adc.intflag().read().resrdy().bit_is_clear() {}
+            self.adc.intflag().write(|w| w.resrdy().set_bit());
+        }
         self.start_conversion();
         while self.adc.intflag().read().resrdy().bit_is_clear() {}
 
@@ -211,6 +360,32 @@ impl ConversionMode<$ADC> for FreeRunning {
     }
 }
 
+impl ConversionMode<$ADC> for EventTriggered {
+    fn on_start(_adc: &mut Adc<$ADC>) {
+    }
+    fn on_complete(_adc: &mut Adc<$ADC>) {
+    }
+    fn on_stop(adc: &mut Adc<$ADC>) {
+        adc.disable_interrupts();
+        adc.power_down();
+        adc.disable_start_event();
+    }
+}
+
+impl InterruptAdc<$ADC, EventTriggered> {
+    /// Arms the ADC to sample the specified pin on every `START` event.
+    ///
+    /// Unlike [`start_conversion`](Self::start_conversion), no conversion is
+    /// started right away. The results are read with
+    /// [`service_interrupt_ready`](Self::service_interrupt_ready).
+    pub fn start_on_event<PIN: Channel<$ADC, ID=u8>>(&mut self, pin: &mut PIN) {
+        self.adc.mux(pin);
+        self.adc.enable_start_event();
+        self.adc.power_up();
+        self.adc.enable_interrupts();
+    }
+}
+
 impl<C> InterruptAdc<$ADC, C>
     where C: ConversionMode<$ADC>
 {
@@ -272,6 +447,110 @@ adc_hal! {
     Adc1: (adc1, apbdmask, adc1_, adc1_biascomp_scale_cal, adc1_biasref_scale_cal, adc1_biasr2r_scale_cal),
 }
 
+fn enable_window_monitor(adc: &adc0::RegisterBlock, mode: WindowMode, lower: u16, upper: u16) {
+    adc.winlt().write(|w| unsafe { w.winlt().bits(lower) });
+    while adc.syncbusy().read().winlt().bit_is_set() {}
+    adc.winut().write(|w| unsafe { w.winut().bits(upper) });
+    while adc.syncbusy().read().winut().bit_is_set() {}
+    adc.ctrlb()
+        .modify(|_, w| w.winmode().variant(mode.winmode()));
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+}
+
+fn enable_correction(adc: &adc0::RegisterBlock, gain: u16, offset: i16) {
+    let (gain, offset) = correction::correction_bits(gain, offset);
+    adc.gaincorr().write(|w| unsafe { w.gaincorr().bits(gain) });
+    while adc.syncbusy().read().gaincorr().bit_is_set() {}
+    adc.offsetcorr()
+        .write(|w| unsafe { w.offsetcorr().bits(offset) });
+    while adc.syncbusy().read().offsetcorr().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.corren().set_bit());
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+}
+
+fn disable_correction(adc: &adc0::RegisterBlock) {
+    adc.ctrlb().modify(|_, w| w.corren().clear_bit());
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+}
+
+fn disable_window_monitor(adc: &adc0::RegisterBlock) {
+    adc.ctrlb()
+        .modify(|_, w| w.winmode().variant(Winmodeselect::Disable));
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+}
+
+/// Select `channel` and start converting it continuously, with the window
+/// monitor interrupt enabled
+#[cfg(feature = "async")]
+fn start_free_running(adc: &adc0::RegisterBlock, channel: u8) {
+    while adc.syncbusy().read().inputctrl().bit_is_set() {}
+    adc.inputctrl()
+        .modify(|_, w| unsafe { w.muxpos().bits(channel) });
+    while adc.syncbusy().read().inputctrl().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.freerun().set_bit());
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+
+    adc.intflag().write(|w| w.winmon().set_bit());
+    adc.intenset().write(|w| w.winmon().set_bit());
+
+    adc.ctrla().modify(|_, w| w.enable().set_bit());
+    while adc.syncbusy().read().enable().bit_is_set() {}
+    adc.swtrig().write(|w| w.start().set_bit());
+}
+
+/// Undo [`start_free_running`]
+#[cfg(feature = "async")]
+fn stop_free_running(adc: &adc0::RegisterBlock) {
+    adc.intenclr().write(|w| w.winmon().set_bit());
+    adc.ctrla().modify(|_, w| w.enable().clear_bit());
+    while adc.syncbusy().read().enable().bit_is_set() {}
+    adc.ctrlb().modify(|_, w| w.freerun().clear_bit());
+    while adc.syncbusy().read().ctrlb().bit_is_set() {}
+    adc.intflag().write(|w| w.winmon().set_bit());
+}
+
+/// The first conversion after enabling the ADC can be used
+#[cfg(feature = "async")]
+const DISCARD_FIRST_CONVERSION: bool = false;
+
+/// Select `channel` and enable the ADC, unless it is already enabled on that
+/// channel. Return whether the ADC had to be enabled.
+#[cfg(feature = "async")]
+fn enable_single_conversion(adc: &adc0::RegisterBlock, channel: u8) -> bool {
+    let enabled = adc.ctrla().read().enable().bit_is_set();
+    if enabled && adc.inputctrl().read().muxpos().bits() == channel {
+        return false;
+    }
+    if enabled {
+        adc.ctrla().modify(|_, w| w.enable().clear_bit());
+        while adc.syncbusy().read().enable().bit_is_set() {}
+    }
+    while adc.syncbusy().read().inputctrl().bit_is_set() {}
+    adc.inputctrl()
+        .modify(|_, w| unsafe { w.muxpos().bits(channel) });
+    while adc.syncbusy().read().inputctrl().bit_is_set() {}
+    adc.ctrla().modify(|_, w| w.enable().set_bit());
+    while adc.syncbusy().read().enable().bit_is_set() {}
+    true
+}
+
+/// Start a single conversion, with the result ready interrupt enabled
+#[cfg(feature = "async")]
+fn trigger_conversion(adc: &adc0::RegisterBlock) {
+    adc.intflag().write(|w| w.resrdy().set_bit());
+    adc.intenset().write(|w| w.resrdy().set_bit());
+    adc.swtrig().write(|w| w.start().set_bit());
+}
+
+/// Abort any ongoing conversion, and disable the ADC
+#[cfg(feature = "async")]
+fn stop_single_conversion(adc: &adc0::RegisterBlock) {
+    adc.intenclr().write(|w| w.resrdy().set_bit());
+    adc.ctrla().modify(|_, w| w.enable().clear_bit());
+    while adc.syncbusy().read().enable().bit_is_set() {}
+    adc.intflag().write(|w| w.resrdy().set_bit());
+}
+
 macro_rules! adc_pins {
     (
         $(
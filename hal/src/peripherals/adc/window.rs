@@ -0,0 +1,57 @@
+//! Hardware window monitor.
+
+use super::Winmodeselect;
+
+/// Condition checked by the ADC window monitor
+///
+/// The window monitor compares every conversion result against a lower and an
+/// upper threshold and flags the result when the selected condition is met,
+/// without any CPU involvement. Depending on the mode, only one of the
+/// thresholds is used. The first three comparisons are strict, while
+/// [`Outside`](Self::Outside) is the inverse of [`Inside`](Self::Inside), so
+/// it includes both thresholds:
+///
+/// | Mode                       | Condition                              |
+/// |----------------------------|----------------------------------------|
+/// | [`Above`](Self::Above)     | `result > lower`                       |
+/// | [`Below`](Self::Below)     | `result < upper`                       |
+/// | [`Inside`](Self::Inside)   | `lower < result < upper`               |
+/// | [`Outside`](Self::Outside) | `result <= lower \|\| result >= upper` |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WindowMode {
+    /// The result is above the lower threshold
+    Above,
+    /// The result is below the upper threshold
+    Below,
+    /// The result is between both thresholds
+    Inside,
+    /// The result is not between both thresholds
+    Outside,
+}
+
+impl WindowMode {
+    /// Corresponding `WINMODE` register value
+    #[inline]
+    pub(super) fn winmode(self) -> Winmodeselect {
+        match self {
+            Self::Above => Winmodeselect::Mode1,
+            Self::Below => Winmodeselect::Mode2,
+            Self::Inside => Winmodeselect::Mode3,
+            Self::Outside => Winmodeselect::Mode4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_mode_encoding() {
+        assert_eq!(u8::from(WindowMode::Above.winmode()), 1);
+        assert_eq!(u8::from(WindowMode::Below.winmode()), 2);
+        assert_eq!(u8::from(WindowMode::Inside.winmode()), 3);
+        assert_eq!(u8::from(WindowMode::Outside.winmode()), 4);
+    }
+}
@@ -4,6 +4,57 @@
 //! before you can set up most of the peripherals on the atsamd21 device.
 //! The other types in this module are used to enforce at compile time
 //! that the peripherals have been correctly configured.
+//!
+//! # Generator ownership
+//!
+//! Each clock generator can only be configured once. Generators configured
+//! at startup are never handed out again, and
+//! [`GenericClockController::configure_gclk_divider_and_source`] returns
+//! `None` for a generator that is already configured, rather than changing
+//! the frequency of the peripherals it drives.
+//!
+//! To make sure a generator is configured by the intended code, claim it with
+//! [`GenericClockController::claim_gclk`], and pass the returned
+//! [`GClockToken`] to [`GenericClockController::configure_gclk`]. Only one
+//! token exists per generator, so any other attempt to claim or configure it
+//! fails. Existing code calling `configure_gclk_divider_and_source` keeps
+//! working unchanged; migrating is a matter of replacing
+//!
+//! ```ignore
+//! let gclk2 = clocks
+//!     .configure_gclk_divider_and_source(Gclk2, 1, source, false)
+//!     .unwrap();
+//! ```
+//!
+//! with
+//!
+//! ```ignore
+//! let token = clocks.claim_gclk(Gclk2).unwrap();
+//! // ... later, in the code owning the generator
+//! let gclk2 = clocks.configure_gclk(token, 1, source, false);
+//! ```
+//!
+//! # Sharing a generator
+//!
+//! Configuring a peripheral clock, e.g. with
+//! [`GenericClockController::sercom0_core`], only borrows the [`GClock`], and
+//! never reconfigures the generator. Any number of peripherals can therefore
+//! be clocked from the same generator, which saves generators for other uses:
+//!
+//! ```ignore
+//! let gclk2 = clocks
+//!     .configure_gclk_divider_and_source(Gclk2, 1, ClockSource::Dfll48m, false)
+//!     .unwrap();
+//! let sercom0_clock = clocks.sercom0_core(&gclk2).unwrap();
+//! let sercom1_clock = clocks.sercom1_core(&gclk2).unwrap();
+//! let sercom2_clock = clocks.sercom2_core(&gclk2).unwrap();
+//! ```
+//!
+//! All of these peripherals then run from the same frequency, so it must
+//! suit each of them. For SERCOMs, each one still sets its own baud rate from
+//! that frequency, but the frequency must be high enough for the fastest of
+//! them, e.g. at least 16 times the baud rate of a UART using 16x
+//! oversampling.
 #![allow(clippy::from_over_into)]
 
 use atsamd_hal_macros::{hal_cfg, hal_macro_helper};
@@ -26,6 +77,10 @@ pub type ClockSource = pac::gclk::genctrl::Srcselect;
 /// Its primary purpose is to be passed in to methods
 /// such as `GenericClockController::tcc2_tc3` to configure
 /// the clock for a peripheral.
+///
+/// A `GClock` can only be obtained from a [`GenericClockController`], which
+/// refuses to return generators that have not been configured. It can be
+/// shared by any number of peripherals.
 #[derive(Clone, Copy)]
 pub struct GClock {
     gclk: ClockGenId,
@@ -38,6 +93,26 @@ impl Into<Hertz> for GClock {
     }
 }
 
+/// The exclusive right to configure a clock generator
+///
+/// A `GClockToken` is obtained from
+/// [`GenericClockController::claim_gclk`], which hands out at most one token
+/// per generator, and never for the generators configured at startup. It is
+/// consumed by [`GenericClockController::configure_gclk`], so a generator can
+/// only be configured once, by the code that claimed it.
+#[derive(Debug)]
+pub struct GClockToken {
+    gclk: ClockGenId,
+}
+
+impl GClockToken {
+    /// The clock generator this token allows to configure
+    #[inline]
+    pub fn id(&self) -> ClockGenId {
+        self.gclk
+    }
+}
+
 struct State {
     gclk: Gclk,
 }
@@ -132,6 +207,7 @@ impl State {
 pub struct GenericClockController {
     state: State,
     gclks: [Hertz; 8],
+    claimed_gclks: u16,
     used_clocks: u64,
 }
 
@@ -217,6 +293,7 @@ impl GenericClockController {
                 0.Hz(),
                 0.Hz(),
             ],
+            claimed_gclks: 0b11,
             used_clocks: 1u64 << u8::from(ClockId::Dfll48),
         }
     }
@@ -269,6 +346,7 @@ impl GenericClockController {
                 0.Hz(),
                 0.Hz(),
             ],
+            claimed_gclks: 0b1,
             used_clocks: 0,
         }
     }
@@ -304,26 +382,39 @@ impl GenericClockController {
         }
     }
 
-    /// Configures a clock generator with the specified divider and
-    /// source.
+    /// Claims the exclusive right to configure a clock generator.
+    /// Returns `None` if the clock generator has already been configured,
+    /// or if its token has already been claimed.
+    ///
+    /// Claiming the generators an application relies on early, and passing
+    /// the tokens to the code that configures them, guarantees that no other
+    /// driver can configure those generators with conflicting settings.
+    pub fn claim_gclk(&mut self, gclk: ClockGenId) -> Option<GClockToken> {
+        let bit = 1 << u8::from(gclk);
+        if self.claimed_gclks & bit != 0 {
+            return None;
+        }
+        self.claimed_gclks |= bit;
+        Some(GClockToken { gclk })
+    }
+
+    /// Configures the clock generator of `token` with the specified divider
+    /// and source, consuming the token.
     /// `divider` is a linear divider to be applied to the clock
     /// source.  While the hardware also supports an exponential divider,
     /// this function doesn't expose that functionality at this time.
     /// `improve_duty_cycle` is a boolean that, when set to true, enables
-    /// a 5o/50 duty cycle for odd divider values.
+    /// a 50/50 duty cycle for odd divider values.
     /// Returns a `GClock` for the configured clock generator.
-    /// Returns `None` if the clock generator has already been configured.
-    pub fn configure_gclk_divider_and_source(
+    pub fn configure_gclk(
         &mut self,
-        gclk: ClockGenId,
+        token: GClockToken,
         divider: u16,
         src: ClockSource,
         improve_duty_cycle: bool,
-    ) -> Option<GClock> {
+    ) -> GClock {
+        let gclk = token.gclk;
         let idx = u8::from(gclk) as usize;
-        if self.gclks[idx].to_Hz() != 0 {
-            return None;
-        }
         self.state
             .set_gclk_divider_and_source(gclk, divider, src, improve_duty_cycle);
         let freq: Hertz = match src {
@@ -335,7 +426,29 @@ impl GenericClockController {
             Gclkin | Xosc => unimplemented!(),
         };
         self.gclks[idx] = freq / divider as u32;
-        Some(GClock { gclk, freq })
+        GClock {
+            gclk,
+            freq: self.gclks[idx],
+        }
+    }
+
+    /// Configures a clock generator with the specified divider and
+    /// source.
+    /// This is equivalent to claiming the generator with
+    /// [`claim_gclk`](Self::claim_gclk), and configuring it with
+    /// [`configure_gclk`](Self::configure_gclk).
+    /// Returns a `GClock` for the configured clock generator.
+    /// Returns `None` if the clock generator has already been configured,
+    /// or if its token has been claimed.
+    pub fn configure_gclk_divider_and_source(
+        &mut self,
+        gclk: ClockGenId,
+        divider: u16,
+        src: ClockSource,
+        improve_duty_cycle: bool,
+    ) -> Option<GClock> {
+        let token = self.claim_gclk(gclk)?;
+        Some(self.configure_gclk(token, divider, src, improve_duty_cycle))
     }
 
     /// Enables or disables the given GClk from operation in standby.
@@ -413,6 +526,7 @@ clock_generator!(
     (sercom0_core, Sercom0CoreClock, Sercom0Core),
     (sercom1_core, Sercom1CoreClock, Sercom1Core),
     (sercom2_core, Sercom2CoreClock, Sercom2Core),
+    (sercomx_slow, SercomxSlowClock, SercomxSlow),
     (rtc, RtcClock, Rtc),
     (adc, AdcClock, Adc),
     (wdt, WdtClock, Wdt),
@@ -441,6 +555,7 @@ clock_generator!(
     (sercom3_core, Sercom3CoreClock, Sercom3Core),
     (sercom4_core, Sercom4CoreClock, Sercom4Core),
     (sercom5_core, Sercom5CoreClock, Sercom5Core),
+    (sercomx_slow, SercomxSlowClock, SercomxSlow),
     (usb, UsbClock, Usb),
     (rtc, RtcClock, Rtc),
     (adc, AdcClock, Adc),
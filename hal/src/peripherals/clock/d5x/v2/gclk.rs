@@ -324,6 +324,25 @@
 //! This prevents users from changing its `Source` or divider if any *other,
 //! additional* clock consumes it (besides the main clock).
 //!
+//! ## Dynamic frequency scaling
+//!
+//! Because of these restrictions, the CPU can be slowed down while idle and
+//! sped up for bursts of work by changing the `Source` or divider of
+//! `EnabledGclk0` at runtime, but only as long as no peripheral is clocked
+//! from `Gclk0`. There is no mechanism to notify peripherals of a frequency
+//! change, and none is needed: clock the peripherals from other `Gclk`s, which
+//! keep their frequency while `Gclk0` changes, so their baud rates and timer
+//! periods stay correct.
+//!
+//! Whatever is derived from the CPU clock must still be recomputed after each
+//! change, such as SysTick reload values or busy-wait loop counts. Read the
+//! new frequency with [`EnabledGclk0::freq`].
+//!
+//! When switching, order the steps so that the CPU never runs faster than the
+//! flash wait states allow. For example, raise the divider before swapping to
+//! a faster source, and lower it afterwards. See the `clock_scaling` example
+//! of the `metro_m4` BSP.
+//!
 //! [`clock_system_at_reset`]: super::clock_system_at_reset
 //! [`Xosc`]: super::xosc::Xosc
 //! [`Xosc32k`]: super::xosc32k::Xosc32k
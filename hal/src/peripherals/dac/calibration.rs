@@ -0,0 +1,145 @@
+//! Offset and gain correction.
+
+/// Linear correction of the offset and gain errors of a DAC channel
+///
+/// Neither the SAMD11/SAMD21 nor the SAMx5x NVM Software Calibration Area
+/// holds a factory calibration for the DAC. The internal references are
+/// trimmed at reset, but their remaining error, as well as the error of an
+/// external reference or of VDDANA, shows up as a gain error of the output.
+/// Both errors can be measured once per board, for example with
+/// [`from_measurements`](Self::from_measurements), and stored alongside the
+/// application.
+///
+/// The output voltage of a calibrated channel is modelled as
+///
+/// ```text
+/// output = offset + code * reference / (max_code + 1) * (1 + gain_error)
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Calibration {
+    /// Output voltage for a code of zero, in microvolts
+    pub offset_uv: i32,
+    /// Gain error, in parts per million
+    pub gain_ppm: i32,
+}
+
+impl Calibration {
+    /// No correction
+    pub const NONE: Self = Self {
+        offset_uv: 0,
+        gain_ppm: 0,
+    };
+
+    /// Compute the calibration from the output voltage measured in
+    /// microvolts, for two codes
+    ///
+    /// The codes should be far apart, for example at 10% and 90% of the full
+    /// scale, to limit the effect of measurement noise. Returns `None` if
+    /// both codes are equal.
+    pub fn from_measurements(
+        reference_mv: u16,
+        max_code: u16,
+        (low_code, low_uv): (u16, i32),
+        (high_code, high_uv): (u16, i32),
+    ) -> Option<Self> {
+        let codes = i64::from(high_code) - i64::from(low_code);
+        if codes == 0 {
+            return None;
+        }
+        let volts = i64::from(high_uv) - i64::from(low_uv);
+
+        // Measured and ideal slopes are compared with a common denominator, so
+        // that no precision is lost to integer division
+        let measured = volts * (i64::from(max_code) + 1);
+        let ideal = codes * i64::from(reference_mv) * 1000;
+        let gain_ppm = div_round(measured * 1_000_000, ideal) - 1_000_000;
+        let offset_uv = i64::from(low_uv) - div_round(volts * i64::from(low_code), codes);
+
+        Some(Self {
+            offset_uv: offset_uv as i32,
+            gain_ppm: gain_ppm as i32,
+        })
+    }
+
+    /// Compute the code producing `millivolts` at the output of a DAC with
+    /// the given reference voltage and maximum code
+    ///
+    /// The code is rounded to the nearest step, and saturates at zero and at
+    /// `max_code` for voltages outside of the achievable range.
+    pub fn code(&self, millivolts: u16, reference_mv: u16, max_code: u16) -> u16 {
+        let target = i64::from(millivolts) * 1000 - i64::from(self.offset_uv);
+        let numerator = target * (i64::from(max_code) + 1) * 1_000_000;
+        let denominator = i64::from(reference_mv) * 1000 * (1_000_000 + i64::from(self.gain_ppm));
+        if denominator <= 0 {
+            return 0;
+        }
+        div_round(numerator, denominator).clamp(0, i64::from(max_code)) as u16
+    }
+}
+
+/// Divide, rounding to the nearest integer, with ties away from zero
+fn div_round(numerator: i64, denominator: i64) -> i64 {
+    let half = denominator.abs() / 2;
+    if (numerator < 0) == (denominator < 0) {
+        (numerator + half * denominator.signum()) / denominator
+    } else {
+        (numerator - half * denominator.signum()) / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncalibrated_code() {
+        let cal = Calibration::NONE;
+        assert_eq!(cal.code(0, 3300, 4095), 0);
+        assert_eq!(cal.code(1650, 3300, 4095), 2048);
+        assert_eq!(cal.code(3300, 3300, 4095), 4095);
+        assert_eq!(cal.code(5000, 3300, 4095), 4095);
+        assert_eq!(cal.code(500, 1000, 1023), 512);
+    }
+
+    #[test]
+    fn calibrated_code() {
+        // A 10mV offset lowers the code by 10mV / (3300mV / 4096) = 12.4 steps
+        let offset = Calibration {
+            offset_uv: 10_000,
+            gain_ppm: 0,
+        };
+        assert_eq!(offset.code(1650, 3300, 4095), 2036);
+        assert_eq!(offset.code(5, 3300, 4095), 0);
+
+        // A 1% excess gain lowers the code by 1%
+        let gain = Calibration {
+            offset_uv: 0,
+            gain_ppm: 10_000,
+        };
+        assert_eq!(gain.code(1650, 3300, 4095), 2028);
+    }
+
+    #[test]
+    fn calibration_from_measurements() {
+        let cal = Calibration {
+            offset_uv: -4_000,
+            gain_ppm: 2_500,
+        };
+        // Simulate the output of a DAC with the calibration above
+        let output = |code: u16| {
+            let ideal = i64::from(code) * 3_300_000 / 4096;
+            (ideal * 1_002_500 / 1_000_000 - 4_000) as i32
+        };
+        let measured =
+            Calibration::from_measurements(3300, 4095, (410, output(410)), (3686, output(3686)))
+                .unwrap();
+        assert!((measured.offset_uv - cal.offset_uv).abs() <= 2);
+        assert!((measured.gain_ppm - cal.gain_ppm).abs() <= 2);
+
+        assert_eq!(
+            Calibration::from_measurements(3300, 4095, (100, 0), (100, 1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Digital-to-Analogue Conversion
+//!
+//! The [`Dac`] drives a single 10-bit output, on pin PA02. Output codes can
+//! be set directly with [`Dac::set_value`], or computed from a voltage with
+//! [`Dac::set_voltage_mv`], using the reference voltage and the
+//! [`Calibration`] of the [`Dac`].
+//!
+//! ```no_run
+//! # use atsamd_hal::clock::GenericClockController;
+//! # use atsamd_hal::dac::{Calibration, Dac, Reference};
+//! # use atsamd_hal::gpio::{Pin, PA02};
+//! # fn example(
+//! #     mut peripherals: atsamd_hal::pac::Peripherals,
+//! #     clocks: &mut GenericClockController,
+//! #     pa02: Pin<PA02, atsamd_hal::gpio::Disabled<atsamd_hal::gpio::Floating>>,
+//! # ) {
+//! let gclk0 = clocks.gclk0();
+//! let dac_clock = clocks.dac(&gclk0).unwrap();
+//! let mut dac = Dac::new(
+//!     peripherals.dac,
+//!     &mut peripherals.pm,
+//!     &dac_clock,
+//!     pa02.into(),
+//!     Reference::Avcc,
+//!     3300,
+//! );
+//! dac.set_calibration(Calibration {
+//!     offset_uv: 2_500,
+//!     gain_ppm: -1_200,
+//! });
+//! dac.set_voltage_mv(1200);
+//! # }
+//! ```
+
+use crate::clock::DacClock;
+use crate::gpio::{AlternateB, Pin, PA02};
+use crate::pac::{self, Pm};
+
+mod calibration;
+pub use calibration::Calibration;
+
+/// Reference voltage (or its source)
+///
+/// The internal reference, [`Int1v`](Reference::Int1v), is nominally 1000mV.
+pub use pac::dac::ctrlb::Refselselect as Reference;
+
+/// Pin driven by the DAC output
+pub type Vout = Pin<PA02, AlternateB>;
+
+/// Largest output code
+pub const MAX_CODE: u16 = 0x3FF;
+
+/// `Dac` encapsulates the device DAC
+pub struct Dac {
+    dac: pac::Dac,
+    vout: Vout,
+    reference_mv: u16,
+    calibration: Calibration,
+}
+
+impl Dac {
+    /// Create and enable a new `Dac`, with an output of zero
+    ///
+    /// `reference_mv` is the voltage of `reference`, in millivolts. The DAC
+    /// can't measure it, so it must be provided for
+    /// [`set_voltage_mv`](Self::set_voltage_mv) to be accurate.
+    pub fn new(
+        dac: pac::Dac,
+        pm: &mut Pm,
+        _clock: &DacClock,
+        vout: Vout,
+        reference: Reference,
+        reference_mv: u16,
+    ) -> Self {
+        pm.apbcmask().modify(|_, w| w.dac_().set_bit());
+
+        dac.ctrla().write(|w| w.swrst().set_bit());
+        while dac.ctrla().read().swrst().bit_is_set() || dac.status().read().syncbusy().bit_is_set()
+        {
+        }
+
+        dac.ctrlb().write(|w| {
+            w.refsel().variant(reference);
+            w.eoen().set_bit()
+        });
+        dac.ctrla().write(|w| w.enable().set_bit());
+        while dac.status().read().syncbusy().bit_is_set() {}
+
+        let mut dac = Self {
+            dac,
+            vout,
+            reference_mv,
+            calibration: Calibration::NONE,
+        };
+        dac.set_value(0);
+        dac
+    }
+
+    /// Set the output code, clamped to [`MAX_CODE`]
+    #[inline]
+    pub fn set_value(&mut self, code: u16) {
+        self.dac
+            .data()
+            .write(|w| unsafe { w.data().bits(code.min(MAX_CODE)) });
+        while self.dac.status().read().syncbusy().bit_is_set() {}
+    }
+
+    /// Set the output voltage, in millivolts
+    ///
+    /// The code is computed from the reference voltage and the calibration.
+    /// Voltages outside of the achievable range saturate.
+    #[inline]
+    pub fn set_voltage_mv(&mut self, millivolts: u16) {
+        let code = self
+            .calibration
+            .code(millivolts, self.reference_mv, MAX_CODE);
+        self.set_value(code);
+    }
+
+    /// Set the correction applied by [`set_voltage_mv`](Self::set_voltage_mv)
+    #[inline]
+    pub fn set_calibration(&mut self, calibration: Calibration) {
+        self.calibration = calibration;
+    }
+
+    /// Get the correction applied by [`set_voltage_mv`](Self::set_voltage_mv)
+    #[inline]
+    pub fn get_calibration(&self) -> Calibration {
+        self.calibration
+    }
+
+    /// Get the reference voltage, in millivolts
+    #[inline]
+    pub fn get_reference_mv(&self) -> u16 {
+        self.reference_mv
+    }
+
+    /// Disable the DAC, and return the underlying peripheral and pin
+    pub fn free(self) -> (pac::Dac, Vout) {
+        self.dac.ctrla().write(|w| w.enable().clear_bit());
+        while self.dac.status().read().syncbusy().bit_is_set() {}
+        (self.dac, self.vout)
+    }
+}
@@ -0,0 +1,204 @@
+//! Digital-to-Analogue Conversion
+//!
+//! The [`Dac`] drives up to two 12-bit outputs, on pins PA02 ([`Vout0`]) and
+//! PA05 ([`Vout1`]). Output codes can be set directly with
+//! [`Dac::set_value`], or computed from a voltage with
+//! [`Dac::set_voltage_mv`], using the reference voltage and the
+//! [`Calibration`] of each channel.
+//!
+//! ```no_run
+//! # use atsamd_hal::clock::GenericClockController;
+//! # use atsamd_hal::dac::{Calibration, Channel, Dac, Reference};
+//! # use atsamd_hal::gpio::{Pin, PA02};
+//! # fn example(
+//! #     mut peripherals: atsamd_hal::pac::Peripherals,
+//! #     clocks: &mut GenericClockController,
+//! #     pa02: Pin<PA02, atsamd_hal::gpio::Disabled<atsamd_hal::gpio::Floating>>,
+//! # ) {
+//! use atsamd_hal::pac::gclk::{genctrl::Srcselect, pchctrl::Genselect};
+//!
+//! // The DAC clock must not exceed 12MHz
+//! let gclk = clocks
+//!     .configure_gclk_divider_and_source(Genselect::Gclk4, 4, Srcselect::Dfll, false)
+//!     .unwrap();
+//! let dac_clock = clocks.dac(&gclk).unwrap();
+//! let mut dac = Dac::new(
+//!     peripherals.dac,
+//!     &mut peripherals.mclk,
+//!     &dac_clock,
+//!     Some(pa02.into()),
+//!     None,
+//!     Reference::Vddana,
+//!     3300,
+//! );
+//! dac.set_calibration(
+//!     Channel::Ch0,
+//!     Calibration {
+//!         offset_uv: 2_500,
+//!         gain_ppm: -1_200,
+//!     },
+//! );
+//! dac.set_voltage_mv(Channel::Ch0, 1200);
+//! # }
+//! ```
+
+use fugit::RateExtU32;
+
+use crate::clock::DacClock;
+use crate::gpio::{AlternateB, Pin, PA02, PA05};
+use crate::pac::{self, Mclk};
+
+mod calibration;
+pub use calibration::Calibration;
+
+/// Reference voltage (or its source)
+///
+/// The internal reference, [`Intref`](Reference::Intref), is selected by the
+/// `SUPC.VREF.SEL` field, and is 1000mV after reset.
+pub use pac::dac::ctrlb::Refselselect as Reference;
+
+/// Pin driven by the output of [`Channel::Ch0`]
+pub type Vout0 = Pin<PA02, AlternateB>;
+
+/// Pin driven by the output of [`Channel::Ch1`]
+pub type Vout1 = Pin<PA05, AlternateB>;
+
+/// Largest output code
+pub const MAX_CODE: u16 = 0xFFF;
+
+/// DAC output channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Channel {
+    /// Channel 0, driving [`Vout0`]
+    Ch0 = 0,
+    /// Channel 1, driving [`Vout1`]
+    Ch1 = 1,
+}
+
+/// `Dac` encapsulates the device DAC
+pub struct Dac {
+    dac: pac::Dac,
+    vout0: Option<Vout0>,
+    vout1: Option<Vout1>,
+    reference_mv: u16,
+    calibration: [Calibration; 2],
+}
+
+impl Dac {
+    /// Create and enable a new `Dac`, with an output of zero
+    ///
+    /// Only the channels given a pin are enabled. `reference_mv` is the
+    /// voltage of `reference`, in millivolts. The DAC can't measure it, so it
+    /// must be provided for [`set_voltage_mv`](Self::set_voltage_mv) to be
+    /// accurate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency of `clock` exceeds 12MHz.
+    pub fn new(
+        dac: pac::Dac,
+        mclk: &mut Mclk,
+        clock: &DacClock,
+        vout0: Option<Vout0>,
+        vout1: Option<Vout1>,
+        reference: Reference,
+        reference_mv: u16,
+    ) -> Self {
+        let freq = clock.freq();
+        assert!(freq <= 12.MHz::<1, 1>(), "DAC clock exceeds 12MHz");
+
+        mclk.apbdmask().modify(|_, w| w.dac_().set_bit());
+
+        dac.ctrla().write(|w| w.swrst().set_bit());
+        while dac.syncbusy().read().swrst().bit_is_set() {}
+
+        dac.ctrlb().write(|w| w.refsel().variant(reference));
+
+        let enabled = [vout0.is_some(), vout1.is_some()];
+        for (n, &enable) in enabled.iter().enumerate() {
+            dac.dacctrl(n).write(|w| {
+                // The current control must match the clock frequency
+                if freq <= 1_200.kHz::<1, 1>() {
+                    w.cctrl().cc100k();
+                } else if freq <= 6.MHz::<1, 1>() {
+                    w.cctrl().cc1m();
+                } else {
+                    w.cctrl().cc12m();
+                }
+                w.enable().bit(enable)
+            });
+        }
+
+        dac.ctrla().write(|w| w.enable().set_bit());
+        while dac.syncbusy().read().enable().bit_is_set() {}
+
+        let mut dac = Self {
+            dac,
+            vout0,
+            vout1,
+            reference_mv,
+            calibration: [Calibration::NONE; 2],
+        };
+        if enabled[0] {
+            while dac.dac.status().read().ready0().bit_is_clear() {}
+            dac.set_value(Channel::Ch0, 0);
+        }
+        if enabled[1] {
+            while dac.dac.status().read().ready1().bit_is_clear() {}
+            dac.set_value(Channel::Ch1, 0);
+        }
+        dac
+    }
+
+    /// Set the output code of `channel`, clamped to [`MAX_CODE`]
+    ///
+    /// Writing to a channel which was not given a pin has no effect.
+    #[inline]
+    pub fn set_value(&mut self, channel: Channel, code: u16) {
+        self.dac
+            .data(channel as usize)
+            .write(|w| unsafe { w.data().bits(code.min(MAX_CODE)) });
+        match channel {
+            Channel::Ch0 => while self.dac.syncbusy().read().data0().bit_is_set() {},
+            Channel::Ch1 => while self.dac.syncbusy().read().data1().bit_is_set() {},
+        }
+    }
+
+    /// Set the output voltage of `channel`, in millivolts
+    ///
+    /// The code is computed from the reference voltage and the calibration of
+    /// `channel`. Voltages outside of the achievable range saturate.
+    #[inline]
+    pub fn set_voltage_mv(&mut self, channel: Channel, millivolts: u16) {
+        let code = self.calibration[channel as usize].code(millivolts, self.reference_mv, MAX_CODE);
+        self.set_value(channel, code);
+    }
+
+    /// Set the correction applied by [`set_voltage_mv`](Self::set_voltage_mv)
+    /// to `channel`
+    #[inline]
+    pub fn set_calibration(&mut self, channel: Channel, calibration: Calibration) {
+        self.calibration[channel as usize] = calibration;
+    }
+
+    /// Get the correction applied by [`set_voltage_mv`](Self::set_voltage_mv)
+    /// to `channel`
+    #[inline]
+    pub fn get_calibration(&self, channel: Channel) -> Calibration {
+        self.calibration[channel as usize]
+    }
+
+    /// Get the reference voltage, in millivolts
+    #[inline]
+    pub fn get_reference_mv(&self) -> u16 {
+        self.reference_mv
+    }
+
+    /// Disable the DAC, and return the underlying peripheral and pins
+    pub fn free(self) -> (pac::Dac, Option<Vout0>, Option<Vout1>) {
+        self.dac.ctrla().write(|w| w.enable().clear_bit());
+        while self.dac.syncbusy().read().enable().bit_is_set() {}
+        (self.dac, self.vout0, self.vout1)
+    }
+}
@@ -0,0 +1,79 @@
+//! # CPU cycle counter
+//!
+//! The Cortex-M4 core of SAMx5x chips includes a Data Watchpoint and Trace
+//! (DWT) unit, whose `CYCCNT` register counts CPU clock cycles. This makes it
+//! a cheap and precise way to benchmark a block of code:
+//!
+//! ```no_run
+//! use atsamd_hal::dwt::CycleCounter;
+//! use atsamd_hal::pac::CorePeripherals;
+//!
+//! let mut core = CorePeripherals::take().unwrap();
+//! let counter = CycleCounter::new(&mut core.DCB, core.DWT);
+//!
+//! let src = [0u8; 1024];
+//! let mut dst = [0u8; 1024];
+//! let cycles = counter.measure(|| dst.copy_from_slice(&src));
+//! ```
+//!
+//! The counter is 32 bits wide, so it wraps around after `2^32` cycles, about
+//! 35 seconds at 120 MHz. Durations are computed with wrapping arithmetic, so
+//! they are correct as long as the measured code runs for less than one full
+//! period.
+//!
+//! The Cortex-M0+ core of SAMD11/SAMD21 chips has no cycle counter, so this
+//! module is only available on SAMx5x chips. On those chips, use a
+//! [`TimerCounter`](crate::timer::TimerCounter) to time code instead.
+
+use cortex_m::peripheral::{DCB, DWT};
+
+/// CPU cycle counter, backed by the DWT `CYCCNT` register
+///
+/// Creating a [`CycleCounter`] enables the counter, and holding it guarantees
+/// that the counter keeps running.
+pub struct CycleCounter {
+    dwt: DWT,
+}
+
+impl CycleCounter {
+    /// Enable tracing and start the cycle counter
+    ///
+    /// The counter is not reset, it keeps counting from its current value.
+    #[inline]
+    pub fn new(dcb: &mut DCB, mut dwt: DWT) -> Self {
+        dcb.enable_trace();
+        dwt.enable_cycle_counter();
+        Self { dwt }
+    }
+
+    /// Current value of the cycle counter
+    #[inline]
+    pub fn cycle_count(&self) -> u32 {
+        DWT::cycle_count()
+    }
+
+    /// Number of cycles elapsed since `start`, a value previously returned by
+    /// [`cycle_count`](Self::cycle_count)
+    #[inline]
+    pub fn elapsed(&self, start: u32) -> u32 {
+        self.cycle_count().wrapping_sub(start)
+    }
+
+    /// Run `f` and return the number of cycles it took
+    ///
+    /// The result includes the few cycles needed to read the counter around
+    /// `f`.
+    #[inline]
+    pub fn measure(&self, f: impl FnOnce()) -> u32 {
+        let start = self.cycle_count();
+        f();
+        self.elapsed(start)
+    }
+
+    /// Stop the cycle counter and return the DWT peripheral
+    #[inline]
+    pub fn free(mut self) -> DWT {
+        self.dwt.disable_cycle_counter();
+        self.dwt
+    }
+}
@@ -42,6 +42,33 @@
 //! let mut extint = eic_channels.2.with_pin(button);
 //! ```
 //!
+//! ## Event output
+//!
+//! Instead of, or in addition to, raising an interrupt, an [`ExtInt`] can
+//! generate an event for the event system (EVSYS) with
+//! [`ExtInt::enable_event`]. This sets the channel's `EXTINTEO` bit in the
+//! `EVCTRL` register. The register is enable-protected on SAMx5x chips, so
+//! the EIC is briefly disabled while it is written.
+//! [`ExtInt::disable_event`] clears it again. Other channels' event outputs
+//! are left untouched.
+//!
+//! The event follows the [`sense`](ExtInt::sense) configuration of the
+//! channel: for edge detection, a pulse is generated on every selected edge,
+//! and for level detection, the event stays active for as long as the level
+//! is. The filter also applies, when enabled.
+//!
+//! The HAL has no abstraction over EVSYS yet, so the event must be routed to
+//! its user through the PAC. Use the `EIC_EXTINT_x` event generator ID, and
+//! the ID of the user, such as an ADC start input or a TC event input. The
+//! datasheet lists both in the EVSYS chapter. Consumers then react to the pin
+//! without any CPU involvement. See the `adc_event_trigger` and `pwm_fault`
+//! examples of the `metro_m4` BSP.
+//!
+//! Routed to a timer in
+//! [event counter mode](crate::timer::TimerCounter::start_event_counter), the
+//! event counts pulses in hardware. Unlike the interrupt-driven edge counter
+//! below, this keeps up with pulse rates in the MHz range.
+//!
 //! ## `async` operation <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
 //!
 //! [`ExtInt`]s can be used for async operations. Configuring the [`Eic`] in
@@ -61,6 +88,38 @@
 //!   async channels.
 //! * Use the provided [`wait`](ExtInt::wait) method. async-enabled [`ExtInt`]s
 //!   also implement [`embedded_hal_async::digital::Wait`].
+//!
+//! ## Buttons <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
+//!
+//! A [`Button`] wraps an async-enabled [`ExtInt`] connected to a push button.
+//! It debounces the pin in hardware, and provides
+//! [`wait_for_press`](Button::wait_for_press) and
+//! [`wait_for_release`](Button::wait_for_release), for buttons that are
+//! either active-low or active-high.
+//!
+//! ## Counting edges <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
+//!
+//! To count pulses without writing an interrupt handler, bind the `EIC`
+//! interrupt source of the channel to the [`EdgeCounterHandler`], and turn the
+//! [`ExtInt`] into an [`EdgeCounter`] by calling
+//! [`ExtInt::into_edge_counter`]. The handler increments an atomic counter on
+//! every selected edge, which [`EdgeCounter::count`] and
+//! [`EdgeCounter::reset`] read.
+//!
+//! The [`EdgeCounterHandler`] and the `async` [`InterruptHandler`] can be bound
+//! to the same interrupt source. Each one only services its own channels.
+//!
+//! ```no_run
+//! atsamd_hal::bind_interrupts!(struct Irqs {
+//!     EIC_EXTINT_7 => atsamd_hal::eic::EdgeCounterHandler;
+//! });
+//!
+//! let tach: Pin<_, PullUpInterrupt> = pins.d0.into();
+//! let mut counter = eic_channels.7.with_pin(tach).into_edge_counter(Sense::Fall, Irqs);
+//!
+//! // Once per second
+//! let pulses = counter.reset();
+//! ```
 
 use core::marker::PhantomData;
 
@@ -82,6 +141,16 @@ mod impls {}
 #[cfg(feature = "async")]
 pub use impls::async_api::*;
 
+#[cfg(feature = "async")]
+mod button;
+#[cfg(feature = "async")]
+pub use button::Button;
+
+#[cfg(feature = "async")]
+mod counter;
+#[cfg(feature = "async")]
+pub use counter::{EdgeCounter, EdgeCounterHandler};
+
 #[hal_cfg("eic-d5x")]
 use super::clock::v2::{self, gclk::GclkId, osculp32k::OscUlp32kId, pclk::Pclk, rtcosc::RtcOsc};
 
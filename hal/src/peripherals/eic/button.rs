@@ -0,0 +1,112 @@
+//! Debounced push buttons.
+
+use core::convert::Infallible;
+
+use atsamd_hal_macros::hal_macro_helper;
+
+use super::{ChId, EicFuture, EicPin, ExtInt, Sense};
+use crate::ehal::digital::{InputPin, PinState};
+
+/// A push button, debounced by the EIC, that can be awaited
+///
+/// A [`Button`] wraps an `async` [`ExtInt`], and knows which level the pin
+/// has while the button is pressed. Buttons pulling the pin to ground, with a
+/// pull-up enabled, are pressed at [`PinState::Low`]. Buttons pulling the pin
+/// to VDD are pressed at [`PinState::High`].
+///
+/// The pin is debounced in hardware:
+///
+/// * SAMx5x: the debouncer of the channel requires the pin to hold its new
+///   level for 7 consecutive samples of the low-power 32 kHz clock divided by
+///   16, about 3.5 ms, before an edge is seen.
+/// * SAMD11/SAMD21: the filter of the channel takes a majority vote of three
+///   samples, at the frequency of the EIC clock. Bounces are only filtered if
+///   the EIC is clocked slowly, with a GCLK of about 1 kHz.
+///
+/// ```no_run
+/// # async fn example<P: atsamd_hal::eic::EicPin, Id: atsamd_hal::eic::ChId>(
+/// #     extint: atsamd_hal::eic::ExtInt<P, Id, atsamd_hal::eic::EicFuture>,
+/// # ) where atsamd_hal::eic::ExtInt<P, Id, atsamd_hal::eic::EicFuture>:
+/// #     atsamd_hal::ehal::digital::InputPin<Error = core::convert::Infallible>,
+/// # {
+/// use atsamd_hal::ehal::digital::PinState;
+/// use atsamd_hal::eic::Button;
+///
+/// let mut button = Button::new(extint, PinState::Low);
+/// loop {
+///     button.wait_for_press().await;
+///     // React to the press
+///     button.wait_for_release().await;
+/// }
+/// # }
+/// ```
+pub struct Button<P, Id>
+where
+    P: EicPin,
+    Id: ChId,
+{
+    extint: ExtInt<P, Id, EicFuture>,
+    pressed: PinState,
+}
+
+impl<P, Id> Button<P, Id>
+where
+    P: EicPin,
+    Id: ChId,
+    ExtInt<P, Id, EicFuture>: InputPin<Error = Infallible>,
+{
+    /// Create a new [`Button`], pressed when the pin is at the `pressed`
+    /// level
+    ///
+    /// This enables the debouncer (SAMx5x) or the filter (SAMD11/SAMD21) of
+    /// the channel.
+    #[inline]
+    #[hal_macro_helper]
+    pub fn new(mut extint: ExtInt<P, Id, EicFuture>, pressed: PinState) -> Self {
+        #[hal_cfg("eic-d5x")]
+        extint.debounce();
+        #[hal_cfg(any("eic-d11", "eic-d21"))]
+        extint.filter(true);
+        Self { extint, pressed }
+    }
+
+    /// Return `true` if the button is currently pressed
+    #[inline]
+    pub fn is_pressed(&mut self) -> bool {
+        self.extint.is_high().unwrap() == (self.pressed == PinState::High)
+    }
+
+    /// Wait until the button is pressed
+    ///
+    /// This waits for the edge of a press, so it doesn't complete immediately
+    /// if the button is already held down.
+    #[inline]
+    pub async fn wait_for_press(&mut self) {
+        let sense = match self.pressed {
+            PinState::Low => Sense::Fall,
+            PinState::High => Sense::Rise,
+        };
+        self.extint.wait(sense).await;
+    }
+
+    /// Wait until the button is released
+    ///
+    /// This waits for the edge of a release, so it doesn't complete
+    /// immediately if the button is not pressed.
+    #[inline]
+    pub async fn wait_for_release(&mut self) {
+        let sense = match self.pressed {
+            PinState::Low => Sense::Rise,
+            PinState::High => Sense::Fall,
+        };
+        self.extint.wait(sense).await;
+    }
+
+    /// Consume the [`Button`] and return the underlying [`ExtInt`]
+    ///
+    /// The debouncer or filter is left enabled.
+    #[inline]
+    pub fn free(self) -> ExtInt<P, Id, EicFuture> {
+        self.extint
+    }
+}
@@ -0,0 +1,162 @@
+//! Interrupt-driven edge counting.
+
+use atsamd_hal_macros::hal_cfg;
+use portable_atomic::{AtomicU32, Ordering};
+
+use super::{ChId, EicPin, ExtInt, Sense, NUM_CHANNELS};
+use crate::async_hal::interrupts::{Binding, Handler, InterruptSource};
+use crate::pac;
+use crate::util::BitIter;
+
+#[hal_cfg(any("eic-d11", "eic-d21"))]
+use crate::async_hal::interrupts::EIC;
+
+/// Bitmask of the channels currently owned by an [`EdgeCounter`]
+///
+/// The `async` [`InterruptHandler`](super::InterruptHandler) ignores these
+/// channels, so that both handlers can be bound to the same interrupt.
+pub(super) static COUNTING: AtomicU32 = AtomicU32::new(0);
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NEW_COUNT: AtomicU32 = AtomicU32::new(0);
+static COUNTS: [AtomicU32; NUM_CHANNELS] = [NEW_COUNT; NUM_CHANNELS];
+
+/// Interrupt handler incrementing the count of every [`EdgeCounter`]
+pub struct EdgeCounterHandler {
+    _private: (),
+}
+
+impl crate::typelevel::Sealed for EdgeCounterHandler {}
+
+impl EdgeCounterHandler {
+    /// Count and clear the pending edges of the counting channels
+    fn count_pending() {
+        let eic = unsafe { pac::Peripherals::steal().eic };
+
+        let counting = COUNTING.load(Ordering::Relaxed);
+        let pending = eic.intflag().read().bits() & counting;
+        unsafe { eic.intflag().write(|w| w.bits(pending)) };
+        for channel in BitIter(pending) {
+            COUNTS[channel as usize].add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[hal_cfg(any("eic-d11", "eic-d21"))]
+impl Handler<EIC> for EdgeCounterHandler {
+    unsafe fn on_interrupt() {
+        Self::count_pending();
+    }
+}
+
+#[hal_cfg("eic-d5x")]
+seq_macro::seq!(N in 0..=15 {
+    paste::paste! {
+        impl Handler<crate::async_hal::interrupts::[<EIC_EXTINT_ ~N>]> for EdgeCounterHandler {
+            unsafe fn on_interrupt() {
+                Self::count_pending();
+            }
+        }
+    }
+});
+
+/// An [`ExtInt`] counting edges on its pin from the EIC interrupt
+///
+/// Every edge selected by the [`Sense`] increments a counter in the
+/// [`EdgeCounterHandler`], so pulses are counted even while the application is
+/// busy or sleeping. This is convenient for low-rate pulse trains, such as
+/// tachometers and flow sensors.
+///
+/// Each edge costs one interrupt. At pulse rates approaching the interrupt
+/// latency, edges are merged and the count falls short. Such signals are
+/// better counted in hardware, by routing the channel's event (see
+/// [`ExtInt::enable_event`]) to a counter through the event system.
+pub struct EdgeCounter<P, Id, F>
+where
+    P: EicPin,
+    Id: ChId,
+{
+    extint: ExtInt<P, Id, F>,
+}
+
+impl<P, Id, F> ExtInt<P, Id, F>
+where
+    P: EicPin,
+    Id: ChId,
+{
+    /// Start counting the edges selected by `sense` on this pin
+    ///
+    /// Only edge senses ([`Sense::Rise`], [`Sense::Fall`] or [`Sense::Both`])
+    /// are meaningful; level senses count once per interrupt for as long as
+    /// the level holds.
+    #[hal_cfg(any("eic-d11", "eic-d21"))]
+    pub fn into_edge_counter<I>(self, sense: Sense, _irq: I) -> EdgeCounter<P, Id, F>
+    where
+        I: Binding<EIC, EdgeCounterHandler>,
+    {
+        let counter = self.start_counting(sense);
+        EIC::unpend();
+        unsafe { EIC::enable() };
+        counter
+    }
+
+    /// Start counting the edges selected by `sense` on this pin
+    ///
+    /// Only edge senses ([`Sense::Rise`], [`Sense::Fall`] or [`Sense::Both`])
+    /// are meaningful; level senses count once per interrupt for as long as
+    /// the level holds.
+    #[hal_cfg("eic-d5x")]
+    pub fn into_edge_counter<I>(self, sense: Sense, _irq: I) -> EdgeCounter<P, Id, F>
+    where
+        I: Binding<P::InterruptSource, EdgeCounterHandler>,
+        EdgeCounterHandler: Handler<P::InterruptSource>,
+    {
+        let counter = self.start_counting(sense);
+        P::InterruptSource::unpend();
+        unsafe { P::InterruptSource::enable() };
+        counter
+    }
+
+    fn start_counting(mut self, sense: Sense) -> EdgeCounter<P, Id, F> {
+        self.disable_interrupt();
+        COUNTS[Id::ID].store(0, Ordering::Relaxed);
+        COUNTING.bit_set(Id::ID as u32, Ordering::Relaxed);
+        self.sense(sense);
+        self.clear_interrupt();
+        self.enable_interrupt();
+        EdgeCounter { extint: self }
+    }
+}
+
+impl<P, Id, F> EdgeCounter<P, Id, F>
+where
+    P: EicPin,
+    Id: ChId,
+{
+    /// Number of edges counted since the counter was created or last reset
+    ///
+    /// The count wraps around after `u32::MAX` edges.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        COUNTS[Id::ID].load(Ordering::Relaxed)
+    }
+
+    /// Reset the count to zero, and return the count it had
+    ///
+    /// No edge is lost between reading and resetting the count, so calling
+    /// this periodically measures the number of edges in each period.
+    #[inline]
+    pub fn reset(&mut self) -> u32 {
+        COUNTS[Id::ID].swap(0, Ordering::Relaxed)
+    }
+
+    /// Stop counting and return the underlying [`ExtInt`]
+    pub fn free(self) -> ExtInt<P, Id, F> {
+        let mut extint = self.extint;
+        extint.disable_interrupt();
+        extint.sense(Sense::None);
+        extint.clear_interrupt();
+        COUNTING.bit_clear(Id::ID as u32, Ordering::Relaxed);
+        extint
+    }
+}
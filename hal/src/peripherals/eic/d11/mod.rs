@@ -33,6 +33,9 @@ pub(super) mod async_api {
     use crate::eic::{Eic, EicFuture, NUM_CHANNELS};
     use crate::pac;
     use crate::util::BitIter;
+    use portable_atomic::Ordering;
+
+    use super::super::counter::COUNTING;
 
     use core::marker::PhantomData;
 
@@ -48,7 +51,9 @@ pub(super) mod async_api {
         unsafe fn on_interrupt() {
             let eic = pac::Peripherals::steal().eic;
 
-            let pending_interrupts = BitIter(eic.intflag().read().bits());
+            // Channels owned by an `EdgeCounter` are serviced by its own handler
+            let counting = COUNTING.load(Ordering::Relaxed);
+            let pending_interrupts = BitIter(eic.intflag().read().bits() & !counting);
             for channel in pending_interrupts {
                 let mask = 1 << channel;
                 // Disable the interrupt but don't clear; will be cleared
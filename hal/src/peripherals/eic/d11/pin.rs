@@ -59,7 +59,18 @@ where
     pub fn enable_event(&mut self) {
         self.chan.with_disable(|e| {
             e.evctrl()
-                .modify(|_, w| unsafe { w.bits(1 << P::ChId::ID) });
+                .modify(|r, w| unsafe { w.bits(r.bits() | 1 << P::ChId::ID) });
+        });
+    }
+
+    /// Disables the event output of the channel.
+    ///
+    /// Note that whilst this function is executed, the EIC peripheral is disabled
+    /// in order to write to the evctrl register
+    pub fn disable_event(&mut self) {
+        self.chan.with_disable(|e| {
+            e.evctrl()
+                .modify(|r, w| unsafe { w.bits(r.bits() & !(1 << P::ChId::ID)) });
         });
     }
 
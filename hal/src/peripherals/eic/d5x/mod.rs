@@ -34,6 +34,9 @@ pub(super) mod async_api {
     use crate::async_hal::interrupts::Handler;
     use crate::eic::NUM_CHANNELS;
     use crate::util::BitIter;
+    use portable_atomic::Ordering;
+
+    use super::super::counter::COUNTING;
     use embassy_sync::waitqueue::AtomicWaker;
 
     /// Interrupt handler used for `async` operations.
@@ -50,7 +53,9 @@ pub(super) mod async_api {
                 unsafe fn on_interrupt() {
                     let eic = unsafe { pac::Peripherals::steal().eic };
 
-                    let pending_interrupts = BitIter(eic.intflag().read().bits());
+                    // Channels owned by an `EdgeCounter` are serviced by its own handler
+                    let counting = COUNTING.load(Ordering::Relaxed);
+                    let pending_interrupts = BitIter(eic.intflag().read().bits() & !counting);
                     for channel in pending_interrupts {
                         let mask = 1 << channel;
                         // Disable the interrupt but don't clear; will be cleared
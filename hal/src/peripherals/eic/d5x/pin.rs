@@ -65,7 +65,18 @@ where
     pub fn enable_event(&mut self) {
         self.chan.with_disable(|e| {
             e.evctrl()
-                .modify(|_, w| unsafe { w.bits(1 << P::ChId::ID) });
+                .modify(|r, w| unsafe { w.bits(r.bits() | 1 << P::ChId::ID) });
+        });
+    }
+
+    /// Disables the event output of the channel.
+    ///
+    /// Note that whilst this function is executed, the EIC peripheral is disabled
+    /// in order to write to the evctrl register
+    pub fn disable_event(&mut self) {
+        self.chan.with_disable(|e| {
+            e.evctrl()
+                .modify(|r, w| unsafe { w.bits(r.bits() & !(1 << P::ChId::ID)) });
         });
     }
 
@@ -157,7 +168,7 @@ where
             });
 
             e.debouncen()
-                .modify(|_, w| unsafe { w.bits(P::ChId::ID as u32) });
+                .modify(|r, w| unsafe { w.bits(r.bits() | 1 << P::ChId::ID) });
         });
     }
 }
@@ -251,6 +262,14 @@ mod async_impls {
             use core::{future::poll_fn, task::Poll};
             self.disable_interrupt();
 
+            // Level sensing resolves immediately if the pin is already at the
+            // requested level
+            match sense {
+                Sense::High if self.is_high().unwrap() => return,
+                Sense::Low if self.is_low().unwrap() => return,
+                _ => (),
+            }
+
             self.sense(sense);
             poll_fn(|cx| {
                 if self.is_interrupt() {
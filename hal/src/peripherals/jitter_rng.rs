@@ -0,0 +1,197 @@
+//! Software random number generator seeded from clock jitter
+//!
+//! Chips without a TRNG can use [`JitterRng`] instead. It gathers entropy from
+//! the jitter between two independent oscillators, then uses it to seed a
+//! software PRNG (SplitMix64). Like the TRNG, it implements
+//! [`RngCore`] and the `embedded-hal` 0.2 [`Read`] trait, so code written
+//! against those traits works with either implementation.
+//!
+//! **The quality of the seed depends on the clocks in use, and the PRNG is
+//! not cryptographically secure. [`JitterRng`] does not implement
+//! [`CryptoRng`](rand_core::CryptoRng), and must not be used to generate keys
+//! or nonces.**
+//!
+//! # Gathering entropy
+//!
+//! [`JitterRng::new`] takes two counters, which must be clocked from
+//! independent oscillators:
+//!
+//! - A slow counter, such as the [RTC](crate::rtc::Rtc) clocked from the
+//!   internal 32 kHz oscillator
+//! - A fast counter, such as the SysTick counting CPU cycles from the DFLL
+//!
+//! At every tick of the slow counter, the low bits of the fast counter are
+//! mixed into the seed. The phase between the two oscillators drifts
+//! randomly, so those bits are unpredictable. Running both counters from the
+//! same oscillator, e.g. the DFLL in closed-loop mode, yields little to no
+//! entropy.
+//!
+//! ```no_run
+//! # use atsamd_hal::jitter_rng::JitterRng;
+//! # use atsamd_hal::rtc::{Count32Mode, Rtc};
+//! # use cortex_m::peripheral::{syst::SystClkSource, SYST};
+//! # fn example(rtc: &Rtc<Count32Mode>, mut syst: SYST) {
+//! syst.set_clock_source(SystClkSource::Core);
+//! syst.set_reload(0x00ff_ffff);
+//! syst.enable_counter();
+//! let mut rng = JitterRng::new(|| rtc.count32(), SYST::get_current);
+//! let value = rng.random_u32();
+//! # }
+//! ```
+
+use rand_core::RngCore;
+
+use crate::ehal_02::blocking::rng::Read;
+
+/// Number of slow clock ticks sampled to build a seed
+pub const SEED_SAMPLES: usize = 256;
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Software random number generator seeded from clock jitter
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Debug, Clone)]
+pub struct JitterRng {
+    state: u64,
+}
+
+impl JitterRng {
+    /// Seed a new [`JitterRng`] from the jitter between two counters
+    ///
+    /// `slow` and `fast` read counters clocked from independent oscillators,
+    /// the former being much slower than the latter. Gathering the seed blocks
+    /// for [`SEED_SAMPLES`] ticks of the slow counter.
+    pub fn new(slow: impl FnMut() -> u32, fast: impl FnMut() -> u32) -> Self {
+        Self::from_seed(gather_seed(slow, fast))
+    }
+
+    /// Create a [`JitterRng`] from a known seed
+    ///
+    /// The same seed always yields the same sequence.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn random(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            chunk.copy_from_slice(&self.random_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    pub fn random_u8(&mut self) -> u8 {
+        (self.random_u64() >> 56) as u8
+    }
+
+    pub fn random_u16(&mut self) -> u16 {
+        (self.random_u64() >> 48) as u16
+    }
+
+    pub fn random_u32(&mut self) -> u32 {
+        (self.random_u64() >> 32) as u32
+    }
+
+    pub fn random_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix(self.state)
+    }
+}
+
+/// SplitMix64 output function
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Sample the fast counter at each of [`SEED_SAMPLES`] ticks of the slow
+/// counter
+fn gather_seed(mut slow: impl FnMut() -> u32, mut fast: impl FnMut() -> u32) -> u64 {
+    let mut seed: u64 = 0;
+    for _ in 0..SEED_SAMPLES {
+        let tick = slow();
+        while slow() == tick {}
+        seed = mix(seed.rotate_left(7) ^ fast() as u64);
+    }
+    seed
+}
+
+impl RngCore for JitterRng {
+    fn next_u32(&mut self) -> u32 {
+        self.random_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.random_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.random(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl Read for JitterRng {
+    type Error = ();
+    fn read(&mut self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.random(buffer);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Chi-square statistic of the byte values in `bytes`
+    fn chi_square(bytes: &[u8]) -> f64 {
+        let mut counts = [0u32; 256];
+        for byte in bytes {
+            counts[*byte as usize] += 1;
+        }
+        let expected = bytes.len() as f64 / 256.0;
+        counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    #[test]
+    fn bytes_are_uniformly_distributed() {
+        let mut rng = JitterRng::from_seed(0);
+        let mut bytes = [0; 16384];
+        rng.random(&mut bytes);
+        // 255 degrees of freedom, the 99.9th percentile is about 331
+        assert!(chi_square(&bytes) < 331.0);
+    }
+
+    #[test]
+    fn seed_depends_on_jitter() {
+        // The slow counter ticks every 3 reads, and the fast counter advances
+        // by a variable amount between ticks
+        fn seed(jitter: u32) -> u64 {
+            let mut slow = 0u32;
+            let mut fast = 0u32;
+            let mut reads = 0u32;
+            gather_seed(
+                || {
+                    reads += 1;
+                    if reads % 3 == 0 {
+                        slow += 1;
+                    }
+                    slow
+                },
+                || {
+                    fast = fast.wrapping_add(1000 + (fast ^ jitter) % 7);
+                    fast
+                },
+            )
+        }
+        assert_eq!(seed(1), seed(1));
+        assert_ne!(seed(1), seed(2));
+    }
+}
@@ -6,6 +6,12 @@ use atsamd_hal_macros::{hal_cfg, hal_module};
 )]
 pub mod adc {}
 
+#[hal_module(
+    any("dac-d11", "dac-d21") => "dac/d11.rs",
+    "dac-d5x" => "dac/d5x.rs",
+)]
+pub mod dac {}
+
 #[hal_module(
     any("nvmctrl-d11", "nvmctrl-d21") => "calibration/d11.rs",
     "nvmctrl-d5x" => "calibration/d5x.rs",
@@ -55,6 +61,9 @@ pub mod qspi {}
 #[hal_module("trng")]
 pub mod trng {}
 
+#[hal_module(not("trng"))]
+pub mod jitter_rng {}
+
 #[hal_module("icm")]
 pub mod icm {}
 
@@ -68,6 +77,9 @@ pub mod can {}
 #[hal_module("wdt")]
 pub mod watchdog {}
 
+#[hal_module("dwt")]
+pub mod dwt {}
+
 #[hal_module(any("pm-d11", "pm-d21", "rstc-d5x"))]
 mod reset_cause {}
 
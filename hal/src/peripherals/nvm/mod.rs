@@ -112,6 +112,8 @@ pub enum Error {
     Dsu(super::dsu::Error),
     /// An alignment requirement was not fulfilled
     Alignment,
+    /// Data read back after a write differs from the data written
+    Verify,
 }
 
 /// Physical flash banks
@@ -310,7 +312,13 @@ impl Nvm {
     /// *erase the page in the flash memory* and *write it* back again.
     ///
     /// Erasure and flashing is skipped if the userpage stays the same after
-    /// calling the closure on it.
+    /// calling the closure on it. Otherwise, the page is read back after the
+    /// write, and [`Error::Verify`] is returned if it does not match.
+    ///
+    /// The fuses (BOD, watchdog, bootloader protection, SmartEEPROM and region
+    /// lock settings) are only loaded on reset, so the new values take effect
+    /// after the next reset. [`UserpageStatus::Updated`] tells whether one is
+    /// needed.
     ///
     /// # Safety
     ///
@@ -337,6 +345,10 @@ impl Nvm {
             unsafe { self.erase(NvmErase::Userpage)? };
             unsafe { self.write(NvmWrite::Userpage(&modified))? };
 
+            if self.read_userpage() != modified {
+                return Err(Error::Verify);
+            }
+
             Ok(UserpageStatus::Updated)
         } else {
             Ok(UserpageStatus::Skipped)
@@ -733,7 +745,7 @@ impl Nvm {
 /// The outcome of [`Nvm::modify_userpage`]
 #[derive(Copy, Clone, Debug)]
 pub enum UserpageStatus {
-    /// Userpage has been updated
+    /// Userpage has been updated. Reset the device to apply the new fuses
     Updated,
     /// Update has been skipped; expected value is already present.
     Skipped,
@@ -867,6 +879,18 @@ bitfield! {
     pub u32, reserved_3, set_reserved_3: 159, 128;
 }
 
+impl<T: AsRef<[u8]> + AsMut<[u8]>> RawUserpage<T> {
+    /// Size in bytes of the bootloader section protected by the
+    /// `nvm_bootloader_size` (`BOOTPROT`) field
+    ///
+    /// The protected section starts at address `0x0`, and is 8 KiB long for
+    /// every step below `0xF`. The erased value `0xF` disables the protection.
+    #[inline]
+    pub fn bootloader_size(&self) -> u32 {
+        (15 - self.nvm_bootloader_size() as u32) * BLOCKSIZE
+    }
+}
+
 impl<T: AsRef<[u8]>> RawUserpage<T> {
     /// Access the general purpose user-writable section of the userpage via
     /// slice
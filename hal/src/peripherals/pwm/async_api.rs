@@ -0,0 +1,367 @@
+//! Async APIs for TCC-based PWM.
+//!
+//! Use `into_future` on any of the `Tcc*Pwm` structs to convert them into an
+//! asynchronous [`TccFuture`]. The PWM outputs keep running unchanged; the
+//! future additionally allows awaiting two kinds of events:
+//!
+//! * The end of a PWM period (the `OVF` interrupt), through
+//!   [`wait_period`](TccFuture::wait_period). The [`DelayNs`] implementation is
+//!   built on top of it, and has a resolution of one PWM period.
+//! * A compare match on any compare channel (the `MCx` interrupts), through
+//!   [`wait_compare`](TccFuture::wait_compare). Channels which have no pin
+//!   attached can be used as a free timeout within the period, by setting
+//!   their compare value with [`set_compare`](TccFuture::set_compare).
+//!
+//! Each event has its own waker, and the interrupt handler only wakes the
+//! waker belonging to the event that fired. An interrupt is only enabled
+//! while a future is waiting on it, so a running PWM does not generate any
+//! interrupt traffic when nothing is awaited.
+//!
+//! [`DelayNs`]: embedded_hal_async::delay::DelayNs
+
+use crate::{
+    async_hal::interrupts::{Binding, Handler, InterruptSource},
+    pac,
+    time::Hertz,
+    typelevel::Sealed,
+};
+use atsamd_hal_macros::hal_cfg;
+use core::{future::poll_fn, marker::PhantomData, sync::atomic::Ordering, task::Poll};
+use embassy_sync::waitqueue::AtomicWaker;
+use portable_atomic::{AtomicBool, AtomicU8};
+
+use super::Channel;
+
+type RegBlock = pac::tcc0::RegisterBlock;
+
+/// `INTFLAG`/`INTENSET`/`INTENCLR` bit of the overflow (period) interrupt
+const OVF: u32 = 1 << 0;
+
+/// `INTFLAG`/`INTENSET`/`INTENCLR` bit of the compare match interrupt for
+/// channel `n`
+#[inline]
+const fn mc(n: u8) -> u32 {
+    1 << (16 + n as u32)
+}
+
+/// Trait enabling the use of a TCC in async mode. Specifically, this trait
+/// enables us to register the `TCC*` interrupts as wakers for TCC futures.
+///
+/// **⚠️ Warning** This trait should not be implemented outside of this crate!
+pub trait AsyncTcc: Sealed {
+    /// Index of this TCC in the `STATE` tracker
+    const STATE_ID: usize;
+
+    /// Number of compare channels of this TCC
+    const NUM_CHANNELS: u8;
+
+    /// Get a reference to the TCC's register block
+    fn reg_block(peripherals: &pac::Peripherals) -> &RegBlock;
+
+    /// Interrupt type for this TCC
+    type Interrupt: InterruptSource;
+}
+
+/// PWM driver backed by a TCC, which can be converted to a [`TccFuture`]
+///
+/// **⚠️ Warning** This trait should not be implemented outside of this crate!
+pub trait TccPwm: Sealed {
+    /// The underlying TCC peripheral
+    type Tcc: AsyncTcc;
+
+    /// Get a reference to the TCC's register block
+    fn tcc(&self) -> &RegBlock;
+
+    /// Frequency of the clock feeding the TCC, before the prescaler
+    fn clock_freq(&self) -> Hertz;
+}
+
+/// Interrupt handler for async TCC operations
+pub struct InterruptHandler<T: AsyncTcc> {
+    _private: (),
+    _tcc: PhantomData<T>,
+}
+
+impl<T: AsyncTcc> Sealed for InterruptHandler<T> {}
+
+impl<T: AsyncTcc> Handler<T::Interrupt> for InterruptHandler<T> {
+    /// Callback function when any of the corresponding TCC interrupts is fired
+    ///
+    /// # Safety
+    ///
+    /// This method may [`steal`](crate::pac::Peripherals::steal) the `TCC`
+    /// peripheral instance to check the interrupt flags. The only
+    /// modifications it is allowed to apply to the peripheral is to clear
+    /// the interrupt flags and disable the interrupts that fired. This method
+    /// should ONLY be able to be called while a [`TccFuture`] holds an unique
+    /// reference to the underlying `TCC` peripheral.
+    unsafe fn on_interrupt() {
+        let periph = unsafe { crate::pac::Peripherals::steal() };
+        let tcc = T::reg_block(&periph);
+        let state = &STATE[T::STATE_ID];
+
+        let fired = tcc.intflag().read().bits() & tcc.intenset().read().bits();
+        if fired == 0 {
+            return;
+        }
+
+        // Disable the interrupts that fired, they are re-enabled by the next
+        // future that waits on them. This keeps the PWM from interrupting the
+        // CPU on every period while nothing is awaited.
+        tcc.intenclr().write(|w| unsafe { w.bits(fired) });
+        tcc.intflag().write(|w| unsafe { w.bits(fired) });
+
+        if fired & OVF != 0 {
+            state.period_ready.store(true, Ordering::SeqCst);
+            state.period.wake();
+        }
+
+        let channels = (0..T::NUM_CHANNELS)
+            .filter(|&n| fired & mc(n) != 0)
+            .fold(0, |acc, n| acc | 1 << n);
+
+        if channels != 0 {
+            state.compare_ready.fetch_or(channels, Ordering::SeqCst);
+            state.compare.wake();
+        }
+    }
+}
+
+macro_rules! impl_async_tcc {
+    ($TCC: ident, $id: expr, $channels: expr) => {
+        paste::paste! {
+            impl AsyncTcc for pac::$TCC {
+                const STATE_ID: usize = $id;
+
+                const NUM_CHANNELS: u8 = $channels;
+
+                type Interrupt = crate::async_hal::interrupts::[< $TCC:upper >];
+
+                fn reg_block(peripherals: &pac::Peripherals) -> &RegBlock {
+                    &*peripherals.[< $TCC:lower >]
+                }
+            }
+
+            impl Sealed for pac::$TCC {}
+        }
+    };
+}
+
+#[hal_cfg(any("tcc0-d11", "tcc0-d21"))]
+impl_async_tcc!(Tcc0, 0, 4);
+
+#[hal_cfg("tcc1-d21")]
+impl_async_tcc!(Tcc1, 1, 2);
+
+#[hal_cfg("tcc2-d21")]
+impl_async_tcc!(Tcc2, 2, 2);
+
+#[hal_cfg("tcc0-d5x")]
+impl_async_tcc!(Tcc0, 0, 6);
+
+#[hal_cfg("tcc1-d5x")]
+impl_async_tcc!(Tcc1, 1, 4);
+
+#[hal_cfg("tcc2-d5x")]
+impl_async_tcc!(Tcc2, 2, 3);
+
+#[hal_cfg("tcc3-d5x")]
+impl_async_tcc!(Tcc3, 3, 2);
+
+#[hal_cfg("tcc4-d5x")]
+impl_async_tcc!(Tcc4, 4, 2);
+
+// Reserve space for the max number of TCC peripherals based on chip type,
+// even though some wakers may not be used on some chips if they actually don't
+// exist on variant's hardware
+#[hal_cfg("tcc0-d11")]
+const NUM_TCC: usize = 1;
+
+#[hal_cfg("tcc0-d21")]
+const NUM_TCC: usize = 3;
+
+#[hal_cfg("tcc0-d5x")]
+const NUM_TCC: usize = 5;
+
+/// Wrapper around a TCC PWM driver with an `async` interface
+///
+/// The PWM driver is still accessible through [`AsRef`]/[`AsMut`], for
+/// example to change the duty cycle while waiting on the timer.
+pub struct TccFuture<P: TccPwm> {
+    pwm: P,
+}
+
+impl<P: TccPwm> TccFuture<P> {
+    #[inline]
+    pub(super) fn new<I>(pwm: P, _irq: I) -> Self
+    where
+        I: Binding<<P::Tcc as AsyncTcc>::Interrupt, InterruptHandler<P::Tcc>>,
+    {
+        // Start with all interrupts disabled; the futures enable the ones they
+        // wait on.
+        pwm.tcc().intenclr().write(|w| unsafe { w.bits(u32::MAX) });
+        <P::Tcc as AsyncTcc>::Interrupt::unpend();
+        unsafe { <P::Tcc as AsyncTcc>::Interrupt::enable() };
+
+        Self { pwm }
+    }
+
+    /// Wait until the end of the current PWM period
+    #[inline]
+    pub async fn wait_period(&mut self) {
+        let state = &STATE[<P::Tcc as AsyncTcc>::STATE_ID];
+        let tcc = self.pwm.tcc();
+
+        state.period_ready.store(false, Ordering::SeqCst);
+        tcc.intflag().write(|w| unsafe { w.bits(OVF) });
+        tcc.intenset().write(|w| unsafe { w.bits(OVF) });
+
+        poll_fn(|cx| {
+            state.period.register(cx.waker());
+            if state.period_ready.swap(false, Ordering::SeqCst) {
+                return Poll::Ready(());
+            }
+
+            Poll::Pending
+        })
+        .await;
+    }
+
+    /// Wait until the counter matches the compare value of `channel`
+    ///
+    /// For a channel that drives a PWM output, this resolves on the output's
+    /// falling edge. Channels without an output can be used as a timeout
+    /// within the period, see [`set_compare`](Self::set_compare).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` does not exist on this TCC.
+    #[inline]
+    pub async fn wait_compare(&mut self, channel: Channel) {
+        let n = channel as u8;
+        assert!(n < <P::Tcc as AsyncTcc>::NUM_CHANNELS);
+
+        let state = &STATE[<P::Tcc as AsyncTcc>::STATE_ID];
+        let tcc = self.pwm.tcc();
+
+        state.compare_ready.fetch_and(!(1 << n), Ordering::SeqCst);
+        tcc.intflag().write(|w| unsafe { w.bits(mc(n)) });
+        tcc.intenset().write(|w| unsafe { w.bits(mc(n)) });
+
+        poll_fn(|cx| {
+            state.compare.register(cx.waker());
+            if state.compare_ready.fetch_and(!(1 << n), Ordering::SeqCst) & (1 << n) != 0 {
+                return Poll::Ready(());
+            }
+
+            Poll::Pending
+        })
+        .await;
+    }
+
+    /// Set the compare value of `channel`, in timer ticks from the start of
+    /// the period
+    ///
+    /// Unlike the duty cycle methods of the PWM driver, this writes the
+    /// compare register directly instead of its buffer, so the new value
+    /// applies to the current period. It is provided here to arm compare
+    /// channels that have no output attached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` does not exist on this TCC.
+    #[inline]
+    pub fn set_compare(&mut self, channel: Channel, ticks: u32) {
+        let n = channel as u8;
+        assert!(n < <P::Tcc as AsyncTcc>::NUM_CHANNELS);
+        self.pwm
+            .tcc()
+            .cc(n as usize)
+            .write(|w| unsafe { w.cc().bits(ticks) });
+    }
+
+    /// Get the frequency of the PWM periods, ie the rate at which
+    /// [`wait_period`](Self::wait_period) resolves
+    #[inline]
+    pub fn period_freq(&self) -> Hertz {
+        let tcc = self.pwm.tcc();
+        let divider = 1
+            << match tcc.ctrla().read().prescaler().bits() {
+                // DIV1, DIV2, DIV4, DIV8, DIV16
+                p @ 0..=4 => p as u32,
+                // DIV64
+                5 => 6,
+                // DIV256
+                6 => 8,
+                // DIV1024
+                _ => 10,
+            };
+        let top = tcc.per().read().bits();
+        self.pwm.clock_freq() / divider / (top + 1)
+    }
+
+    /// Disable the TCC interrupts, and return the underlying PWM driver
+    #[inline]
+    pub fn free(self) -> P {
+        <P::Tcc as AsyncTcc>::Interrupt::disable();
+        self.pwm
+            .tcc()
+            .intenclr()
+            .write(|w| unsafe { w.bits(u32::MAX) });
+        self.pwm
+    }
+}
+
+impl<P: TccPwm> AsRef<P> for TccFuture<P> {
+    #[inline]
+    fn as_ref(&self) -> &P {
+        &self.pwm
+    }
+}
+
+impl<P: TccPwm> AsMut<P> for TccFuture<P> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut P {
+        &mut self.pwm
+    }
+}
+
+impl<P: TccPwm> embedded_hal_async::delay::DelayNs for TccFuture<P> {
+    /// Delay for at least `ns` nanoseconds, rounded up to a whole number of
+    /// PWM periods
+    async fn delay_ns(&mut self, ns: u32) {
+        let freq = self.period_freq().to_Hz() as u64;
+        let periods = (ns as u64 * freq).div_ceil(1_000_000_000);
+        if periods == 0 {
+            return;
+        }
+
+        // The current period is already running, so wait for one more
+        for _ in 0..=periods {
+            self.wait_period().await;
+        }
+    }
+}
+
+struct State {
+    period: AtomicWaker,
+    period_ready: AtomicBool,
+    compare: AtomicWaker,
+    compare_ready: AtomicU8,
+}
+
+impl State {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            period: AtomicWaker::new(),
+            period_ready: AtomicBool::new(false),
+            compare: AtomicWaker::new(),
+            compare_ready: AtomicU8::new(0),
+        }
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const STATE_NEW: State = State::new();
+static STATE: [State; NUM_TCC] = [STATE_NEW; NUM_TCC];
@@ -5,10 +5,23 @@ use atsamd_hal_macros::hal_cfg;
 use crate::clock;
 use crate::gpio::*;
 use crate::gpio::{AlternateE, AnyPin, Pin};
+use crate::pac::tcc0::ctrla::Prescalerselect;
 use crate::pac::Mclk;
 use crate::time::Hertz;
 use crate::timer_params::TimerParams;
 
+mod dead_time;
+pub use dead_time::{DeadTime, DeadTimeError};
+
+mod fault;
+pub use fault::{FaultInput, SafeState};
+
+#[cfg(feature = "async")]
+mod async_api;
+
+#[cfg(feature = "async")]
+pub use async_api::*;
+
 // Timer/Counter (TCx)
 
 /// This is a major syntax hack.
@@ -217,6 +230,24 @@ impl<I: PinId> $TYPE<I> {
         count.cc(0).write(|w| unsafe { w.cc().bits(params.cycles as u16) });
         while count.syncbusy().read().cc0().bit_is_set() {}
     }
+
+    /// Keep the counter running while the CPU is halted by a debugger
+    ///
+    /// By default, the counter is halted during debug, which freezes the
+    /// output at its current level while stopped at a breakpoint.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, run_during_debug: bool) {
+        self.tc
+            .count16()
+            .dbgctrl()
+            .write(|w| w.dbgrun().bit(run_during_debug));
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.tc.count16().dbgctrl().read().dbgrun().bit_is_set()
+    }
 }
 
 impl<I: PinId> $crate::ehal::pwm::ErrorType for$TYPE<I> {
@@ -551,6 +582,35 @@ impl_tcc_pinout!(TCC4Pinout: [
     (Pb31, PB31, AlternateF)
 ]);
 
+/// TCC prescaler setting for a [`TimerParams`] divider
+fn tcc_prescaler(divider: u16) -> Prescalerselect {
+    match divider {
+        1 => Prescalerselect::Div1,
+        2 => Prescalerselect::Div2,
+        4 => Prescalerselect::Div4,
+        8 => Prescalerselect::Div8,
+        16 => Prescalerselect::Div16,
+        64 => Prescalerselect::Div64,
+        256 => Prescalerselect::Div256,
+        1024 => Prescalerselect::Div1024,
+        _ => unreachable!(),
+    }
+}
+
+/// Clock divider of a TCC prescaler setting
+fn tcc_divider(prescaler: Prescalerselect) -> u32 {
+    match prescaler {
+        Prescalerselect::Div1 => 1,
+        Prescalerselect::Div2 => 2,
+        Prescalerselect::Div4 => 4,
+        Prescalerselect::Div8 => 8,
+        Prescalerselect::Div16 => 16,
+        Prescalerselect::Div64 => 64,
+        Prescalerselect::Div256 => 256,
+        Prescalerselect::Div1024 => 1024,
+    }
+}
+
 macro_rules! pwm_tcc {
     ($($TYPE:ident: ($TCC:ident, $pinout:ident, $clock:ident, $apmask:ident, $apbits:ident, $wrapper:ident)),+) => {
         $(
@@ -597,6 +657,8 @@ impl<I: PinId, M: PinMode> $TYPE<I, M> {
         while tcc.syncbusy().read().wave().bit_is_set() {}
         tcc.per().write(|w| unsafe { w.bits(params.cycles as u32) });
         while tcc.syncbusy().read().per().bit_is_set() {}
+        // Keep the buffer in sync, as it is what the duty cycle methods read
+        tcc.perbuf().write(|w| unsafe { w.bits(params.cycles as u32) });
         tcc.ctrla().modify(|_, w| w.enable().set_bit());
         while tcc.syncbusy().read().enable().bit_is_set() {}
 
@@ -608,6 +670,103 @@ impl<I: PinId, M: PinMode> $TYPE<I, M> {
     }
 }
 
+impl<I: PinId, M: PinMode> $TYPE<I, M> {
+    /// Keep the counter running while the CPU is halted by a debugger
+    ///
+    /// By default, the counter is halted during debug, which freezes all
+    /// outputs at their current level while stopped at a breakpoint.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, run_during_debug: bool) {
+        self.tcc
+            .dbgctrl()
+            .modify(|_, w| w.dbgrun().bit(run_during_debug));
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.tcc.dbgctrl().read().dbgrun().bit_is_set()
+    }
+
+    /// Apply the buffered period and duty cycles immediately
+    ///
+    /// [`set_period`](crate::ehal_02::Pwm::set_period) and
+    /// [`set_duty`](crate::ehal_02::Pwm::set_duty) write the `PERBUF` and `CCBUF`
+    /// buffer registers, which the TCC only copies to the active registers at
+    /// the end of the current period. Every period is then generated with
+    /// either the old or the new settings, never a mix of both. Forcing the
+    /// update instead applies them mid-period, which may glitch the outputs.
+    #[inline]
+    pub fn force_update(&mut self) {
+        self.tcc.ctrlbset().write(|w| w.cmd().update());
+        while self.tcc.syncbusy().read().ctrlb().bit_is_set() {}
+    }
+
+    /// Force the outputs to `safe_state` in hardware whenever an event is
+    /// received on `input`
+    ///
+    /// See [`FaultInput`] for details on how faults are detected and cleared.
+    #[inline]
+    pub fn on_fault(&mut self, input: FaultInput, safe_state: SafeState) {
+        fault::on_fault(&self.tcc, input, safe_state);
+    }
+
+    /// Stop treating events on `input` as faults
+    #[inline]
+    pub fn disable_fault(&mut self, input: FaultInput) {
+        fault::disable_fault(&self.tcc, input);
+    }
+
+    /// Check whether a fault on `input` has latched
+    #[inline]
+    pub fn is_fault(&self, input: FaultInput) -> bool {
+        fault::is_fault(&self.tcc, input)
+    }
+
+    /// Clear a latched fault on `input`, which releases the outputs from
+    /// their safe state
+    ///
+    /// This has no effect while the fault input is still active.
+    #[inline]
+    pub fn clear_fault(&mut self, input: FaultInput) {
+        fault::clear_fault(&self.tcc, input);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I: PinId, M: PinMode> $TYPE<I, M> {
+    /// Transform into a [`TccFuture`], which can additionally await the PWM
+    /// period and compare match interrupts
+    #[inline]
+    pub fn into_future<Irq>(self, irq: Irq) -> TccFuture<Self>
+    where
+        Irq: $crate::async_hal::interrupts::Binding<
+            <crate::pac::$TCC as AsyncTcc>::Interrupt,
+            InterruptHandler<crate::pac::$TCC>,
+        >,
+    {
+        TccFuture::new(self, irq)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I: PinId, M: PinMode> $crate::typelevel::Sealed for $TYPE<I, M> {}
+
+#[cfg(feature = "async")]
+impl<I: PinId, M: PinMode> TccPwm for $TYPE<I, M> {
+    type Tcc = crate::pac::$TCC;
+
+    #[inline]
+    fn tcc(&self) -> &crate::pac::tcc0::RegisterBlock {
+        &self.tcc
+    }
+
+    #[inline]
+    fn clock_freq(&self) -> Hertz {
+        self.clock_freq
+    }
+}
+
 impl<I: PinId, M: PinMode> $crate::ehal_02::Pwm for $TYPE<I, M> {
     type Channel = Channel;
     type Time = Hertz;
@@ -624,25 +783,25 @@ impl<I: PinId, M: PinMode> $crate::ehal_02::Pwm for $TYPE<I, M> {
     }
 
     fn get_period(&self) -> Self::Time {
-        let divisor = self.tcc.ctrla().read().prescaler().bits();
-        let top = self.tcc.per().read().bits();
-        self.clock_freq / divisor as u32 / (top + 1) as u32
+        let divisor = tcc_divider(self.tcc.ctrla().read().prescaler().variant());
+        let top = self.tcc.perbuf().read().bits();
+        self.clock_freq / divisor / (top + 1)
     }
 
     fn get_duty(&self, channel: Self::Channel) -> Self::Duty {
-        let cc = self.tcc.cc(channel as usize);
-        let duty = cc.read().cc().bits();
+        let cc = self.tcc.ccbuf(channel as usize);
+        let duty = cc.read().ccbuf().bits();
         duty
     }
 
     fn get_max_duty(&self) -> Self::Duty {
-        let top = self.tcc.per().read().bits();
+        let top = self.tcc.perbuf().read().bits();
         top
     }
 
     fn set_duty(&mut self, channel: Self::Channel, duty: Self::Duty) {
-        let cc = self.tcc.cc(channel as usize);
-        cc.write(|w| unsafe { w.cc().bits(duty) });
+        let cc = self.tcc.ccbuf(channel as usize);
+        cc.write(|w| unsafe { w.ccbuf().bits(duty) });
     }
 
     fn set_period<P>(&mut self, period: P)
@@ -650,25 +809,19 @@ impl<I: PinId, M: PinMode> $crate::ehal_02::Pwm for $TYPE<I, M> {
         P: Into<Self::Time>,
     {
         let params = TimerParams::new(period.into().convert(), self.clock_freq);
-        self.tcc.ctrla().modify(|_, w| w.enable().clear_bit());
-        while self.tcc.syncbusy().read().enable().bit_is_set() {}
-        self.tcc.ctrla().modify(|_, w| {
-            match params.divider {
-                1 => w.prescaler().div1(),
-                2 => w.prescaler().div2(),
-                4 => w.prescaler().div4(),
-                8 => w.prescaler().div8(),
-                16 => w.prescaler().div16(),
-                64 => w.prescaler().div64(),
-                256 => w.prescaler().div256(),
-                1024 => w.prescaler().div1024(),
-                _ => unreachable!(),
-            }
-        });
-        self.tcc.ctrla().modify(|_, w| w.enable().set_bit());
-        while self.tcc.syncbusy().read().enable().bit_is_set() {}
-        self.tcc.per().write(|w| unsafe { w.bits(params.cycles as u32) });
-        while self.tcc.syncbusy().read().per().bit() {}
+        let prescaler = tcc_prescaler(params.divider);
+        self.tcc.perbuf().write(|w| unsafe { w.bits(params.cycles as u32) });
+        if self.tcc.ctrla().read().prescaler().variant() != prescaler {
+            // The prescaler can only be changed while the TCC is disabled,
+            // which already disturbs the current period. Apply the new period
+            // right away.
+            self.tcc.ctrla().modify(|_, w| w.enable().clear_bit());
+            while self.tcc.syncbusy().read().enable().bit_is_set() {}
+            self.tcc.ctrla().modify(|_, w| w.prescaler().variant(prescaler));
+            self.tcc.ctrla().modify(|_, w| w.enable().set_bit());
+            while self.tcc.syncbusy().read().enable().bit_is_set() {}
+            self.force_update();
+        }
     }
 }
 
@@ -686,3 +839,58 @@ pwm_tcc! { Tcc2Pwm: (Tcc2, TCC2Pinout, Tcc2Tcc3Clock, apbcmask, tcc2_, TccPwm2Wr
 pwm_tcc! { Tcc3Pwm: (Tcc3, TCC3Pinout, Tcc2Tcc3Clock, apbcmask, tcc3_, TccPwm3Wrapper) }
 #[hal_cfg("tcc4")]
 pwm_tcc! { Tcc4Pwm: (Tcc4, TCC4Pinout, Tcc4Clock,     apbdmask, tcc4_, TccPwm4Wrapper) }
+
+macro_rules! pwm_tcc_dead_time {
+    ($($TYPE:ident),+) => {
+        $(
+
+impl<I: PinId, M: PinMode> $TYPE<I, M> {
+    /// Set the dead-time lengths of all complementary pairs of this TCC
+    ///
+    /// See [`DeadTime`] for details on how complementary pairs are generated.
+    #[inline]
+    pub fn set_dead_time(&mut self, dead_time: DeadTime) {
+        dead_time::set_dead_time(&self.tcc, dead_time);
+    }
+
+    /// Get the dead-time lengths of the complementary pairs of this TCC
+    #[inline]
+    pub fn get_dead_time(&self) -> DeadTime {
+        dead_time::get_dead_time(&self.tcc)
+    }
+
+    /// Turn `channel` into a complementary pair, driving `WO[channel]` and
+    /// `WO[channel + 4]` with dead-time inserted between their edges
+    ///
+    /// Returns [`DeadTimeError::InvalidChannel`] if the channel has no
+    /// dead-time insertion generator.
+    #[inline]
+    pub fn enable_dead_time(&mut self, channel: Channel) -> Result<(), DeadTimeError> {
+        dead_time::set_dead_time_enabled(&self.tcc, channel, true)
+    }
+
+    /// Stop inserting dead-time on `channel`, which returns `WO[channel + 4]`
+    /// to its regular waveform output
+    ///
+    /// Returns [`DeadTimeError::InvalidChannel`] if the channel has no
+    /// dead-time insertion generator.
+    #[inline]
+    pub fn disable_dead_time(&mut self, channel: Channel) -> Result<(), DeadTimeError> {
+        dead_time::set_dead_time_enabled(&self.tcc, channel, false)
+    }
+
+    /// Check whether dead-time insertion is enabled on `channel`
+    #[inline]
+    pub fn is_dead_time_enabled(&self, channel: Channel) -> bool {
+        dead_time::is_dead_time_enabled(&self.tcc, channel)
+    }
+}
+
+        )+
+    };
+}
+
+#[hal_cfg("tcc0")]
+pwm_tcc_dead_time! { Tcc0Pwm }
+#[hal_cfg("tcc1")]
+pwm_tcc_dead_time! { Tcc1Pwm }
@@ -0,0 +1,125 @@
+//! Dead-time insertion for complementary PWM outputs.
+
+use crate::pac::tcc0::RegisterBlock;
+
+use super::Channel;
+
+/// Number of dead-time insertion generators in a TCC
+const NUM_DTI: u8 = 4;
+
+/// Dead-time lengths applied to complementary outputs
+///
+/// TCCs with a dead-time insertion (DTI) unit can turn each of their first
+/// four compare channels into a complementary pair of outputs, as needed to
+/// drive a half-bridge. When DTI is enabled for channel `x`:
+///
+/// * `WO[x]` carries the low side output, which follows the channel's PWM
+///   waveform;
+/// * `WO[x + 4]` carries the high side output, which is its complement.
+///
+/// Every edge of either output is delayed by a programmable number of cycles,
+/// so that both sides of the bridge are never on at the same time. Both pins
+/// must be muxed to the TCC for the pair to reach the outside world: the
+/// pinout passed to the PWM constructor only takes care of one of them, the
+/// other one has to be put in the same alternate mode manually.
+///
+/// The low side and high side dead-time lengths are shared between all
+/// channels of a TCC. They are counted in cycles of the TCC's generic clock,
+/// independently of the counter prescaler.
+///
+/// Only TCCs which include a DTI unit expose the dead-time methods: `TCC0` on
+/// SAMD11/SAMD21 chips, and `TCC0` and `TCC1` on SAMx5x chips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeadTime {
+    /// Delay inserted before the low side output goes high
+    pub low_side: u8,
+    /// Delay inserted before the high side output goes high
+    pub high_side: u8,
+}
+
+impl DeadTime {
+    /// Create a [`DeadTime`] with the same length on both sides
+    #[inline]
+    pub const fn symmetric(cycles: u8) -> Self {
+        Self {
+            low_side: cycles,
+            high_side: cycles,
+        }
+    }
+}
+
+/// Errors related to dead-time insertion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DeadTimeError {
+    /// The channel has no dead-time insertion generator. Only channels 0 to 3
+    /// can drive a complementary pair.
+    InvalidChannel,
+}
+
+/// Run `f` with the TCC disabled, since `WEXCTRL` is enable-protected. The
+/// TCC is re-enabled afterwards if it was running.
+pub(super) fn with_tcc_disabled(tcc: &RegisterBlock, f: impl FnOnce(&RegisterBlock)) {
+    let enabled = tcc.ctrla().read().enable().bit_is_set();
+    if enabled {
+        tcc.ctrla().modify(|_, w| w.enable().clear_bit());
+        while tcc.syncbusy().read().enable().bit_is_set() {}
+    }
+
+    f(tcc);
+
+    if enabled {
+        tcc.ctrla().modify(|_, w| w.enable().set_bit());
+        while tcc.syncbusy().read().enable().bit_is_set() {}
+    }
+}
+
+pub(super) fn set_dead_time(tcc: &RegisterBlock, dead_time: DeadTime) {
+    with_tcc_disabled(tcc, |tcc| {
+        tcc.wexctrl().modify(|_, w| unsafe {
+            w.dtls().bits(dead_time.low_side);
+            w.dths().bits(dead_time.high_side)
+        });
+    });
+}
+
+pub(super) fn get_dead_time(tcc: &RegisterBlock) -> DeadTime {
+    let wexctrl = tcc.wexctrl().read();
+    DeadTime {
+        low_side: wexctrl.dtls().bits(),
+        high_side: wexctrl.dths().bits(),
+    }
+}
+
+pub(super) fn set_dead_time_enabled(
+    tcc: &RegisterBlock,
+    channel: Channel,
+    enabled: bool,
+) -> Result<(), DeadTimeError> {
+    let channel = channel as u8;
+    if channel >= NUM_DTI {
+        return Err(DeadTimeError::InvalidChannel);
+    }
+
+    with_tcc_disabled(tcc, |tcc| {
+        tcc.wexctrl().modify(|_, w| match channel {
+            0 => w.dtien0().bit(enabled),
+            1 => w.dtien1().bit(enabled),
+            2 => w.dtien2().bit(enabled),
+            _ => w.dtien3().bit(enabled),
+        });
+    });
+    Ok(())
+}
+
+pub(super) fn is_dead_time_enabled(tcc: &RegisterBlock, channel: Channel) -> bool {
+    let wexctrl = tcc.wexctrl().read();
+    match channel as u8 {
+        0 => wexctrl.dtien0().bit_is_set(),
+        1 => wexctrl.dtien1().bit_is_set(),
+        2 => wexctrl.dtien2().bit_is_set(),
+        3 => wexctrl.dtien3().bit_is_set(),
+        _ => false,
+    }
+}
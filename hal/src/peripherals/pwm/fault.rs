@@ -0,0 +1,159 @@
+//! Non-recoverable fault inputs for TCC PWM outputs.
+
+use crate::pac::tcc0::RegisterBlock;
+
+use super::dead_time::with_tcc_disabled;
+
+/// TCC event input used as a non-recoverable fault source
+///
+/// Each TCC has two event inputs, `EV0` and `EV1`. When one of them is
+/// configured as a fault input with `on_fault`, an event on it forces the PWM
+/// outputs to the [`SafeState`] entirely in hardware, without any CPU
+/// involvement. The fault is latched: the outputs stay in their safe state
+/// until the fault input is inactive again and the fault has been cleared
+/// with `clear_fault`.
+///
+/// The event system must be configured separately to route a generator (for
+/// example an EIC channel wired to an overcurrent comparator) to the TCC's
+/// `EVx` event user. Using the asynchronous path keeps the cutoff independent
+/// of any clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FaultInput {
+    /// Event input 0
+    Ev0,
+    /// Event input 1
+    Ev1,
+}
+
+/// Levels forced on the TCC's waveform outputs while a fault is active
+///
+/// Outputs are identified by their `WO[x]` number. Outputs which are neither
+/// set [`low`](Self::low) nor [`high`](Self::high) keep following their PWM
+/// waveform during a fault. The safe state is shared between both fault
+/// inputs of a TCC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SafeState {
+    /// `DRVCTRL.NREx` bits
+    driven: u8,
+    /// `DRVCTRL.NRVx` bits
+    levels: u8,
+}
+
+impl SafeState {
+    /// Create a [`SafeState`] which leaves all outputs untouched
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            driven: 0,
+            levels: 0,
+        }
+    }
+
+    /// Create a [`SafeState`] which drives all outputs low
+    #[inline]
+    pub const fn all_low() -> Self {
+        Self {
+            driven: 0xff,
+            levels: 0,
+        }
+    }
+
+    /// Drive `WO[output]` low during a fault
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is not in the range `0..8`.
+    #[inline]
+    pub const fn low(mut self, output: u8) -> Self {
+        assert!(output < 8, "TCCs have at most 8 waveform outputs");
+        self.driven |= 1 << output;
+        self.levels &= !(1 << output);
+        self
+    }
+
+    /// Drive `WO[output]` high during a fault
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is not in the range `0..8`.
+    #[inline]
+    pub const fn high(mut self, output: u8) -> Self {
+        assert!(output < 8, "TCCs have at most 8 waveform outputs");
+        self.driven |= 1 << output;
+        self.levels |= 1 << output;
+        self
+    }
+}
+
+pub(super) fn on_fault(tcc: &RegisterBlock, input: FaultInput, safe_state: SafeState) {
+    with_tcc_disabled(tcc, |tcc| {
+        tcc.drvctrl().modify(|r, w| {
+            // NREx occupy bits 0..8, NRVx bits 8..16
+            let bits = r.bits() & !0xffff;
+            unsafe { w.bits(bits | safe_state.driven as u32 | (safe_state.levels as u32) << 8) }
+        });
+        tcc.evctrl().modify(|_, w| match input {
+            FaultInput::Ev0 => {
+                w.evact0().fault();
+                w.tcei0().set_bit()
+            }
+            FaultInput::Ev1 => {
+                w.evact1().fault();
+                w.tcei1().set_bit()
+            }
+        });
+    });
+}
+
+pub(super) fn disable_fault(tcc: &RegisterBlock, input: FaultInput) {
+    with_tcc_disabled(tcc, |tcc| {
+        tcc.evctrl().modify(|_, w| match input {
+            FaultInput::Ev0 => {
+                w.evact0().off();
+                w.tcei0().clear_bit()
+            }
+            FaultInput::Ev1 => {
+                w.evact1().off();
+                w.tcei1().clear_bit()
+            }
+        });
+    });
+}
+
+pub(super) fn is_fault(tcc: &RegisterBlock, input: FaultInput) -> bool {
+    let status = tcc.status().read();
+    match input {
+        FaultInput::Ev0 => status.fault0().bit_is_set(),
+        FaultInput::Ev1 => status.fault1().bit_is_set(),
+    }
+}
+
+pub(super) fn clear_fault(tcc: &RegisterBlock, input: FaultInput) {
+    tcc.status().write(|w| match input {
+        FaultInput::Ev0 => w.fault0().set_bit(),
+        FaultInput::Ev1 => w.fault1().set_bit(),
+    });
+    tcc.intflag().write(|w| match input {
+        FaultInput::Ev0 => w.fault0().set_bit(),
+        FaultInput::Ev1 => w.fault1().set_bit(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_state_sets_enable_and_level_bits() {
+        let state = SafeState::new().low(0).high(4);
+        assert_eq!(state.driven, 0b0001_0001);
+        assert_eq!(state.levels, 0b0001_0000);
+
+        // The last level set for an output wins
+        let state = SafeState::all_low().high(7).low(7);
+        assert_eq!(state.driven, 0xff);
+        assert_eq!(state.levels, 0);
+    }
+}
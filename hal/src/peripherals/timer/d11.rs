@@ -10,7 +10,7 @@ use crate::pac::Pm;
 use crate::pac::{tc1::Count16 as Count16Reg, Tc1};
 #[hal_cfg("tc3-d21")]
 use crate::pac::{tc3::Count16 as Count16Reg, Tc3, Tc4, Tc5};
-use crate::timer_params::TimerParams;
+use crate::timer_params::{self, Prescaler, TimerParams};
 
 use crate::clock;
 use crate::time::{Hertz, Nanoseconds};
@@ -40,9 +40,259 @@ pub use async_api::*;
 pub struct TimerCounter<TC> {
     freq: Hertz,
     tc: TC,
+    prescaler: Option<Prescaler>,
+    run_during_debug: bool,
 }
 
-impl<TC: Count16> TimerCounter<TC> {}
+impl<TC: Count16> TimerCounter<TC> {
+    /// Use a fixed prescaler for the timer's source clock.
+    ///
+    /// By default (or after passing `None`), the smallest prescaler that can
+    /// reach the requested timeout is selected automatically each time the
+    /// timer is started. A fixed prescaler gives a predictable resolution, at
+    /// the cost of a shorter maximum period. The new prescaler takes effect the
+    /// next time the timer is started.
+    #[inline]
+    pub fn set_prescaler(&mut self, prescaler: Option<Prescaler>) {
+        self.prescaler = prescaler;
+    }
+
+    /// Get the fixed prescaler, or `None` if it is selected automatically
+    #[inline]
+    pub fn get_prescaler(&self) -> Option<Prescaler> {
+        self.prescaler
+    }
+
+    /// Keep the timer running while the CPU is halted by a debugger
+    ///
+    /// By default, the timer is halted during debug, so that no timeout
+    /// elapses while stopped at a breakpoint. The setting is kept across
+    /// restarts of the timer.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, run_during_debug: bool) {
+        self.run_during_debug = run_during_debug;
+        self.tc
+            .count_16()
+            .dbgctrl()
+            .write(|w| w.dbgrun().bit(run_during_debug));
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.run_during_debug
+    }
+
+    /// Duration of a single timer tick
+    ///
+    /// If the prescaler is selected automatically, this is the finest
+    /// resolution available, which is only reached by timeouts shorter than
+    /// [`Prescaler::Div1`]'s maximum period.
+    #[inline]
+    pub fn resolution(&self) -> Nanoseconds {
+        self.prescaler
+            .unwrap_or(Prescaler::Div1)
+            .resolution(self.freq)
+    }
+
+    /// Longest timeout that can be passed to [`try_start`](Self::try_start)
+    #[inline]
+    pub fn max_period(&self) -> Nanoseconds {
+        self.prescaler
+            .unwrap_or(Prescaler::Div1024)
+            .max_period(self.freq)
+    }
+
+    /// Number of timer ticks elapsing during `duration`, rounded down
+    ///
+    /// The computation uses the source clock frequency and the fixed
+    /// prescaler, without any intermediate overflow. If the prescaler is
+    /// selected automatically, [`Prescaler::Div1`] is assumed, so set a fixed
+    /// prescaler with [`set_prescaler`](Self::set_prescaler) before deriving
+    /// compare values from durations. Millisecond or microsecond durations
+    /// can be passed after a [`convert`](fugit::Duration::convert).
+    #[inline]
+    pub fn ticks_for<T>(&self, duration: T) -> Result<u16, timer_params::Error>
+    where
+        T: Into<NanosDurationU32>,
+    {
+        self.prescaler
+            .unwrap_or(Prescaler::Div1)
+            .ticks_for(duration.into(), self.freq)
+    }
+
+    /// Duration of `ticks` timer ticks, rounded down to the nearest
+    /// nanosecond
+    ///
+    /// Like [`ticks_for`](Self::ticks_for), this uses the fixed prescaler, or
+    /// [`Prescaler::Div1`] if it is selected automatically.
+    #[inline]
+    pub fn duration_for(&self, ticks: u16) -> Nanoseconds {
+        self.prescaler
+            .unwrap_or(Prescaler::Div1)
+            .duration_for(ticks, self.freq)
+    }
+
+    /// Start the timer, or return an error if the timeout can't be reached
+    /// with the current prescaler setting
+    ///
+    /// Unlike [`CountDown::start`], which panics if the timeout is too long
+    /// and fires as fast as possible if it is too short, this method leaves
+    /// the timer untouched when the timeout is out of range.
+    #[inline]
+    pub fn try_start<T>(&mut self, timeout: T) -> Result<(), timer_params::Error>
+    where
+        T: Into<NanosDurationU32>,
+    {
+        let timeout = timeout.into();
+        self.params(timeout)?;
+        <Self as InterruptDrivenTimer>::start(self, timeout);
+        Ok(())
+    }
+
+    #[inline]
+    fn params(&self, timeout: NanosDurationU32) -> Result<TimerParams, timer_params::Error> {
+        match self.prescaler {
+            Some(prescaler) => TimerParams::try_with_prescaler_ns(timeout, self.freq, prescaler),
+            None => TimerParams::try_new_ns(timeout, self.freq),
+        }
+    }
+
+    /// Count incoming events instead of clock ticks
+    ///
+    /// The timer is reset, and then increments its counter once per event
+    /// received on its event input, regardless of the prescaler setting. The
+    /// counter wraps around after `0xFFFF`, so differences between two
+    /// [`count`](Self::count) readings should be computed with
+    /// [`u16::wrapping_sub`]. Calling [`try_start`](Self::try_start) or
+    /// [`CountDown::start`] turns the timer back into a regular timer.
+    ///
+    /// The events must be routed to the `TCn` event user through the `EVSYS`
+    /// peripheral, for example from an [`ExtInt`](crate::eic::ExtInt) with
+    /// its event output enabled. This HAL does not abstract `EVSYS` yet, so
+    /// the routing has to be done through the PAC.
+    ///
+    /// # Maximum rate
+    ///
+    /// Events are synchronized to the clock of the timer, and at most one
+    /// event is counted per clock cycle. For every pulse to be counted, the
+    /// event rate must stay below half the frequency of the clock passed to
+    /// the constructor, i.e. 24 MHz for a 48 MHz clock. Any EIC filtering or
+    /// synchronization on the way lowers that limit further. Above it, events
+    /// are silently missed.
+    pub fn start_event_counter(&mut self) {
+        self.reset();
+        let count = self.tc.count_16();
+
+        count.evctrl().write(|w| {
+            w.evact().count();
+            w.tcei().set_bit()
+        });
+
+        count.ctrla().modify(|_, w| {
+            w.enable().set_bit();
+            w.runstdby().set_bit()
+        });
+        while count.status().read().syncbusy().bit_is_set() {}
+    }
+
+    /// Read the current value of the counter
+    ///
+    /// In event counter mode, this is the number of events received since
+    /// [`start_event_counter`](Self::start_event_counter) was called, modulo
+    /// `0x10000`.
+    pub fn count(&self) -> u16 {
+        let count = self.tc.count_16();
+        // COUNT must be synchronized before it can be read. Its offset in the
+        // register block is 0x10.
+        count.readreq().write(|w| unsafe {
+            w.addr().bits(0x10);
+            w.rreq().set_bit()
+        });
+        while count.status().read().syncbusy().bit_is_set() {}
+        count.count().read().count().bits()
+    }
+
+    /// Measure the width of pulses on the event input
+    ///
+    /// The timer is reset, and then counts clock ticks of the clock passed to
+    /// the constructor, without prescaler. The counter restarts on every
+    /// rising edge of the event input, and its value is captured on every
+    /// falling edge, so that [`pulse_width`](Self::pulse_width) returns the
+    /// width of the last high pulse, in clock ticks. Pulses longer than
+    /// `0xFFFF` ticks wrap around. Calling [`try_start`](Self::try_start) or
+    /// [`CountDown::start`] turns the timer back into a regular timer.
+    ///
+    /// The event input must follow the level of the measured signal, rather
+    /// than fire on its edges. Route an [`ExtInt`](crate::eic::ExtInt) sensing
+    /// [`Sense::High`](crate::eic::Sense::High), with its event output
+    /// enabled, to the `TCn` event user through an asynchronous `EVSYS`
+    /// channel. As for [`start_event_counter`](Self::start_event_counter), the
+    /// routing has to be done through the PAC. The latency of the event path
+    /// is the same for both edges, so it cancels out of the measurement.
+    pub fn start_pulse_width_capture(&mut self) {
+        self.reset();
+        let count = self.tc.count_16();
+
+        count.evctrl().write(|w| {
+            w.evact().pwp();
+            w.tcei().set_bit()
+        });
+        // Pulse-width capture needs both capture channels: CC0 holds the pulse
+        // width, CC1 the period
+        count.ctrlc().write(|w| {
+            w.cpten0().set_bit();
+            w.cpten1().set_bit()
+        });
+        while count.status().read().syncbusy().bit_is_set() {}
+
+        count.ctrla().modify(|_, w| w.enable().set_bit());
+        while count.status().read().syncbusy().bit_is_set() {}
+    }
+
+    /// Width of the last pulse captured since the previous call, in clock
+    /// ticks
+    ///
+    /// Returns `None` if no pulse ended since the previous call. See
+    /// [`start_pulse_width_capture`](Self::start_pulse_width_capture).
+    pub fn pulse_width(&mut self) -> Option<u16> {
+        let count = self.tc.count_16();
+        if count.intflag().read().mc0().bit_is_clear() {
+            return None;
+        }
+        // CC0 must be synchronized before it can be read. Its offset in the
+        // register block is 0x18.
+        count.readreq().write(|w| unsafe {
+            w.addr().bits(0x18);
+            w.rreq().set_bit()
+        });
+        while count.status().read().syncbusy().bit_is_set() {}
+        count.intflag().write(|w| w.mc0().set_bit());
+        Some(count.cc(0).read().cc().bits())
+    }
+
+    /// Disable the timer and reset all of its registers
+    fn reset(&mut self) {
+        let count = self.tc.count_16();
+
+        // Disable the timer while we reconfigure it
+        count.ctrla().modify(|_, w| w.enable().clear_bit());
+        while count.status().read().syncbusy().bit_is_set() {}
+
+        // Now that we have a clock routed to the peripheral, we
+        // can ask it to perform a reset.
+        count.ctrla().write(|w| w.swrst().set_bit());
+        while count.status().read().syncbusy().bit_is_set() {}
+        // the SVD erroneously marks swrst as write-only, so we
+        // need to manually read the bit here
+        while count.ctrla().read().bits() & 1 != 0 {}
+
+        // The reset also cleared the debug control setting
+        count
+            .dbgctrl()
+            .write(|w| w.dbgrun().bit(self.run_during_debug));
+    }
+}
 
 /// This is a helper trait to make it easier to make most of the
 /// TimerCounter impl generic.  It doesn't make too much sense to
@@ -87,24 +337,20 @@ where
     }
 
     fn start<T: Into<NanosDurationU32>>(&mut self, timeout: T) {
-        let params = TimerParams::new_ns(timeout.into(), self.freq);
+        let params = match self.params(timeout.into()) {
+            Ok(params) => params,
+            // Timeouts shorter than a tick make the timer fire as fast as it can
+            Err(timer_params::Error::PeriodTooShort) => TimerParams {
+                divider: self.prescaler.map_or(1, Prescaler::divider),
+                cycles: 0,
+            },
+            Err(e) => panic!("timeout is out of range for a 16 bit counter: {:?}", e),
+        };
         let divider = params.divider;
         let cycles = params.cycles;
-
+        self.reset();
         let count = self.tc.count_16();
 
-        // Disable the timer while we reconfigure it
-        count.ctrla().modify(|_, w| w.enable().clear_bit());
-        while count.status().read().syncbusy().bit_is_set() {}
-
-        // Now that we have a clock routed to the peripheral, we
-        // can ask it to perform a reset.
-        count.ctrla().write(|w| w.swrst().set_bit());
-        while count.status().read().syncbusy().bit_is_set() {}
-        // the SVD erroneously marks swrst as write-only, so we
-        // need to manually read the bit here
-        while count.ctrla().read().bits() & 1 != 0 {}
-
         count.ctrlbset().write(|w| {
             // Count up when the direction bit is zero
             w.dir().clear_bit();
@@ -186,6 +432,8 @@ impl TimerCounter<$TC>
         Self {
             freq: clock.freq(),
             tc,
+            prescaler: None,
+            run_during_debug: false,
         }
     }
 }
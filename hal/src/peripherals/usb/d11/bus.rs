@@ -731,6 +731,10 @@ impl Inner {
         false
     }
 
+    fn frame_number(&self) -> u16 {
+        self.usb().fnum().read().fnum().bits()
+    }
+
     fn poll(&self) -> PollResult {
         let intflags = self.usb().intflag().read();
         if intflags.eorst().bit() {
@@ -872,6 +876,16 @@ impl UsbBus {
     pub fn check_sof_interrupt(&self) -> bool {
         disable_interrupts(|cs| self.inner.borrow(cs).borrow_mut().check_sof_interrupt())
     }
+
+    /// Returns the number of the last Start Of Frame (SOF) received
+    ///
+    /// The frame number is 11 bits wide, and increments every millisecond
+    /// while the host is sending frames. Reading it right after
+    /// [`check_sof_interrupt`](Self::check_sof_interrupt) returns `true` gives
+    /// the number of the frame which just started.
+    pub fn frame_number(&self) -> u16 {
+        disable_interrupts(|cs| self.inner.borrow(cs).borrow().frame_number())
+    }
 }
 
 impl usb_device::bus::UsbBus for UsbBus {
@@ -1,4 +1,15 @@
 //! USB Device support
+//!
+//! # Start Of Frame
+//!
+//! The host starts a new frame every millisecond, with a Start Of Frame (SOF)
+//! packet. Periodic work, such as feeding audio samples to a DAC, can be
+//! synchronized to this frame clock. Enable the SOF interrupt with
+//! [`UsbBus::enable_sof_interrupt`], then call
+//! [`UsbBus::check_sof_interrupt`] and [`UsbBus::frame_number`] from the USB
+//! interrupt handler. The [`UsbBus`] is available through
+//! [`UsbDevice::bus`](usb_device::device::UsbDevice::bus) once the device is
+//! built.
 
 use crate::gpio::{
     pin::{Pin, PA23, PA24, PA25},
@@ -0,0 +1,112 @@
+//! Async APIs for the USB Start Of Frame (SOF)
+
+use core::future::poll_fn;
+use core::task::Poll;
+
+use embassy_sync::waitqueue::AtomicWaker;
+use portable_atomic::{AtomicU16, AtomicU32, Ordering};
+
+use crate::async_hal::interrupts::{Binding, Handler, Interrupt, USB_SOF_HSOF};
+use crate::pac::Usb;
+use crate::typelevel::Sealed;
+
+use super::UsbBus;
+
+static SOF_WAKER: AtomicWaker = AtomicWaker::new();
+static SOF_COUNT: AtomicU32 = AtomicU32::new(0);
+static FRAME_NUMBER: AtomicU16 = AtomicU16::new(0);
+
+/// Interrupt handler for the USB Start Of Frame
+pub struct SofInterruptHandler {
+    _private: (),
+}
+
+impl Sealed for SofInterruptHandler {}
+
+impl Handler<USB_SOF_HSOF> for SofInterruptHandler {
+    /// Callback function when the `USB_SOF_HSOF` interrupt is fired
+    ///
+    /// # Safety
+    ///
+    /// This method steals the `USB` peripheral to read the frame number, and
+    /// to clear the SOF interrupt flag. It doesn't touch any other flag, so it
+    /// doesn't interfere with the [`UsbBus`] servicing the endpoints.
+    unsafe fn on_interrupt() {
+        let usb = unsafe { Usb::steal() };
+        let usb = usb.device();
+
+        if usb.intflag().read().sof().bit_is_set() {
+            usb.intflag().write(|w| w.sof().set_bit());
+            FRAME_NUMBER.store(usb.fnum().read().fnum().bits(), Ordering::Relaxed);
+            // Only this handler writes to the counter, so it doesn't need to
+            // be a read-modify-write operation
+            SOF_COUNT.store(
+                SOF_COUNT.load(Ordering::Relaxed).wrapping_add(1),
+                Ordering::Release,
+            );
+            SOF_WAKER.wake();
+        }
+    }
+}
+
+impl UsbBus {
+    /// Enable the Start Of Frame (SOF) interrupt, and return a [`SofWaiter`]
+    ///
+    /// Since the [`UsbBus`] is usually owned by a
+    /// [`UsbBusAllocator`](usb_device::bus::UsbBusAllocator), this can be
+    /// called through [`UsbDevice::bus`](usb_device::device::UsbDevice::bus).
+    ///
+    /// Once the interrupt handler is installed,
+    /// [`check_sof_interrupt`](Self::check_sof_interrupt) always returns
+    /// `false`, because the handler clears the flag.
+    pub fn sof_waiter<I>(&self, _irq: I) -> SofWaiter
+    where
+        I: Binding<USB_SOF_HSOF, SofInterruptHandler>,
+    {
+        USB_SOF_HSOF::unpend();
+        self.enable_sof_interrupt();
+        unsafe { USB_SOF_HSOF::enable() };
+        SofWaiter { _private: () }
+    }
+}
+
+/// Waits for the USB Start Of Frame (SOF)
+///
+/// Created by [`UsbBus::sof_waiter`]. Only one task should wait for the SOF
+/// at a time, since the interrupt handler only wakes the last one.
+pub struct SofWaiter {
+    _private: (),
+}
+
+impl SofWaiter {
+    /// Wait for the start of the next frame, and return its number
+    ///
+    /// The frame number is 11 bits wide, and increments every millisecond. If
+    /// the task was too slow to call this method again in time, the number of
+    /// the returned frame is more than one after the previous one.
+    pub async fn wait_sof(&mut self) -> u16 {
+        let start = SOF_COUNT.load(Ordering::Acquire);
+        poll_fn(|cx| {
+            SOF_WAKER.register(cx.waker());
+            if SOF_COUNT.load(Ordering::Acquire) != start {
+                Poll::Ready(FRAME_NUMBER.load(Ordering::Relaxed))
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Get the number of the last frame
+    #[inline]
+    pub fn frame_number(&self) -> u16 {
+        FRAME_NUMBER.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for SofWaiter {
+    fn drop(&mut self) {
+        let usb = unsafe { Usb::steal() };
+        usb.device().intenclr().write(|w| w.sof().set_bit());
+    }
+}
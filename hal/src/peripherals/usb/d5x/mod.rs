@@ -1,4 +1,21 @@
 //! USB Device support
+//!
+//! # Start Of Frame
+//!
+//! The host starts a new frame every millisecond, with a Start Of Frame (SOF)
+//! packet. Periodic work, such as feeding audio samples to a DAC, can be
+//! synchronized to this frame clock. Enable the SOF interrupt with
+//! [`UsbBus::enable_sof_interrupt`], then call
+//! [`UsbBus::check_sof_interrupt`] and [`UsbBus::frame_number`] from the USB
+//! interrupt handler. The [`UsbBus`] is available through
+//! [`UsbDevice::bus`](usb_device::device::UsbDevice::bus) once the device is
+//! built.
+//!
+//! With the `async` feature, bind the `USB_SOF_HSOF` interrupt to
+//! [`SofInterruptHandler`] and call [`UsbBus::sof_waiter`] instead. The
+//! returned [`SofWaiter`] waits for the start of the next frame while the CPU
+//! sleeps. The endpoints are still serviced from the other USB interrupts, so
+//! waiting for the SOF doesn't delay them.
 
 use crate::gpio::{
     pin::{Pin, PA23, PA24, PA25},
@@ -13,6 +30,11 @@ pub use self::bus::UsbBus;
 mod devicedesc;
 use self::devicedesc::Descriptors;
 
+#[cfg(feature = "async")]
+mod async_api;
+#[cfg(feature = "async")]
+pub use async_api::*;
+
 /// Default SOF pad
 pub type SofPad = Pin<PA23, AlternateH>;
 
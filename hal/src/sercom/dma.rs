@@ -13,7 +13,7 @@ use crate::{
         channel::{AnyChannel, Busy, Channel, InterruptFlags, Ready},
         sram::DmacDescriptor,
         transfer::BufferPair,
-        Beat, Buffer, Transfer, TriggerAction,
+        Beat, Buffer, ChId, Transfer, TriggerAction,
     },
     sercom::{
         i2c::{self, I2c},
@@ -420,6 +420,160 @@ where
     }
 }
 
+/// Ring buffer of a [`CircularRx`]
+type RingBuffer<C, const N: usize> = &'static mut [<C as uart::AnyConfig>::Word; N];
+
+/// Circular DMA transfer of a [`CircularRx`]
+type CircularTransfer<C, D, Id, const N: usize> =
+    Transfer<Channel<Id, Busy>, BufferPair<Uart<C, D>, RingBuffer<C, N>>>;
+
+/// A circular DMA reception into a ring buffer
+///
+/// Started by [`Uart::receive_circular_dma`]. The DMA channel writes each
+/// received word to the next slot of the buffer, and wraps around at its end,
+/// so the reception never stops. The [`write_index`](Self::write_index) is
+/// the slot the DMA will write next. The words between the read cursor and
+/// this index can be consumed with [`read`](Self::read).
+///
+/// # Overruns
+///
+/// The DMAC has no way to know which words were consumed, so it doesn't flag
+/// an overrun when it wraps past unread data. Instead,
+/// [`bytes_available`](Self::bytes_available) compares the
+/// [`write_index`](Self::write_index) with the one it read on its previous
+/// call, and adds the words received in between to the unread count. Once
+/// more than `N` words are unread, the oldest ones were overwritten, and it
+/// returns [`Error::Overflow`](uart::Error::Overflow).
+///
+/// Two successive index reads can't tell apart a DMA that moved by `N`
+/// words from one that didn't move, so an overrun is only detected if
+/// [`bytes_available`](Self::bytes_available) or [`read`](Self::read) is
+/// called at least once every `N` received words.
+pub struct CircularRx<C, D, Id, const N: usize>
+where
+    C: uart::ValidConfig,
+    C::Word: Beat,
+    D: uart::Receive,
+    Id: ChId,
+    Uart<C, D>: Buffer<Beat = C::Word>,
+{
+    transfer: CircularTransfer<C, D, Id, N>,
+    /// Write index read by the previous call to `bytes_available`
+    last_write_index: usize,
+    /// Number of words received but not yet consumed
+    unread: usize,
+}
+
+impl<C, D, Id, const N: usize> CircularRx<C, D, Id, N>
+where
+    C: uart::ValidConfig,
+    C::Word: Beat,
+    D: uart::Receive,
+    Id: ChId,
+    Uart<C, D>: Buffer<Beat = C::Word>,
+{
+    /// Get the index of the slot the DMA will write next
+    ///
+    /// The index is derived from the remaining beat count of the channel.
+    #[inline]
+    pub fn write_index(&mut self) -> usize {
+        (N - self.transfer.remaining()) % N
+    }
+
+    /// Get the number of words received, but not yet consumed with
+    /// [`read`](Self::read)
+    ///
+    /// Returns [`Error::Overflow`](uart::Error::Overflow) if the DMA overwrote
+    /// unread words. The unread words are then discarded, and the read cursor
+    /// moves to the [`write_index`](Self::write_index). See
+    /// [Overruns](Self#overruns) for the limits of the detection.
+    #[inline]
+    pub fn bytes_available(&mut self) -> Result<usize, uart::Error> {
+        let write_index = self.write_index();
+        self.unread += (write_index + N - self.last_write_index) % N;
+        self.last_write_index = write_index;
+        if self.unread > N {
+            self.unread = 0;
+            Err(uart::Error::Overflow)
+        } else {
+            Ok(self.unread)
+        }
+    }
+
+    /// Consume received words into `buf`, and return how many were copied
+    ///
+    /// This never blocks, and copies at most
+    /// [`bytes_available`](Self::bytes_available) words. Returns its error if
+    /// an overrun is detected.
+    #[inline]
+    pub fn read(&mut self, buf: &mut [C::Word]) -> Result<usize, uart::Error> {
+        let count = self.bytes_available()?.min(buf.len());
+        let mut read_index = (self.last_write_index + N - self.unread) % N;
+        // SAFETY: Only the slots behind the DMA cursor are read, and they are
+        // read with volatile reads, without keeping any reference to them.
+        let ring = unsafe { self.transfer.borrow_destination() }.as_ptr();
+        for word in buf[..count].iter_mut() {
+            *word = unsafe { ring.add(read_index).read_volatile() };
+            read_index = (read_index + 1) % N;
+        }
+        self.unread -= count;
+        Ok(count)
+    }
+
+    /// Stop the reception, and return the channel, the [`Uart`] and the
+    /// buffer
+    ///
+    /// The [`Uart`] can be used for blocking reads again.
+    #[inline]
+    pub fn stop(self) -> (Channel<Id, Ready>, Uart<C, D>, RingBuffer<C, N>) {
+        self.transfer.stop()
+    }
+}
+
+impl<C, D> Uart<C, D>
+where
+    Self: Buffer<Beat = C::Word>,
+    C: uart::ValidConfig,
+    C::Word: Beat,
+    D: uart::Receive,
+{
+    /// Start a circular DMA reception into a ring buffer
+    ///
+    /// Unlike [`receive_with_dma`](Self::receive_with_dma), the reception
+    /// never completes. The DMA wraps around to the start of `buf` once it is
+    /// full. See [`CircularRx`] to consume the received data.
+    #[inline]
+    #[hal_macro_helper]
+    pub fn receive_circular_dma<Ch, const N: usize>(
+        self,
+        buf: &'static mut [C::Word; N],
+        mut channel: Ch,
+    ) -> CircularRx<C, D, Ch::Id, N>
+    where
+        Ch: AnyChannel<Status = Ready>,
+    {
+        channel
+            .as_mut()
+            .enable_interrupts(InterruptFlags::new().with_tcmpl(true));
+
+        #[hal_cfg("sercom0-d5x")]
+        let trigger_action = TriggerAction::Burst;
+
+        #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+        let trigger_action = TriggerAction::Beat;
+
+        // SAFETY: This is safe because `buf` is `'static`, and the buffer
+        // length of an `Uart` is always 1. The transfer is never dropped while
+        // running, because `CircularRx` only releases it through `stop`.
+        let xfer = unsafe { dmac::Transfer::new_unchecked(channel, self, buf, true) };
+        CircularRx {
+            transfer: xfer.begin(C::Sercom::DMA_RX_TRIGGER, trigger_action),
+            last_write_index: 0,
+            unread: 0,
+        }
+    }
+}
+
 //=============================================================================
 // SPI DMA transfers
 //=============================================================================
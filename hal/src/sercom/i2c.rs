@@ -167,9 +167,65 @@
 //! let config = i2c.disable();
 //! ```
 //!
+//! # SMBus
+//!
+//! [`I2c`] also provides the SMBus [Quick Command](I2c::smbus_quick),
+//! [Write Byte](I2c::write_byte), [Write Word](I2c::write_word),
+//! [Read Byte](I2c::read_byte) and [Read Word](I2c::read_word) transactions.
+//! Enabling [`pec`](Config::pec) makes them append a Packet Error Code to
+//! every write, and verify the one received after every read:
+//!
+//! ```no_run
+//! // Assume config is a valid I2C Config struct
+//! let mut i2c = config.inactive_timeout(InactiveTimeout::Us55).pec(true).enable();
+//!
+//! // Probe for a battery gauge, then read its voltage
+//! i2c.smbus_quick(0x0B, Direction::Write)?;
+//! let millivolts = i2c.read_word(0x0B, 0x09)?;
+//! ```
+//!
+//! The PEC is a CRC-8 over all the bytes of the transaction, including the
+//! address bytes, as specified by SMBus. [`pec`] computes it for other
+//! transactions.
+//!
+//! # Multi-master buses
+//!
+//! When another master starts transmitting at the same time, the master which
+//! sends a recessive bit while the other sends a dominant one loses
+//! arbitration, and must let the other master finish its transaction. By
+//! default, the [`I2c`] suits single-master buses, and losing arbitration fails
+//! the transaction with [`Error::ArbitrationLost`].
+//!
+//! On multi-master buses, set
+//! [`arbitration_retries`](Config::arbitration_retries) instead. After losing
+//! arbitration, the [`I2c`] waits for the bus to be released, and runs the
+//! whole transaction again from its START condition. It only returns
+//! [`Error::ArbitrationLost`] once all retries are used up.
+//!
+//! ```no_run
+//! // Assume config is a valid I2C Config struct
+//! let mut i2c = config
+//!     .inactive_timeout(InactiveTimeout::Us205)
+//!     .arbitration_retries(3)
+//!     .enable();
+//! ```
+//!
+//! The bus is considered released once a STOP condition is detected. Set an
+//! [`inactive_timeout`](Config::inactive_timeout) as well, so that a master
+//! stopping in the middle of a transaction does not block the [`I2c`] forever.
+//!
+//! Retries apply to the blocking
+//! [`embedded_hal::i2c::I2c`](crate::ehal::i2c::I2c) implementation and to the
+//! SMBus transactions, but not to DMA or `async` transactions, which always
+//! return [`Error::ArbitrationLost`].
+//!
+//! The SERCOM only operates as a master, so it does not acknowledge its own
+//! address. If the master which won arbitration addresses this device, that
+//! transaction is not acknowledged, and the [`I2c`] retries once it ends.
+//!
 //! # Non-supported features
 //!
-//! * Slave mode is not supported at this time.
+//! * Slave mode is only supported through the `async` [`target`] API.
 //! * High-speed mode is not supported.
 //! * 4-wire mode is not supported.
 //! * 32-bit extension mode is not supported (SAMx5x). If you need to transfer
@@ -381,12 +437,18 @@ pub use config::*;
 
 mod impl_ehal;
 
+mod smbus;
+pub use smbus::*;
+
 #[cfg(feature = "async")]
 mod async_api;
 
 #[cfg(feature = "async")]
 pub use async_api::*;
 
+#[cfg(feature = "async")]
+pub mod target;
+
 /// Word size for an I2C message
 pub type Word = u8;
 
@@ -515,6 +577,26 @@ impl<C: AnyConfig, D> I2c<C, D> {
         self.config.as_mut().registers.cmd_stop()
     }
 
+    /// Run a blocking `transaction`, and run it again after every loss of
+    /// arbitration, up to the configured number of
+    /// [retries](Config::arbitration_retries)
+    #[inline]
+    fn retry_arbitration<T>(
+        &mut self,
+        mut transaction: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut retries = self.config.as_ref().get_arbitration_retries();
+        loop {
+            match transaction(self) {
+                Err(Error::ArbitrationLost) if retries > 0 => {
+                    retries -= 1;
+                    self.config.as_mut().registers.wait_bus_released();
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Reconfigure the I2C peripheral.
     ///
     /// Calling this method will temporarily disable the SERCOM peripheral, as
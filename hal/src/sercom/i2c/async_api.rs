@@ -11,6 +11,12 @@ use core::{marker::PhantomData, task::Poll};
 use embedded_hal_async::i2c::{ErrorType, I2c as I2cTrait, Operation};
 
 /// Interrupt handler for async I2C operarions
+///
+/// Bind it to the `SERCOMx` interrupt(s) of the SERCOM used by the
+/// [`I2cFuture`]. It wakes the task waiting on the bus whenever one of the
+/// master flags fires. DMA transfers are completed by the
+/// `dmac::InterruptHandler`, which must be bound as well when a DMA channel is
+/// used.
 pub struct InterruptHandler<S: Sercom> {
     _private: (),
     _sercom: PhantomData<S>,
@@ -33,6 +33,8 @@ where
     pub(in super::super) registers: Registers<P::Sercom>,
     pads: P,
     freq: Hertz,
+    pec: bool,
+    arbitration_retries: u8,
 }
 
 impl<P: PadSet> Config<P> {
@@ -46,6 +48,8 @@ impl<P: PadSet> Config<P> {
             registers,
             pads,
             freq: freq.into(),
+            pec: false,
+            arbitration_retries: 0,
         }
     }
 
@@ -124,6 +128,45 @@ impl<P: PadSet> Config<P> {
         self.registers.get_run_in_standby()
     }
 
+    /// Run during debug mode (builder pattern version)
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger, so that no transaction makes progress past a breakpoint.
+    /// The I2C peripheral runs during debug by default.
+    #[inline]
+    pub fn run_during_debug(mut self, set: bool) -> Self {
+        self.set_run_during_debug(set);
+        self
+    }
+
+    /// Run during debug mode (setter version)
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger, so that no transaction makes progress past a breakpoint.
+    /// The I2C peripheral runs during debug by default.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, set: bool) {
+        self.registers.set_run_during_debug(set);
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.registers.get_run_during_debug()
+    }
+
+    /// Smallest supported ratio of the GCLK frequency to the SCL frequency
+    pub const MAX_CLOCK_DIVIDER: u32 = 10;
+
+    /// Get the maximum baud rate
+    ///
+    /// This is the GCLK frequency divided by
+    /// [`MAX_CLOCK_DIVIDER`](Self::MAX_CLOCK_DIVIDER).
+    #[inline]
+    pub fn max_baud(&self) -> Hertz {
+        self.freq / Self::MAX_CLOCK_DIVIDER
+    }
+
     /// Set the baud rate (builder pattern version)
     ///
     /// This function will calculate the best BAUD register setting based on the
@@ -132,6 +175,10 @@ impl<P: PadSet> Config<P> {
     /// the maximum supported baud rate.
     ///
     /// Note that 3x oversampling is not supported.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above [`max_baud`](Self::max_baud).
     #[inline]
     pub fn baud(mut self, baud: impl Into<Hertz>) -> Self {
         self.set_baud(baud);
@@ -144,8 +191,14 @@ impl<P: PadSet> Config<P> {
     /// stored GCLK frequency and desired baud rate. The maximum baud rate is
     /// GCLK frequency/10. Values outside this range will saturate at
     /// the maximum supported baud rate.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above [`max_baud`](Self::max_baud).
     #[inline]
     pub fn set_baud(&mut self, baud: impl Into<Hertz>) {
+        let baud = baud.into();
+        debug_assert!(baud <= self.max_baud(), "I2C baud rate above GCLK / 10");
         self.registers.set_baud(self.freq, baud);
     }
 
@@ -220,6 +273,59 @@ impl<P: PadSet> Config<P> {
         self.registers.get_inactive_timeout()
     }
 
+    /// Enable SMBus Packet Error Checking (builder pattern version).
+    ///
+    /// When set, the [SMBus](super#smbus) transactions of the [`I2c`] append a
+    /// PEC byte to every write, and read and verify a PEC byte after every
+    /// read. Plain I2C transactions are not affected.
+    #[inline]
+    pub fn pec(mut self, set: bool) -> Self {
+        self.set_pec(set);
+        self
+    }
+
+    /// Enable SMBus Packet Error Checking (setter version).
+    ///
+    /// When set, the [SMBus](super#smbus) transactions of the [`I2c`] append a
+    /// PEC byte to every write, and read and verify a PEC byte after every
+    /// read. Plain I2C transactions are not affected.
+    #[inline]
+    pub fn set_pec(&mut self, set: bool) {
+        self.pec = set;
+    }
+
+    /// Get the SMBus Packet Error Checking setting.
+    #[inline]
+    pub fn get_pec(&self) -> bool {
+        self.pec
+    }
+
+    /// Set the number of retries after losing arbitration (builder pattern
+    /// version).
+    ///
+    /// See the [multi-master](super#multi-master-buses) section of the module
+    /// documentation. The default of zero suits single-master buses.
+    #[inline]
+    pub fn arbitration_retries(mut self, retries: u8) -> Self {
+        self.set_arbitration_retries(retries);
+        self
+    }
+
+    /// Set the number of retries after losing arbitration (setter version).
+    ///
+    /// See the [multi-master](super#multi-master-buses) section of the module
+    /// documentation. The default of zero suits single-master buses.
+    #[inline]
+    pub fn set_arbitration_retries(&mut self, retries: u8) {
+        self.arbitration_retries = retries;
+    }
+
+    /// Get the number of retries after losing arbitration.
+    #[inline]
+    pub fn get_arbitration_retries(&self) -> u8 {
+        self.arbitration_retries
+    }
+
     /// Enable the I2C peripheral
     ///
     /// I2C transactions are not possible until the peripheral is enabled.
@@ -102,6 +102,9 @@ pub enum Error {
     LengthError,
     Nack,
     Timeout,
+    /// The PEC byte received at the end of an SMBus read did not match the
+    /// transferred bytes
+    Pec,
     #[cfg(feature = "dma")]
     Dma(crate::dmac::Error),
 }
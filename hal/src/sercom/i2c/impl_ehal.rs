@@ -12,6 +12,7 @@ impl i2c::Error for Error {
             Error::LengthError => ErrorKind::Other,
             Error::Nack => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown),
             Error::Timeout => ErrorKind::Other,
+            Error::Pec => ErrorKind::Other,
             // Pattern reachable when "dma" feature is enabled
             _ => ErrorKind::Other,
         }
@@ -72,20 +73,23 @@ impl<C: AnyConfig> i2c::I2c for I2c<C> {
         address: u8,
         operations: &mut [Operation<'_>],
     ) -> Result<(), Self::Error> {
-        self.transaction_byte_by_byte(address, operations)?;
-        Ok(())
+        self.retry_arbitration(|i2c| i2c.transaction_byte_by_byte(address, operations))
     }
 
     fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
-        self.do_write(address, bytes)?;
-        self.cmd_stop();
-        Ok(())
+        self.retry_arbitration(|i2c| {
+            i2c.do_write(address, bytes)?;
+            i2c.cmd_stop();
+            Ok(())
+        })
     }
 
     fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        self.do_read(address, buffer)?;
-        self.cmd_stop();
-        Ok(())
+        self.retry_arbitration(|i2c| {
+            i2c.do_read(address, buffer)?;
+            i2c.cmd_stop();
+            Ok(())
+        })
     }
 
     fn write_read(
@@ -94,9 +98,11 @@ impl<C: AnyConfig> i2c::I2c for I2c<C> {
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.do_write_read(address, bytes, buffer)?;
-        self.cmd_stop();
-        Ok(())
+        self.retry_arbitration(|i2c| {
+            i2c.do_write_read(address, bytes, buffer)?;
+            i2c.cmd_stop();
+            Ok(())
+        })
     }
 }
 
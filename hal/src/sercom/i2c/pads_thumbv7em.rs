@@ -47,6 +47,21 @@ where
     SCL: IsI2cPad<PadNum = Pad1, Sercom = S>,
     (SDA, SCL): ShareIoSet,
 {
+    /// Describe the SERCOM, IOSET and pad of each role at run time
+    ///
+    /// See [`uart::Pads::describe`](crate::sercom::uart::Pads::describe) for
+    /// an example.
+    #[inline]
+    pub fn describe(&self) -> PadsDescription<2> {
+        PadsDescription::new(
+            S::NUM,
+            [
+                ("SDA", <SDA as OptionalPad>::DYN),
+                ("SCL", <SCL as OptionalPad>::DYN),
+            ],
+        )
+    }
+
     /// Consume the [`Pads`] and return each individual
     /// [`Pin`](crate::gpio::Pin)
     #[inline]
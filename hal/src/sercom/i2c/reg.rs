@@ -67,9 +67,7 @@ impl<S: Sercom> Registers<S> {
 
     /// Configure the baudrate for I2C master mode
     pub(super) fn set_baud(&mut self, clock_freq: impl Into<Hertz>, baud: impl Into<Hertz>) {
-        // Since BAUDLOW is 0, the baud rate is used to generate both SCL high and SCL
-        // low periods.
-        let baud = (clock_freq.into().to_Hz() / (2 * baud.into().to_Hz()) - 1) as u8;
+        let baud = baud_bits(clock_freq.into(), baud.into());
 
         unsafe {
             self.i2c_master().baud().modify(|_, w| w.baud().bits(baud));
@@ -151,6 +149,21 @@ impl<S: Sercom> Registers<S> {
         self.i2c_master().ctrla().read().runstdby().bit()
     }
 
+    /// Run during debug mode
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger.
+    #[inline]
+    pub(super) fn set_run_during_debug(&mut self, set: bool) {
+        self.i2c_master().dbgctrl().write(|w| w.dbgstop().bit(!set));
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub(super) fn get_run_during_debug(&self) -> bool {
+        self.i2c_master().dbgctrl().read().dbgstop().bit_is_clear()
+    }
+
     /// Set Smart Mode
     #[inline]
     pub(super) fn set_smart_mode(&mut self, set: bool) {
@@ -163,6 +176,21 @@ impl<S: Sercom> Registers<S> {
         self.i2c_master().ctrlb().read().smen().bit()
     }
 
+    /// Enable or disable quick command
+    ///
+    /// When set, the interrupt flags are set right after the address is
+    /// acknowledged, and no data byte is transferred.
+    #[inline]
+    pub(super) fn set_quick_command(&mut self, set: bool) {
+        self.i2c_master().ctrlb().modify(|_, w| w.qcen().bit(set));
+    }
+
+    /// Get the current quick command setting
+    #[inline]
+    pub(super) fn get_quick_command(&self) -> bool {
+        self.i2c_master().ctrlb().read().qcen().bit()
+    }
+
     /// Clear specified interrupt flags
     #[inline]
     pub(super) fn clear_flags(&mut self, flags: Flags) {
@@ -223,9 +251,10 @@ impl<S: Sercom> Registers<S> {
     /// an async method.
     #[inline]
     pub(super) fn start_write(&mut self, addr: u8) -> Result<(), Error> {
-        if self.get_smart_mode() {
+        if self.get_smart_mode() || self.get_quick_command() {
             self.disable();
             self.set_smart_mode(false);
+            self.set_quick_command(false);
             self.enable();
         }
 
@@ -255,9 +284,10 @@ impl<S: Sercom> Registers<S> {
     /// Start a write transaction. May be used by [`start_write_blocking`], or
     /// an async method.
     pub(super) fn start_read(&mut self, addr: u8) -> Result<(), Error> {
-        if self.get_smart_mode() {
+        if self.get_smart_mode() || self.get_quick_command() {
             self.disable();
             self.set_smart_mode(false);
+            self.set_quick_command(false);
             self.enable();
         }
 
@@ -286,9 +316,13 @@ impl<S: Sercom> Registers<S> {
         // wait for transmission to complete
         loop {
             let intflag = self.i2c_master().intflag().read();
-            // If arbitration was lost, it will be signalled via the mb bit
+            // If arbitration was lost, or the address was not acknowledged, it
+            // will be signalled via the mb bit
             if intflag.mb().bit_is_set() {
-                return Err(Error::ArbitrationLost);
+                return self
+                    .read_status()
+                    .check_bus_error()
+                    .and(Err(Error::ArbitrationLost));
             }
             if intflag.sb().bit_is_set() || intflag.error().bit_is_set() {
                 break;
@@ -308,9 +342,10 @@ impl<S: Sercom> Registers<S> {
     #[cfg(feature = "dma")]
     #[inline]
     pub(in super::super) fn start_dma_write(&mut self, address: u8, xfer_len: u8) {
-        if !self.get_smart_mode() {
+        if !self.get_smart_mode() || self.get_quick_command() {
             self.disable();
             self.set_smart_mode(true);
+            self.set_quick_command(false);
             self.enable();
         }
 
@@ -333,9 +368,10 @@ impl<S: Sercom> Registers<S> {
     #[cfg(feature = "dma")]
     #[inline]
     pub(in super::super) fn start_dma_read(&mut self, address: u8, xfer_len: u8) {
-        if !self.get_smart_mode() {
+        if !self.get_smart_mode() || self.get_quick_command() {
             self.disable();
             self.set_smart_mode(true);
+            self.set_quick_command(false);
             self.enable();
         }
 
@@ -348,6 +384,50 @@ impl<S: Sercom> Registers<S> {
         self.sync_sysop();
     }
 
+    /// Issue an SMBus quick command, and send a STOP condition
+    ///
+    /// Only the address and the R/W bit are sent. The quick command setting is
+    /// left enabled, until the next transaction disables it.
+    pub(super) fn do_quick(&mut self, addr: u8, read: bool) -> Result<(), Error> {
+        if self.get_smart_mode() || !self.get_quick_command() {
+            self.disable();
+            self.set_smart_mode(false);
+            self.set_quick_command(true);
+            self.enable();
+        }
+
+        self.check_bus_status()?;
+
+        self.i2c_master()
+            .intflag()
+            .modify(|_, w| w.error().clear_bit());
+
+        let addr = if read {
+            encode_read_address(addr)
+        } else {
+            encode_write_address(addr)
+        };
+        unsafe {
+            self.i2c_master().addr().write(|w| w.addr().bits(addr));
+        }
+
+        // With quick command enabled, MB or SB is set as soon as the address has
+        // been (not) acknowledged
+        loop {
+            let intflag = self.i2c_master().intflag().read();
+            if intflag.mb().bit_is_set()
+                || intflag.sb().bit_is_set()
+                || intflag.error().bit_is_set()
+            {
+                break;
+            }
+        }
+
+        let result = self.read_status().check_bus_error();
+        self.cmd_stop();
+        result
+    }
+
     /// Send a STOP condition. If the I2C is performing a read, will also send a
     /// NACK to the slave.
     #[inline]
@@ -514,6 +594,17 @@ impl<S: Sercom> Registers<S> {
         self.sync_sysop();
     }
 
+    /// Wait until the bus is released by another master
+    ///
+    /// The bus state leaves BUSY when a STOP condition is detected, or when the
+    /// inactive timeout expires.
+    #[inline]
+    pub(super) fn wait_bus_released(&mut self) {
+        while self.read_status().busstate() == BusState::Busy {
+            core::hint::spin_loop();
+        }
+    }
+
     #[inline]
     fn sync_sysop(&mut self) {
         while self.i2c_master().syncbusy().read().sysop().bit_is_set() {}
@@ -546,10 +637,34 @@ impl<S: Sercom> Registers<S> {
     }
 }
 
-fn encode_write_address(addr_7_bits: u8) -> u16 {
+pub(super) fn encode_write_address(addr_7_bits: u8) -> u16 {
     (addr_7_bits as u16) << 1
 }
 
-fn encode_read_address(addr_7_bits: u8) -> u16 {
+pub(super) fn encode_read_address(addr_7_bits: u8) -> u16 {
     ((addr_7_bits as u16) << 1) | 1
 }
+
+/// Calculate the contents of the `BAUD` register for a baud rate of `baud`,
+/// with a GCLK frequency of `freq`
+///
+/// Baud rates outside of the achievable range saturate.
+#[inline]
+fn baud_bits(freq: Hertz, baud: Hertz) -> u8 {
+    // Since BAUDLOW is 0, the baud rate is used to generate both SCL high and SCL
+    // low periods.
+    let bits = (freq.to_Hz() / (2 * baud.to_Hz().max(1))).saturating_sub(1);
+    bits.try_into().unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_range_baud_rates_saturate() {
+        assert_eq!(baud_bits(Hertz::MHz(48), Hertz::kHz(400)), 59);
+        assert_eq!(baud_bits(Hertz::MHz(48), Hertz::MHz(48)), 0);
+        assert_eq!(baud_bits(Hertz::MHz(48), Hertz::kHz(10)), u8::MAX);
+    }
+}
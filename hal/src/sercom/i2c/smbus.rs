@@ -0,0 +1,175 @@
+//! SMBus transactions for [`I2c`]s
+
+use super::reg::{encode_read_address, encode_write_address};
+use super::{config::AnyConfig, Error, I2c};
+
+/// Direction of an SMBus quick command, carried by the R/W bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    /// R/W bit cleared
+    Write,
+    /// R/W bit set
+    Read,
+}
+
+/// Compute the SMBus Packet Error Code of `bytes`
+///
+/// The PEC is a CRC-8 with polynomial `x^8 + x^2 + x + 1` (`0x07`), initial
+/// value zero and no reflection. It covers every byte of the message,
+/// including the address bytes with their R/W bit.
+#[inline]
+pub fn pec(bytes: &[u8]) -> u8 {
+    pec_update(0, bytes)
+}
+
+/// Continue computing a PEC over `bytes`, from a previous value `crc`
+fn pec_update(crc: u8, bytes: &[u8]) -> u8 {
+    bytes.iter().fold(crc, |crc, &byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+impl<C: AnyConfig, D> I2c<C, D> {
+    /// Issue an SMBus Quick Command
+    ///
+    /// Only the address is sent, and `direction` is carried by the R/W bit. No
+    /// data byte, and no PEC, is transferred. Returns [`Error::Nack`] if no
+    /// device acknowledged the address, which makes this suitable for probing
+    /// a bus.
+    #[inline]
+    pub fn smbus_quick(&mut self, address: u8, direction: Direction) -> Result<(), Error> {
+        let read = direction == Direction::Read;
+        self.retry_arbitration(|i2c| i2c.config.as_mut().registers.do_quick(address, read))
+    }
+
+    /// Perform an SMBus Write Byte transaction
+    ///
+    /// Writes `command` followed by `value`, and by a PEC byte if
+    /// [enabled](super::Config::pec).
+    #[inline]
+    pub fn write_byte(&mut self, address: u8, command: u8, value: u8) -> Result<(), Error> {
+        self.smbus_write(address, &[command, value])
+    }
+
+    /// Perform an SMBus Write Word transaction
+    ///
+    /// Writes `command` followed by `value`, low byte first, and by a PEC byte
+    /// if [enabled](super::Config::pec).
+    #[inline]
+    pub fn write_word(&mut self, address: u8, command: u8, value: u16) -> Result<(), Error> {
+        let [low, high] = value.to_le_bytes();
+        self.smbus_write(address, &[command, low, high])
+    }
+
+    /// Perform an SMBus Read Byte transaction
+    ///
+    /// Writes `command`, then reads a byte after a repeated start. If PEC is
+    /// [enabled](super::Config::pec), a PEC byte is read as well, and a
+    /// mismatch returns [`Error::Pec`].
+    #[inline]
+    pub fn read_byte(&mut self, address: u8, command: u8) -> Result<u8, Error> {
+        let mut buffer = [0; 1];
+        self.smbus_read(address, command, &mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    /// Perform an SMBus Read Word transaction
+    ///
+    /// Writes `command`, then reads a word, low byte first, after a repeated
+    /// start. If PEC is [enabled](super::Config::pec), a PEC byte is read as
+    /// well, and a mismatch returns [`Error::Pec`].
+    #[inline]
+    pub fn read_word(&mut self, address: u8, command: u8) -> Result<u16, Error> {
+        let mut buffer = [0; 2];
+        self.smbus_read(address, command, &mut buffer)?;
+        Ok(u16::from_le_bytes(buffer))
+    }
+
+    /// Write `bytes`, followed by their PEC if enabled, and send a STOP
+    fn smbus_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Error> {
+        let send_pec = self.config.as_ref().get_pec();
+        self.retry_arbitration(|i2c| {
+            let result = i2c.do_write(address, bytes).and_then(|()| {
+                if send_pec {
+                    let crc = pec_update(pec(&[encode_write_address(address) as u8]), bytes);
+                    i2c.continue_write(&[crc])
+                } else {
+                    Ok(())
+                }
+            });
+            i2c.stop_unless_lost(&result);
+            result
+        })
+    }
+
+    /// Write `command` and read `buffer` after a repeated start, followed by
+    /// their PEC if enabled, and send a STOP
+    fn smbus_read(&mut self, address: u8, command: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        let check_pec = self.config.as_ref().get_pec();
+        let mut received = [0; 3];
+        let received = &mut received[..buffer.len() + check_pec as usize];
+
+        self.retry_arbitration(|i2c| {
+            let result = i2c.do_write_read(address, &[command], received);
+            i2c.stop_unless_lost(&result);
+            result
+        })?;
+
+        let (data, received_pec) = received.split_at(buffer.len());
+        if check_pec {
+            let header = [
+                encode_write_address(address) as u8,
+                command,
+                encode_read_address(address) as u8,
+            ];
+            if pec_update(pec(&header), data) != received_pec[0] {
+                return Err(Error::Pec);
+            }
+        }
+        buffer.copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Send a STOP, unless the bus was lost to another master
+    #[inline]
+    fn stop_unless_lost<T>(&mut self, result: &Result<T, Error>) {
+        if !matches!(result, Err(Error::ArbitrationLost)) {
+            self.cmd_stop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pec_check_value() {
+        // Check value of the CRC-8/SMBUS algorithm
+        assert_eq!(pec(b"123456789"), 0xF4);
+        assert_eq!(pec(&[]), 0x00);
+        assert_eq!(pec(&[0x01]), 0x07);
+        assert_eq!(pec(&[0x80]), 0x89);
+    }
+
+    #[test]
+    fn pec_incremental() {
+        let message = [0x16, 0x09, 0x17, 0x34, 0x12];
+        let (header, data) = message.split_at(3);
+        assert_eq!(pec_update(pec(header), data), pec(&message));
+    }
+
+    #[test]
+    fn pec_of_message_with_pec_is_zero() {
+        let mut message = [0x16, 0x00, 0x01, 0x00, 0x00];
+        message[4] = pec(&message[..4]);
+        assert_eq!(pec(&message), 0);
+    }
+}
@@ -0,0 +1,440 @@
+//! I2C target (slave) mode
+//!
+//! An [`I2cTarget`] answers transactions started by another device, the
+//! controller, when they are addressed to the [`Config`]'s address. It can be
+//! used to emulate a sensor, or to expose a register map to a host processor.
+//! Only an `async` API is provided.
+//!
+//! [`I2cTarget::transaction`] waits until the controller addresses the
+//! target, and tells whether the controller wants to write or read. The
+//! target then handles that phase with [`receive`](I2cTarget::receive) or
+//! [`respond`](I2cTarget::respond), which return once the controller ends it
+//! with a STOP or a repeated START. A register read thus shows as a `Write`
+//! phase carrying the register number, followed by a `Read` phase:
+//!
+//! ```no_run
+//! use atsamd_hal::sercom::i2c::target::{self, Transaction};
+//!
+//! atsamd_hal::bind_interrupts!(struct Irqs {
+//!     SERCOM2 => target::InterruptHandler<Sercom2>;
+//! });
+//!
+//! let mut target = target::Config::new(&mclk, sercom2, pads, 0x28).enable(Irqs);
+//! let mut registers = [0u8; 16];
+//! let mut pointer = 0;
+//!
+//! loop {
+//!     match target.transaction().await? {
+//!         Transaction::Write { .. } => {
+//!             let mut buf = [0; 2];
+//!             match target.receive(&mut buf).await? {
+//!                 1 => pointer = buf[0] as usize % 16,
+//!                 2 => registers[buf[0] as usize % 16] = buf[1],
+//!                 _ => {}
+//!             }
+//!         }
+//!         Transaction::Read => {
+//!             target.respond(&registers[pointer..]).await?;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Clock stretching
+//!
+//! The SERCOM holds SCL low after recognizing its address, and after every
+//! byte, until the software has handled it. The controller therefore waits for
+//! the target, however long it takes to prepare a response: there is no need to
+//! have the data ready before calling [`respond`](I2cTarget::respond). The bus
+//! is held for as long as a phase is left unhandled, so every [`Transaction`]
+//! must be followed by a call to `receive` or `respond`.
+//!
+//! # General call
+//!
+//! When [`general_call`](Config::general_call) is enabled, the target also
+//! answers writes to the general call address, `0x00`. These show as
+//! `Transaction::Write { general_call: true }`.
+
+use core::{marker::PhantomData, task::Poll};
+
+use atsamd_hal_macros::hal_cfg;
+
+use super::{Error, PadSet};
+use crate::{
+    async_hal::interrupts::{Binding, Handler, InterruptSource},
+    pac::sercom0::{i2cs::ctrla::Modeselect, I2cs},
+    sercom::{ApbClkCtrl, Sercom},
+    typelevel::Sealed,
+};
+
+/// Address match interrupt flag
+const AMATCH: u8 = 0x02;
+/// Data ready interrupt flag
+const DRDY: u8 = 0x04;
+/// Stop received interrupt flag
+const PREC: u8 = 0x01;
+/// Error interrupt flag
+const ERROR: u8 = 0x80;
+const ALL_FLAGS: u8 = AMATCH | DRDY | PREC | ERROR;
+
+/// Execute the acknowledge action, then wait for a START condition
+const CMD_WAIT_START: u8 = 0x2;
+/// Execute the acknowledge action, then continue with the next byte
+const CMD_CONTINUE: u8 = 0x3;
+
+/// Byte sent when the controller reads past the end of the response
+const FILL_BYTE: u8 = 0xFF;
+
+#[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+type DataReg = u8;
+
+#[hal_cfg("sercom0-d5x")]
+type DataReg = u32;
+
+//=============================================================================
+// Interrupt handler
+//=============================================================================
+
+/// Interrupt handler for [`I2cTarget`]s
+pub struct InterruptHandler<S: Sercom> {
+    _private: (),
+    _sercom: PhantomData<S>,
+}
+
+impl<S: Sercom> Sealed for InterruptHandler<S> {}
+
+impl<S: Sercom> Handler<S::Interrupt> for InterruptHandler<S> {
+    #[inline]
+    unsafe fn on_interrupt() {
+        let mut peripherals = unsafe { crate::pac::Peripherals::steal() };
+        let i2cs = S::reg_block(&mut peripherals).i2cs();
+        let pending = i2cs.intflag().read().bits() & ALL_FLAGS;
+
+        // Disable interrupts, but don't clear the flags. The target will take care
+        // of clearing flags and re-enabling interrupts when woken.
+        if pending != 0 {
+            i2cs.intenclr().write(|w| unsafe { w.bits(pending) });
+            S::rx_waker().wake();
+        }
+    }
+}
+
+//=============================================================================
+// Config
+//=============================================================================
+
+/// A configurable, disabled I2C target
+///
+/// Upon creation, the [`Config`] takes ownership of the [`Sercom`] and resets
+/// it, returning it configured as an I2C target answering to a 7-bit address.
+pub struct Config<P: PadSet> {
+    sercom: P::Sercom,
+    pads: P,
+}
+
+impl<P: PadSet> Config<P> {
+    /// Create a new [`Config`] answering to the 7-bit `address`
+    ///
+    /// This function will enable the corresponding APB clock, and reset the
+    /// [`Sercom`] peripheral. The SERCOM core clock must be enabled and running
+    /// while the target is in use, although its frequency does not set the bus
+    /// speed, which the controller chooses.
+    #[inline]
+    pub fn new(apb_clk_ctrl: &ApbClkCtrl, mut sercom: P::Sercom, pads: P, address: u8) -> Self {
+        sercom.enable_apb_clock(apb_clk_ctrl);
+        let i2cs = sercom.i2cs();
+        i2cs.ctrla().write(|w| w.swrst().set_bit());
+        while i2cs.syncbusy().read().swrst().bit_is_set() {}
+        i2cs.ctrla()
+            .write(|w| w.mode().variant(Modeselect::I2cSlave));
+        i2cs.addr()
+            .write(|w| unsafe { w.addr().bits(address.into()) });
+        Self { sercom, pads }
+    }
+
+    #[inline]
+    fn i2cs(&self) -> &I2cs {
+        self.sercom.i2cs()
+    }
+
+    /// Answer the general call address (builder pattern version)
+    #[inline]
+    pub fn general_call(mut self, set: bool) -> Self {
+        self.set_general_call(set);
+        self
+    }
+
+    /// Answer the general call address (setter version)
+    #[inline]
+    pub fn set_general_call(&mut self, set: bool) {
+        self.i2cs().addr().modify(|_, w| w.gencen().bit(set));
+    }
+
+    /// Get whether the general call address is answered
+    #[inline]
+    pub fn get_general_call(&self) -> bool {
+        self.i2cs().addr().read().gencen().bit()
+    }
+
+    /// Run in standby mode (builder pattern version)
+    ///
+    /// When set, the target keeps answering while the chip is in standby, and
+    /// an address match wakes the chip up.
+    #[inline]
+    pub fn run_in_standby(mut self, set: bool) -> Self {
+        self.set_run_in_standby(set);
+        self
+    }
+
+    /// Run in standby mode (setter version)
+    ///
+    /// When set, the target keeps answering while the chip is in standby, and
+    /// an address match wakes the chip up.
+    #[inline]
+    pub fn set_run_in_standby(&mut self, set: bool) {
+        self.i2cs().ctrla().modify(|_, w| w.runstdby().bit(set));
+    }
+
+    /// Get the current run in standby mode
+    #[inline]
+    pub fn get_run_in_standby(&self) -> bool {
+        self.i2cs().ctrla().read().runstdby().bit()
+    }
+
+    /// Enable the target, and start answering the controller
+    #[inline]
+    pub fn enable<I>(self, _interrupts: I) -> I2cTarget<P>
+    where
+        I: Binding<<P::Sercom as Sercom>::Interrupt, InterruptHandler<P::Sercom>>,
+    {
+        self.i2cs().ctrla().modify(|_, w| w.enable().set_bit());
+        while self.i2cs().syncbusy().read().enable().bit_is_set() {}
+
+        <P::Sercom as Sercom>::Interrupt::unpend();
+        unsafe { <P::Sercom as Sercom>::Interrupt::enable() };
+
+        I2cTarget { config: self }
+    }
+
+    /// Consume the [`Config`], reset the peripheral, and return the [`Sercom`]
+    /// and [`Pads`](super::Pads)
+    #[inline]
+    pub fn free(self) -> (P::Sercom, P) {
+        self.i2cs().ctrla().write(|w| w.swrst().set_bit());
+        while self.i2cs().syncbusy().read().swrst().bit_is_set() {}
+        (self.sercom, self.pads)
+    }
+}
+
+//=============================================================================
+// I2cTarget
+//=============================================================================
+
+/// Phase of a transaction requested by the controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Transaction {
+    /// The controller writes to the target, which must call
+    /// [`receive`](I2cTarget::receive)
+    Write {
+        /// The controller addressed every target with the general call
+        /// address, rather than this target
+        general_call: bool,
+    },
+    /// The controller reads from the target, which must call
+    /// [`respond`](I2cTarget::respond)
+    Read,
+}
+
+/// An enabled I2C target
+///
+/// Create this struct by calling [`Config::enable`].
+pub struct I2cTarget<P: PadSet> {
+    config: Config<P>,
+}
+
+impl<P: PadSet> I2cTarget<P> {
+    #[inline]
+    fn i2cs(&self) -> &I2cs {
+        self.config.i2cs()
+    }
+
+    /// Wait until one of `flags` is set
+    async fn wait_flags(&mut self, flags: u8) -> u8 {
+        core::future::poll_fn(|cx| {
+            let pending = self.i2cs().intflag().read().bits() & flags;
+            if pending != 0 {
+                return Poll::Ready(pending);
+            }
+
+            self.i2cs()
+                .intenclr()
+                .write(|w| unsafe { w.bits(ALL_FLAGS) });
+            // By convention, I2C uses the sercom's RX waker.
+            <P::Sercom as Sercom>::rx_waker().register(cx.waker());
+            self.i2cs().intenset().write(|w| unsafe { w.bits(flags) });
+
+            let pending = self.i2cs().intflag().read().bits() & flags;
+            if pending != 0 {
+                Poll::Ready(pending)
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Clear the ERROR flag, and return the error it signalled
+    fn take_error(&mut self) -> Error {
+        let status = self.i2cs().status().read();
+        let error = if status.coll().bit_is_set() {
+            Error::ArbitrationLost
+        } else if status.lowtout().bit_is_set() || status.sexttout().bit_is_set() {
+            Error::Timeout
+        } else {
+            Error::BusError
+        };
+        self.i2cs().status().write(|w| {
+            w.buserr().set_bit();
+            w.coll().set_bit();
+            w.lowtout().set_bit();
+            w.sexttout().set_bit()
+        });
+        self.i2cs().intflag().write(|w| w.error().set_bit());
+        error
+    }
+
+    /// Acknowledge (or not) the last address or byte, then issue `cmd`
+    #[inline]
+    fn command(&mut self, ack: bool, cmd: u8) {
+        self.i2cs().ctrlb().modify(|_, w| {
+            w.ackact().bit(!ack);
+            unsafe { w.cmd().bits(cmd) }
+        });
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_cast)]
+    fn read_data(&mut self) -> u8 {
+        // SAMx5x: u32 -> u8 conversion is fine as long as we don't set CTRLC.DATA32B to
+        // 1.
+        self.i2cs().data().read().bits() as u8
+    }
+
+    /// Wait for the controller to address this target
+    ///
+    /// The address is acknowledged before returning, and the controller is
+    /// then held by clock stretching until the returned phase is handled.
+    pub async fn transaction(&mut self) -> Result<Transaction, Error> {
+        loop {
+            let flags = self.wait_flags(AMATCH | DRDY | PREC | ERROR).await;
+            if flags & ERROR != 0 {
+                return Err(self.take_error());
+            }
+            if flags & AMATCH != 0 {
+                break;
+            }
+            if flags & DRDY != 0 {
+                // Bytes of a phase which was left unhandled
+                self.read_data();
+                self.command(false, CMD_WAIT_START);
+            }
+            if flags & PREC != 0 {
+                self.i2cs().intflag().write(|w| w.prec().set_bit());
+            }
+        }
+
+        let read = self.i2cs().status().read().dir().bit_is_set();
+        // The received address byte, including the R/W bit
+        let general_call = self.read_data() >> 1 == 0;
+        self.command(true, CMD_CONTINUE);
+
+        Ok(if read {
+            Transaction::Read
+        } else {
+            Transaction::Write { general_call }
+        })
+    }
+
+    /// Receive the bytes written by the controller into `buffer`
+    ///
+    /// Returns the number of bytes received, once the controller ends the
+    /// phase. Bytes which do not fit in `buffer` are not acknowledged, and
+    /// are discarded.
+    pub async fn receive(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let mut received = 0;
+        loop {
+            let flags = self.wait_flags(AMATCH | DRDY | PREC | ERROR).await;
+            if flags & ERROR != 0 {
+                return Err(self.take_error());
+            }
+            if flags & DRDY != 0 {
+                let byte = self.read_data();
+                if let Some(slot) = buffer.get_mut(received) {
+                    *slot = byte;
+                    received += 1;
+                    self.command(true, CMD_CONTINUE);
+                } else {
+                    self.command(false, CMD_WAIT_START);
+                }
+                continue;
+            }
+            if flags & PREC != 0 {
+                self.i2cs().intflag().write(|w| w.prec().set_bit());
+            }
+            // A repeated START leaves AMATCH set, for the next transaction
+            return Ok(received);
+        }
+    }
+
+    /// Send `data` to the controller
+    ///
+    /// Returns the number of bytes sent, once the controller ends the phase.
+    /// If the controller reads more bytes than `data` holds, `0xFF` is sent
+    /// for the remaining ones, and is not counted.
+    pub async fn respond(&mut self, data: &[u8]) -> Result<usize, Error> {
+        let mut sent = 0;
+        let mut first = true;
+        loop {
+            let flags = self.wait_flags(AMATCH | DRDY | PREC | ERROR).await;
+            if flags & ERROR != 0 {
+                return Err(self.take_error());
+            }
+            if flags & DRDY != 0 {
+                if !first && self.i2cs().status().read().rxnack().bit_is_set() {
+                    // The controller does not want any more bytes
+                    self.command(true, CMD_WAIT_START);
+                } else {
+                    let byte = match data.get(sent) {
+                        Some(&byte) => {
+                            sent += 1;
+                            byte
+                        }
+                        None => FILL_BYTE,
+                    };
+                    self.i2cs()
+                        .data()
+                        .write(|w| unsafe { w.data().bits(byte as DataReg) });
+                    first = false;
+                }
+                continue;
+            }
+            if flags & PREC != 0 {
+                self.i2cs().intflag().write(|w| w.prec().set_bit());
+            }
+            return Ok(sent);
+        }
+    }
+
+    /// Disable the target, and return the underlying [`Config`]
+    #[inline]
+    pub fn disable(self) -> Config<P> {
+        <P::Sercom as Sercom>::Interrupt::disable();
+        self.i2cs()
+            .intenclr()
+            .write(|w| unsafe { w.bits(ALL_FLAGS) });
+        self.i2cs().ctrla().modify(|_, w| w.enable().clear_bit());
+        while self.i2cs().syncbusy().read().enable().bit_is_set() {}
+        self.config
+    }
+}
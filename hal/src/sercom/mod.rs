@@ -2,6 +2,86 @@
 //!
 //! The SERCOM module is used to configure the SERCOM peripherals as USART, SPI
 //! or I2C interfaces.
+//!
+//! # Portability across chip families
+//!
+//! The pad definitions are implemented separately for thumbv6m (SAMD11/SAMD21)
+//! and thumbv7em (SAMx5x) targets, but the [`uart`], [`spi`] and [`i2c`]
+//! modules expose the same `Pads`, `PadSet`, `ValidPads` and `ValidConfig`
+//! items on every chip. Code that is generic over these traits compiles
+//! unchanged for any target, as long as it only relies on the items listed
+//! below as portable.
+//!
+//! Where the hardware genuinely differs, the API is feature-gated rather than
+//! renamed:
+//!
+//! * SAMx5x chips require all pads to belong to the same [`IoSet`]. The
+//!   [`ShareIoSet`] bound is defined on every chip, but it is trivially
+//!   satisfied on SAMD11/SAMD21.
+//! * On SAMD11 chips, the same [`PinId`](crate::gpio::PinId) can map to two
+//!   different [`PadNum`]s of the same `Sercom`. [`GetPad`] and
+//!   [`Pad`] therefore take an extra `PadNum` type parameter, the
+//!   `Pads` builder methods take pins already converted to the correct
+//!   [`PinMode`](crate::gpio::PinMode), and the UART and SPI `PadsFromIds`
+//!   aliases are not available.
+//! * The `RXPO`/`TXPO` (UART) and `DIPO`/`DOPO` (SPI) tables differ between
+//!   families. They are exposed through the same `RxpoTxpo` and `DipoDopo`
+//!   traits on every chip.
+//!
+//! # Register synchronization
+//!
+//! Writes to some SERCOM registers must be synchronized to the peripheral
+//! clock before they take effect. Code which configures a SERCOM through the
+//! PAC can wait for this with [`Sercom::wait_syncbusy`] or [`Sercom::sync`],
+//! rather than polling `SYNCBUSY` itself. [`SyncBusy`] lists the registers
+//! which need it.
+//!
+//! ```no_run
+//! # fn example(sercom: atsamd_hal::sercom::Sercom0) {
+//! use atsamd_hal::sercom::{Sercom, SyncBusy};
+//!
+//! sercom.i2cm().ctrla().modify(|_, w| w.enable().clear_bit());
+//! sercom.wait_syncbusy(SyncBusy::ENABLE);
+//! // `CTRLA` can now be modified
+//! # }
+//! ```
+//!
+//! # Software reset
+//!
+//! [`Sercom::reset`] returns a SERCOM to its reset state, for example before
+//! driving it through the PAC in a different mode, or to recover from a
+//! wedged peripheral after a bus error. Any prior configuration is lost,
+//! including the mode, and the peripheral is left disabled.
+//!
+//! The [`uart`], [`spi`] and [`i2c`] drivers already reset the SERCOM when a
+//! `Config` is created, and when it is freed, so a SERCOM returned by `free`
+//! can be configured again right away. A driver owns its SERCOM, so a reset
+//! can never pull the configuration from under it.
+//!
+//! ```no_run
+//! # fn example(mut sercom: atsamd_hal::sercom::Sercom0) {
+//! use atsamd_hal::sercom::Sercom;
+//!
+//! sercom.i2cm().ctrla().modify(|_, w| w.enable().clear_bit());
+//! // ... the peripheral stopped responding
+//! sercom.reset();
+//! // Every register now holds its reset value
+//! # }
+//! ```
+//!
+//! # Behavior at a debugger breakpoint
+//!
+//! The `DBGCTRL` register selects whether a peripheral keeps running while the
+//! CPU is halted by a debugger. It exists on every SERCOM, TC and TCC of all
+//! supported chips, but the reset values differ:
+//!
+//! * SERCOMs keep running by default. Pass `false` to the `run_during_debug`
+//!   method of the [`uart`], [`spi`] or [`i2c`] `Config` to halt the baud-rate
+//!   generator at a breakpoint instead.
+//! * TCs and TCCs are halted by default. The
+//!   [`TimerCounter`](crate::timer::TimerCounter) and PWM types provide a
+//!   `set_run_during_debug` method to keep them running.
+//!
 //! # Undocumented features
 //!
 //! The ATSAMx5x chips contain certain features that aren't documented in the
@@ -58,6 +138,9 @@ pub mod spi;
 pub mod spi_future;
 pub mod uart;
 
+mod sync;
+pub use sync::SyncBusy;
+
 #[cfg(feature = "dma")]
 pub mod dma;
 
@@ -78,7 +161,6 @@ pub trait Sercom: Sealed + Deref<Target = sercom0::RegisterBlock> {
     #[cfg(feature = "dma")]
     const DMA_TX_TRIGGER: TriggerSource;
 
-    #[cfg(feature = "async")]
     type Interrupt: crate::async_hal::interrupts::InterruptSource;
 
     /// Enable the corresponding APB clock
@@ -88,6 +170,42 @@ pub trait Sercom: Sealed + Deref<Target = sercom0::RegisterBlock> {
     /// [`Peripherals`] block
     fn reg_block(peripherals: &mut Peripherals) -> &crate::pac::sercom0::RegisterBlock;
 
+    /// Read the `SYNCBUSY` register
+    ///
+    /// The register is read the same way in every SERCOM mode. See the
+    /// [`SyncBusy`] documentation for the registers which need
+    /// synchronization.
+    #[inline]
+    fn read_syncbusy(&self) -> SyncBusy {
+        sync::read_syncbusy(self)
+    }
+
+    /// Wait until none of the `mask` bits are set in the `SYNCBUSY` register
+    ///
+    /// Call this after writing to a register which needs synchronization,
+    /// before relying on its new value.
+    #[inline]
+    fn wait_syncbusy(&self, mask: SyncBusy) {
+        sync::wait_syncbusy(self, mask)
+    }
+
+    /// Wait until every ongoing synchronization is complete
+    #[inline]
+    fn sync(&self) {
+        self.wait_syncbusy(SyncBusy::all())
+    }
+
+    /// Reset every register of this SERCOM to its default value
+    ///
+    /// This issues a software reset (`CTRLA.SWRST`) and blocks until it is
+    /// complete. The peripheral is disabled, and any prior configuration,
+    /// including the mode, is lost. See the [module-level
+    /// documentation](self#software-reset).
+    #[inline]
+    fn reset(&mut self) {
+        sync::reset(self)
+    }
+
     /// Get a reference to this [`Sercom`]'s associated RX Waker
     #[cfg(feature = "async")]
     #[inline]
@@ -119,11 +237,9 @@ macro_rules! sercom {
                 #[cfg(feature = "dma")]
                 const DMA_TX_TRIGGER: TriggerSource = TriggerSource::[< Sercom $N Tx >];
 
-                #[cfg(feature = "async")]
                 #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
                 type Interrupt = $crate::async_hal::interrupts::[< SERCOM $N >];
 
-                #[cfg(feature = "async")]
                 #[hal_cfg("sercom0-d5x")]
                 type Interrupt = $crate::async_hal::interrupts::[< SERCOM $N >];
 
@@ -191,15 +307,12 @@ sercom!(apbdmask, 7);
 // even though some wakers may not be used on some chips if they actually don't
 // exist on variant's hardware
 #[hal_cfg("sercom0-d11")]
-#[cfg(feature = "async")]
 const NUM_SERCOM: usize = 3;
 
 #[hal_cfg("sercom0-d21")]
-#[cfg(feature = "async")]
 const NUM_SERCOM: usize = 6;
 
 #[hal_cfg("sercom0-d5x")]
-#[cfg(feature = "async")]
 const NUM_SERCOM: usize = 8;
 
 #[cfg(feature = "async")]
@@ -214,3 +327,55 @@ pub(super) mod async_api {
     /// Waker for a TX event.
     pub(super) static TX_WAKERS: [AtomicWaker; super::NUM_SERCOM] = [NEW_WAKER; super::NUM_SERCOM];
 }
+
+#[cfg(test)]
+mod tests {
+    //! Generic code written against the portable `Pads` API. None of these
+    //! functions are called: the test is that they type-check unchanged when
+    //! the test harness is built for each chip family.
+    #![allow(dead_code)]
+
+    use super::*;
+    use crate::typelevel::NoneT;
+
+    fn uart_pad_fields<P: uart::ValidPads>() -> (u8, u8) {
+        (P::RXPO, P::TXPO)
+    }
+
+    fn uart_free<S, RX, TX>(pads: uart::Pads<S, RX, TX>) -> (RX, TX)
+    where
+        S: Sercom,
+        RX: OptionalPad,
+        TX: OptionalPad,
+        (RX, TX, NoneT, NoneT): ShareIoSet,
+    {
+        let (rx, tx, _, _) = pads.free();
+        (rx, tx)
+    }
+
+    fn spi_pad_fields<P: spi::ValidPads>() -> (u8, u8) {
+        P::DIPO_DOPO
+    }
+
+    fn spi_free<S, DI, DO, CK>(pads: spi::Pads<S, DI, DO, CK>) -> (DI, DO, CK)
+    where
+        S: Sercom,
+        DI: OptionalPad,
+        DO: OptionalPad,
+        CK: OptionalPad,
+        (DI, DO, CK, NoneT): ShareIoSet,
+    {
+        let (di, dout, ck, _) = pads.free();
+        (di, dout, ck)
+    }
+
+    fn i2c_free<S, SDA, SCL>(pads: i2c::Pads<S, SDA, SCL>) -> (SDA, SCL)
+    where
+        S: Sercom,
+        SDA: IsI2cPad<PadNum = Pad0, Sercom = S>,
+        SCL: IsI2cPad<PadNum = Pad1, Sercom = S>,
+        (SDA, SCL): ShareIoSet,
+    {
+        pads.free()
+    }
+}
@@ -32,15 +32,34 @@
 //! defines a [type-level enum], [`IoSet`], to enforce this restriction, and the
 //! [`InIoSet`] [type class] is responsible for labeling each `IsPad` type with
 //! its corresponding, valid `IoSet`\(s).
+//!
+//! The pads of each IOSET can also be inspected at run time. Every `IoSet` has a
+//! value-level counterpart in [`DynIoSet`], and the `PADS` table lists each
+//! SERCOM pad of the selected chip along with the IOSETs it belongs to.
+//! `DynIoSet::pads` filters that table for a given SERCOM, e.g. to list the
+//! pins of `Sercom0` in IOSET 1:
+//!
+//! ```no_run
+//! use atsamd_hal::sercom::pad::DynIoSet;
+//!
+//! for pad in DynIoSet::IoSet1.pads(0) {
+//!     // PA08, PA09, PA10 & PA11 for pads 0 to 3
+//!     let _ = (pad.id, pad.pad);
+//! }
+//! ```
+//!
+//! SAMD11 and SAMD21 chips have no IOSETs. On those targets, [`ShareIoSet`] is
+//! still defined, but it is implemented for every combination of pads. Generic
+//! code can therefore use the same `ShareIoSet` bounds on every chip.
 
-use atsamd_hal_macros::{hal_cfg, hal_module};
+use atsamd_hal_macros::{hal_cfg, hal_macro_helper, hal_module};
 use paste::paste;
 use seq_macro::seq;
 
 use super::Sercom;
 #[hal_cfg(any("sercom0-d21", "sercom0-d5x"))]
 use crate::gpio::OptionalPinId;
-use crate::gpio::{AnyPin, OptionalPin, Pin, PinId, PinMode};
+use crate::gpio::{AnyPin, DynPin, DynPinId, OptionalPin, Pin, PinId, PinMode, SpecificPin};
 use crate::typelevel::{NoneT, Sealed};
 
 #[hal_module(
@@ -59,7 +78,10 @@ mod impl_pad {}
 /// enum] documentation for an explanation of the pattern.
 ///
 /// [type-level enum]: crate::typelevel#type-level-enum
-pub trait PadNum: Sealed {}
+pub trait PadNum: Sealed {
+    /// Value-level pad number
+    const NUM: u8;
+}
 
 seq!(N in 0..=3 {
     paste! {
@@ -71,7 +93,9 @@ seq!(N in 0..=3 {
         /// [type-level enum]: crate::typelevel#type-level-enum
         pub enum Pad~N {}
         impl Sealed for Pad~N {}
-        impl PadNum for Pad~N {}
+        impl PadNum for Pad~N {
+            const NUM: u8 = N;
+        }
     }
 });
 
@@ -124,19 +148,96 @@ pub trait IsI2cPad: IsPad {}
 
 /// Type-level equivalent of `Option<Pad>`
 ///
+/// [`NoneT`] plays the role of `None`, and marks a role that has no pad
+/// assigned, e.g. the `CTS` pad of a UART without flow control. Every
+/// [`IsPad`] type plays the role of `Some`. Since the choice is made at
+/// compile time, a run-time `Option` can't select between the two; instead,
+/// both branches must be written out with their respective types.
+///
+/// Generic code can still move between the type-level and value-level
+/// worlds. [`into_optional`](Self::into_optional) erases the type of the pad
+/// into an `Option<DynPin>`, and [`try_from_optional`](Self::try_from_optional)
+/// recovers it, after checking the pin ID and mode at run time.
+///
+/// ```
+/// use atsamd_hal::gpio::{AlternateC, DynPin, Pin, PA08};
+/// use atsamd_hal::sercom::pad::OptionalPad;
+/// use atsamd_hal::typelevel::NoneT;
+///
+/// /// Store any optional pad in a type-erased form
+/// fn erase<P: OptionalPad>(pad: P) -> Option<DynPin> {
+///     pad.into_optional()
+/// }
+///
+/// fn round_trip(pad: Pin<PA08, AlternateC>) -> Pin<PA08, AlternateC> {
+///     assert!(erase(NoneT).is_none());
+///     let pin = erase(pad);
+///     OptionalPad::try_from_optional(pin).ok().unwrap()
+/// }
+/// ```
+///
 /// See the [`OptionalKind`] documentation for more details on the pattern.
 ///
 /// [`OptionalKind`]: crate::typelevel#optionalkind-trait-pattern
-pub trait OptionalPad: OptionalPin {
+pub trait OptionalPad: OptionalPin + Sized {
     type PadNum: OptionalPadNum;
+    /// Corresponding [`DynPad`], if any
+    const DYN: Option<DynPad>;
+
+    /// Convert into a value-level `Option`, erasing the type of the pin
+    fn into_optional(self) -> Option<DynPin>;
+
+    /// Recover the type-level pad from a value-level `Option`
+    ///
+    /// This fails if the `Option` variant doesn't match, or if the pin ID or
+    /// mode differs from the ones of `Self`. The `Option` is then returned
+    /// unchanged.
+    fn try_from_optional(pin: Option<DynPin>) -> Result<Self, Option<DynPin>>;
 }
 
 impl OptionalPad for NoneT {
     type PadNum = NoneT;
+    const DYN: Option<DynPad> = None;
+
+    #[inline]
+    fn into_optional(self) -> Option<DynPin> {
+        None
+    }
+
+    #[inline]
+    fn try_from_optional(pin: Option<DynPin>) -> Result<Self, Option<DynPin>> {
+        match pin {
+            None => Ok(NoneT),
+            pin => Err(pin),
+        }
+    }
 }
 
 impl<P: IsPad> OptionalPad for P {
     type PadNum = P::PadNum;
+    const DYN: Option<DynPad> = Some(DynPad {
+        id: <P::Id as PinId>::DYN,
+        num: <P::PadNum as PadNum>::NUM,
+    });
+
+    #[inline]
+    fn into_optional(self) -> Option<DynPin> {
+        let pin: SpecificPin<P> = self.into();
+        Some(pin.into())
+    }
+
+    #[inline]
+    fn try_from_optional(pin: Option<DynPin>) -> Result<Self, Option<DynPin>> {
+        match pin {
+            Some(pin)
+                if pin.id() == <P::Id as PinId>::DYN && pin.mode() == <P::Mode as PinMode>::DYN =>
+            {
+                let pin: SpecificPin<P> = pin.try_into().unwrap();
+                Ok(pin.into())
+            }
+            pin => Err(pin),
+        }
+    }
 }
 
 /// Type-level equivalent of `Some(Pad)`
@@ -260,6 +361,57 @@ where
     type Pad = Pad<S, I>;
 }
 
+//==============================================================================
+// PadsDescription
+//==============================================================================
+
+/// Value-level description of a pin configured as a SERCOM pad
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynPad {
+    /// ID of the pin
+    pub id: DynPinId,
+    /// SERCOM pad number
+    pub num: u8,
+}
+
+/// Run-time description of a set of SERCOM pads
+///
+/// Returned by the `describe` method of the `Pads` types, e.g.
+/// [`uart::Pads::describe`](crate::sercom::uart::Pads::describe). Everything
+/// in it is known at compile time, but it can be printed to check the pin
+/// assignment of a new board during bring-up.
+#[hal_macro_helper]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PadsDescription<const N: usize> {
+    /// SERCOM instance number
+    pub sercom: usize,
+    /// First IOSET containing all of the pads
+    #[hal_cfg("sercom0-d5x")]
+    pub io_set: Option<DynIoSet>,
+    /// Name of each role, along with the pad assigned to it, if any
+    pub roles: [(&'static str, Option<DynPad>); N],
+}
+
+impl<const N: usize> PadsDescription<N> {
+    #[inline]
+    #[hal_macro_helper]
+    pub(super) fn new(sercom: usize, roles: [(&'static str, Option<DynPad>); N]) -> Self {
+        Self {
+            sercom,
+            #[hal_cfg("sercom0-d5x")]
+            io_set: DynIoSet::containing(sercom, roles.iter().filter_map(|(_, pad)| *pad)),
+            roles,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<const N: usize> defmt::Format for PadsDescription<N> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", defmt::Debug2Format(self));
+    }
+}
+
 //==============================================================================
 // IoSet
 //==============================================================================
@@ -268,6 +420,7 @@ where
 mod ioset {
 
     use super::*;
+    use crate::gpio::DynAlternate;
     use sorted_hlist::{mk_hlist, HList, Intersect, NonEmptyHList};
 
     /// Type-level enum representing a SERCOM IOSET
@@ -290,6 +443,80 @@ mod ioset {
     /// [type-level enum]: crate::typelevel#type-level-enum
     pub trait IoSet: Sealed {
         type Order;
+        /// Corresponding [`DynIoSet`]
+        const DYN: DynIoSet;
+    }
+
+    /// Value-level `enum` for SERCOM IOSETs
+    ///
+    /// Each variant corresponds to one of the type-level [`IoSet`] variants.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DynIoSet {
+        IoSet1,
+        IoSet2,
+        IoSet3,
+        IoSet4,
+        IoSet5,
+        IoSet6,
+        UndocIoSet1,
+        UndocIoSet2,
+    }
+
+    impl DynIoSet {
+        /// Iterate over the pads of a SERCOM that belong to this IOSET
+        ///
+        /// `sercom` is the SERCOM instance number, as given by
+        /// [`Sercom::NUM`]. Pads are returned in the order of the `PADS`
+        /// table, i.e. sorted by [`PinId`].
+        #[inline]
+        pub fn pads(self, sercom: usize) -> impl Iterator<Item = &'static PadInfo> {
+            PADS.iter()
+                .filter(move |pad| pad.sercom == sercom && pad.io_sets.contains(&self))
+        }
+
+        /// Find the first IOSET of `sercom` containing all of `pads`
+        pub fn containing(
+            sercom: usize,
+            pads: impl Iterator<Item = DynPad> + Clone,
+        ) -> Option<Self> {
+            use DynIoSet::*;
+            [
+                IoSet1,
+                IoSet2,
+                IoSet3,
+                IoSet4,
+                IoSet5,
+                IoSet6,
+                UndocIoSet1,
+                UndocIoSet2,
+            ]
+            .into_iter()
+            .find(|io_set| {
+                pads.clone().all(|pad| {
+                    io_set
+                        .pads(sercom)
+                        .any(|info| info.id == pad.id && info.pad == pad.num)
+                })
+            })
+        }
+    }
+
+    /// Value-level description of a SERCOM pad
+    ///
+    /// This is the run-time counterpart of the [`IsPad`] and [`InIoSet`]
+    /// implementations for a given [`Pin`].
+    #[derive(Clone, Copy)]
+    pub struct PadInfo {
+        /// ID of the pin
+        pub id: DynPinId,
+        /// Alternate function connecting the pin to the SERCOM
+        pub alternate: DynAlternate,
+        /// SERCOM instance number
+        pub sercom: usize,
+        /// SERCOM pad number
+        pub pad: u8,
+        /// IOSETs containing this pad
+        pub io_sets: &'static [DynIoSet],
     }
 
     seq!(N in 1..=6 {
@@ -304,6 +531,7 @@ mod ioset {
             impl Sealed for IoSet~N {}
             impl IoSet for IoSet~N {
                 type Order = typenum::U~N;
+                const DYN: DynIoSet = DynIoSet::IoSet~N;
             }
         }
     });
@@ -339,6 +567,7 @@ mod ioset {
     impl Sealed for UndocIoSet1 {}
     impl IoSet for UndocIoSet1 {
         type Order = typenum::U8;
+        const DYN: DynIoSet = DynIoSet::UndocIoSet1;
     }
 
     /// Type-level variant of [`IoSet`] representing an undocumented SERCOM
@@ -365,6 +594,7 @@ mod ioset {
     impl Sealed for UndocIoSet2 {}
     impl IoSet for UndocIoSet2 {
         type Order = typenum::U9;
+        const DYN: DynIoSet = DynIoSet::UndocIoSet2;
     }
 
     /// Type class for SERCOM pads in a given [`IoSet`]
@@ -426,6 +656,21 @@ mod ioset {
 #[hal_cfg("sercom0-d5x")]
 pub use ioset::*;
 
+#[hal_cfg("sercom0-d5x")]
+pub use impl_pad::PADS;
+
+/// Shortcut trait for Pad tuples that share at least one IoSet
+///
+/// SAMD11 and SAMD21 chips have no IOSET restrictions, so every tuple of pads
+/// trivially implements this trait. It exists so that code which is generic
+/// over [`Pads`](crate::sercom::uart::Pads) types can state the same
+/// `(A, B, ..): ShareIoSet` bounds on every target.
+#[hal_cfg(not("sercom0-d5x"))]
+pub trait ShareIoSet {}
+
+#[hal_cfg(not("sercom0-d5x"))]
+impl<A> ShareIoSet for A {}
+
 #[cfg(doc)]
 #[hal_cfg(not("sercom0-d5x"))]
 /// This trait is not present with the selected feature set, defined for
@@ -449,3 +694,9 @@ pub enum UndocIoSet1 {}
 /// This type is not present with the selected feature set, defined for
 /// documentation only
 pub enum UndocIoSet2 {}
+
+#[cfg(doc)]
+#[hal_cfg(not("sercom0-d5x"))]
+/// This type is not present with the selected feature set, defined for
+/// documentation only
+pub enum DynIoSet {}
@@ -1,5 +1,7 @@
 //! Implementations of the [`IsPad`], [`GetPad`] and [`InIoSet`] traits
 
+use atsamd_hal_macros::hal_macro_helper;
+
 use crate::gpio::*;
 use crate::sercom::*;
 
@@ -89,10 +91,10 @@ macro_rules! pad_table {
     };
     (
         $(
-            $( #[$id_cfg:meta] )?
+            #[hal_cfg $id_cfg:tt]
             $PinId:ident {
                 $(
-                    $( #[$sercom_cfg:meta] )?
+                    #[hal_cfg $sercom_cfg:tt]
                     $Cfg:ident: ( $Sercom:ident, $PadNum:ident, $( $IoSet:ident ),+ ) $( + $I2C:ident )?,
                 )+
             }
@@ -100,15 +102,36 @@ macro_rules! pad_table {
     ) => {
         $(
             pad_table!(
-                $( #[$id_cfg] )?
+                #[hal_cfg $id_cfg]
                 $PinId{
                     $(
-                        $( #[$sercom_cfg] )?
+                        #[hal_cfg $sercom_cfg]
                         $Cfg: ( $Sercom, $PadNum, $( $IoSet),+ ) $( + $I2C )?,
                     )+
                 }
             );
         )+
+
+        /// Value-level list of every SERCOM pad available on the selected chip
+        ///
+        /// Each entry records the [`IoSet`]s the pad belongs to. Use
+        /// [`DynIoSet::pads`] to list the pads of a SERCOM in a given IOSET.
+        #[hal_macro_helper]
+        pub const PADS: &[PadInfo] = &[
+            $(
+                $(
+                    #[hal_cfg $id_cfg]
+                    #[hal_cfg $sercom_cfg]
+                    PadInfo {
+                        id: <$PinId as PinId>::DYN,
+                        alternate: DynAlternate::$Cfg,
+                        sercom: <$Sercom as Sercom>::NUM,
+                        pad: <$PadNum as PadNum>::NUM,
+                        io_sets: &[ $( <$IoSet as IoSet>::DYN ),+ ],
+                    },
+                )+
+            )+
+        ];
     };
 }
 
@@ -184,6 +184,11 @@
 //! manaully. But it must be [`SomePad`] in [`MasterHWSS`] and [`Slave`] modes,
 //! where it is controlled by the hardware.
 //!
+//! Both `Config` and [`Spi`] provide a `config_summary` method, which gathers
+//! the human-relevant settings, such as the actual baud rate, the SPI mode and
+//! the enabled interrupts, into a [`ConfigSummary`] that can be printed with
+//! `{:?}`.
+//!
 //! # Using a functional `Spi` peripheral
 //!
 //! An [`Spi`] struct has two type parameters. The first is the corresponding
@@ -214,6 +219,68 @@
 //! type Spi = spi::Spi<Config, Rx>;
 //! ```
 //!
+//! Every method and trait implementation that touches the RX or TX side of
+//! the peripheral is bounded by [`Receive`] or [`Transmit`] respectively, and
+//! the [`SpiBus`](crate::ehal::spi::SpiBus) implementation requires
+//! [`Duplex`]. A [`Tx`] [`Spi`] only needs the `DO` and `SCK` pins, for
+//! example to drive an LED strip, and can't be read from by mistake:
+//!
+//! ```
+//! use atsamd_hal::sercom::spi::{Config, MasterMode, Size, Spi, Tx, ValidConfig, ValidPads};
+//!
+//! fn send<P, M, C>(spi: &mut Spi<Config<P, M, C>, Tx>) -> Result<(), atsamd_hal::sercom::spi::Error>
+//! where
+//!     Config<P, M, C>: ValidConfig,
+//!     P: ValidPads,
+//!     M: MasterMode,
+//!     C: Size<Word = u8> + 'static,
+//! {
+//!     spi.write_all_blocking(&[0xFF, 0x00, 0x00])
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use atsamd_hal::sercom::spi::{Config, MasterMode, Size, Spi, Tx, ValidConfig, ValidPads};
+//!
+//! fn receive<P, M, C>(spi: &mut Spi<Config<P, M, C>, Tx>)
+//! where
+//!     Config<P, M, C>: ValidConfig,
+//!     P: ValidPads,
+//!     M: MasterMode,
+//!     C: Size<Word = u8> + 'static,
+//! {
+//!     let _ = spi.read_byte();
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use atsamd_hal::ehal::spi::SpiBus;
+//! use atsamd_hal::sercom::spi::{Spi, Tx, ValidConfig};
+//!
+//! fn exchange<C: ValidConfig>(spi: &mut Spi<C, Tx>) {
+//!     let mut buf = [0x12, 0x34];
+//!     let _ = spi.transfer_in_place(&mut buf);
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use atsamd_hal::sercom::spi::{Config, MasterMode, Rx, Size, Spi, ValidConfig, ValidPads};
+//!
+//! fn send<P, M, C>(spi: &mut Spi<Config<P, M, C>, Rx>)
+//! where
+//!     Config<P, M, C>: ValidConfig,
+//!     P: ValidPads,
+//!     M: MasterMode,
+//!     C: Size<Word = u8> + 'static,
+//! {
+//!     let _ = spi.write_byte(0x42);
+//! }
+//! ```
+//!
+//! Drivers which take an [`SpiBus`](crate::ehal::spi::SpiBus) even though they
+//! only transfer data in one direction can still use a simplex [`Spi`],
+//! through the [`PanicOnRead`] and [`PanicOnWrite`] wrappers described below.
+//!
 //! Only [`Spi`] structs can actually perform transactions. To do so, use the
 //! various embedded HAL traits, like [`spi::SpiBus`](crate::ehal::spi::SpiBus),
 //! [`embedded_io::Read`], [`embedded_io::Write`],
@@ -230,6 +297,29 @@
 //! let rcvd: u16 = block!(spi.read());
 //! ```
 //!
+//! ## In-place transfers
+//!
+//! SPI sends and receives one word for every clock cycle, so a full-duplex
+//! transfer can reuse a single buffer.
+//! [`SpiBus::transfer_in_place`](crate::ehal::spi::SpiBus::transfer_in_place)
+//! sends the words of the buffer and overwrites each of them with the word
+//! received in its place. Once it returns, the buffer holds the slave's
+//! response and the original data is gone. The embedded HAL v0.2
+//! [`Transfer`](crate::ehal_02::blocking::spi::Transfer) trait behaves the same
+//! way, and additionally returns the buffer for convenience.
+//!
+//! There is no need to copy the outgoing data beforehand, unless it is still
+//! needed afterwards. Data stored in an immutable `static` or literal must be
+//! copied into a mutable buffer first:
+//!
+//! ```
+//! use atsamd_hal::ehal::spi::SpiBus;
+//!
+//! let mut buf = *b"hello world";
+//! spi.transfer_in_place(&mut buf)?;
+//! // `buf` now holds the 11 bytes received from the slave
+//! ```
+//!
 //! ## Flushing the bus
 //!
 //! The [`SpiBus`](crate::ehal::spi::SpiBus) methods do not flush the bus when a
@@ -249,6 +339,85 @@
 //! spec](https://docs.rs/embedded-hal/latest/embedded_hal/spi/index.html#flushing)
 //! for more information.
 //!
+//! ## Chip select timing
+//!
+//! Some slaves need extra time between the assertion of their chip select and
+//! the first clock edge, or need their chip select held after the last clock
+//! edge. [`Spi::with_cs_timing`] combines an [`Spi`] with a chip select pin and
+//! a delay source into a [`CsTimingDevice`], which implements
+//! [`SpiDevice`](crate::ehal::spi::SpiDevice) and inserts both delays around
+//! every transaction:
+//!
+//! ```no_run
+//! # use atsamd_hal::ehal::{delay::DelayNs, digital::OutputPin, spi::SpiBus};
+//! use atsamd_hal::ehal::spi::SpiDevice;
+//! use atsamd_hal::fugit::ExtU32;
+//! # fn example<S, CS, D>(spi: atsamd_hal::sercom::spi::Spi<S, atsamd_hal::sercom::spi::Duplex>, cs: CS, delay: D)
+//! # where
+//! #     S: atsamd_hal::sercom::spi::ValidConfig,
+//! #     atsamd_hal::sercom::spi::Spi<S, atsamd_hal::sercom::spi::Duplex>: SpiBus,
+//! #     CS: OutputPin,
+//! #     D: DelayNs,
+//! # {
+//! let mut device = spi.with_cs_timing(cs, delay, 10.micros(), 500.nanos()).ok().unwrap();
+//! let mut sample = [0; 2];
+//! device.read(&mut sample).unwrap();
+//! # }
+//! ```
+//!
+//! ## Blocking byte transfers
+//!
+//! Master [`Spi`] structs with 8-bit words also provide
+//! [`write_byte`](Spi::write_byte),
+//! [`write_all_blocking`](Spi::write_all_blocking),
+//! [`read_byte`](Spi::read_byte) and
+//! [`read_exact_blocking`](Spi::read_exact_blocking), which don't require the
+//! `nb` crate. Reads send the no-op word, and writes discard the received
+//! bytes. Unlike the [`SpiBus`](crate::ehal::spi::SpiBus) methods, writes wait
+//! for the transmission to complete before returning.
+//!
+//! ## 32-bit byte transfers
+//!
+//! Master [`Spi`] structs with [`Duplex`] capability and 8-bit words also
+//! provide [`read_32bit`](Spi::read_32bit),
+//! [`write_32bit`](Spi::write_32bit) and
+//! [`transfer_in_place_32bit`](Spi::transfer_in_place_32bit). On SAMx5x chips,
+//! they use the hardware `LENGTH` counter to move four bytes per access to the
+//! `DATA` register, which speeds up long transfers such as SD card sectors. On
+//! SAMD11/SAMD21 chips, they fall back to byte-by-byte transfers.
+//!
+//! ## Loopback
+//!
+//! [`Config::loopback`] makes the receiver sample the data out pad instead of
+//! the data in pad, so that a [`Master`] receives every word it sends. No
+//! slave device or jumper wire is needed to exercise a driver on the bench:
+//!
+//! ```
+//! use atsamd_hal::ehal::spi::SpiBus;
+//!
+//! let mut spi = config.loopback(true).enable();
+//! let mut buf = [0x5A, 0xA5];
+//! spi.transfer_in_place(&mut buf)?;
+//! assert_eq!(buf, [0x5A, 0xA5]);
+//! ```
+//!
+//! The loopback goes through the data out pad, so the transmitted signal, and
+//! the clock, are still visible on their pins. The data in pad is left unused
+//! in the meantime, and anything arriving on it is ignored. A [`Duplex`]
+//! [`Pads`] is still required, so that the [`Spi`] has the capability to read.
+//!
+//! # Shared buses
+//!
+//! In [`Slave`] mode, the SS pad is mandatory, and the SERCOM only drives the
+//! MISO pin while SS is asserted. Otherwise, MISO is left in a high-impedance
+//! state, so several slaves can share the same MISO line without any extra
+//! pin management.
+//!
+//! In [`Master`] and [`MasterHWSS`] modes, the SERCOM drives MOSI and SCK for
+//! as long as the [`Spi`] is enabled. On a bus with several masters,
+//! [`disable`](Spi::disable) the [`Spi`] and return the pins to a floating
+//! input mode while another master owns the bus.
+//!
 //! # [`PanicOnRead`] and [`PanicOnWrite`]
 //!
 //! Some driver libraries take a type implementing [`embedded_hal::spi::SpiBus`]
@@ -292,6 +461,37 @@
 //! spi.write(&mut buffer)?;
 //! ```
 //!
+//! ## Sharing a single DMA channel
+//!
+//! A DMA read needs two channels, because every word clocked in requires a
+//! word to be written at the same time. Many protocols, such as SD cards and
+//! displays, mostly write commands and data, and only read short responses.
+//! For those, a [`Duplex`] master [`Spi`] can be given a single TX channel
+//! with [`Spi::with_tx_channel`]. Writes then use DMA, while reads and
+//! transfers fall back to word-by-word transfers. Each write waits for the
+//! bus to be idle and discards the words received in the meantime, so that
+//! the following read starts with the response.
+//!
+//! When the [`Spi`] is wrapped in a
+//! [`SpiDevice`](crate::ehal::spi::SpiDevice), such as [`CsTimingDevice`],
+//! the chip select stays asserted between the write and read operations of a
+//! transaction. Reads are slower than with two channels: the CPU is busy
+//! for the whole read, and a short gap is left between words. Measure the
+//! trade-off for your bus speed with the `spi_single_dma_channel` example of
+//! the `metro_m4` BSP.
+//!
+//! ```
+//! let mut device = spi
+//!     .with_tx_channel(channel0)
+//!     .with_cs_timing(cs, delay, 0.nanos(), 0.nanos())?;
+//!
+//! let mut response = [0; 512];
+//! device.transaction(&mut [
+//!     Operation::Write(&command),
+//!     Operation::Read(&mut response),
+//! ])?;
+//! ```
+//!
 //! # `async` operation <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
 //!
 //! An [`Spi`] can be used for `async` operations. Configuring a [`Spi`] in
@@ -498,6 +698,9 @@ pub mod lengths {
 
 pub mod impl_ehal;
 
+mod cs_timing;
+pub use cs_timing::*;
+
 #[cfg(feature = "async")]
 mod async_api;
 #[cfg(feature = "async")]
@@ -509,7 +712,7 @@ pub use async_api::*;
 
 /// Define the bit order of transactions
 #[repr(u8)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum BitOrder {
     LsbFirst,
@@ -535,7 +738,7 @@ bitflags! {
     /// The available interrupt flags are `DRE`, `RXC`, `TXC`, `SSL` and
     /// `ERROR`. The binary format of the underlying bits exactly matches the
     /// `INTFLAG` register.
-    #[derive(Clone, Copy)]
+    #[derive(Debug, Clone, Copy)]
     pub struct Flags: u8 {
         const DRE = DRE;
         const TXC = TXC;
@@ -622,6 +825,14 @@ pub trait OpMode: Sealed {
     const MODE: Modeselect;
     /// Bit indicating whether hardware `SS` control is enabled
     const MSSEN: bool;
+    /// Smallest supported ratio of the GCLK frequency to the SCK frequency
+    ///
+    /// In [`Master`] modes, this is the fastest setting of the `BAUD`
+    /// register, so SCK runs at up to half the GCLK frequency. In [`Slave`]
+    /// mode, SCK is generated by the master, and its maximum frequency is
+    /// given by the SPI timing characteristics of the datasheet. This module
+    /// uses a quarter of the GCLK frequency as a conservative bound.
+    const MAX_CLOCK_DIVIDER: u32;
 }
 
 /// [`OpMode`] variant for Master mode
@@ -640,16 +851,19 @@ impl Sealed for Slave {}
 impl OpMode for Master {
     const MODE: Modeselect = Modeselect::SpiMaster;
     const MSSEN: bool = false;
+    const MAX_CLOCK_DIVIDER: u32 = 2;
 }
 
 impl OpMode for MasterHWSS {
     const MODE: Modeselect = Modeselect::SpiMaster;
     const MSSEN: bool = true;
+    const MAX_CLOCK_DIVIDER: u32 = 2;
 }
 
 impl OpMode for Slave {
     const MODE: Modeselect = Modeselect::SpiSlave;
     const MSSEN: bool = false;
+    const MAX_CLOCK_DIVIDER: u32 = 4;
 }
 
 /// Marker trait for Master operating modes
@@ -1027,11 +1241,15 @@ where
         self.regs.get_bit_order()
     }
 
-    /// Set the bit order of transmission (MSB/LSB first) using the builder
-    /// pattern
+    /// Set the bit order of transmission (MSB/LSB first)
     ///
     /// This only affects the order of bits within each byte. Bytes are always
     /// transferred in little endian order from the 32-bit DATA register.
+    ///
+    /// `CTRLA.DORD` is enable-protected. A [`Config`] is always disabled, so
+    /// the bit order can be set freely. To change it on an enabled [`Spi`],
+    /// use [`Spi::reconfigure`], which disables and re-enables the peripheral.
+    /// The reset value is [`BitOrder::MsbFirst`].
     #[inline]
     pub fn set_bit_order(&mut self, order: BitOrder) {
         self.regs.set_bit_order(order);
@@ -1041,7 +1259,9 @@ where
     /// pattern
     ///
     /// This only affects the order of bits within each byte. Bytes are always
-    /// transferred in little endian order from the 32-bit DATA register.
+    /// transferred in little endian order from the 32-bit DATA register. See
+    /// [`set_bit_order`](Self::set_bit_order) for changing it on an enabled
+    /// [`Spi`].
     #[inline]
     pub fn bit_order(mut self, order: BitOrder) -> Self {
         self.set_bit_order(order);
@@ -1081,14 +1301,33 @@ where
         self.regs.get_baud(self.freq)
     }
 
+    /// Get the maximum baud rate in the current [`OpMode`]
+    ///
+    /// This is the GCLK frequency divided by
+    /// [`OpMode::MAX_CLOCK_DIVIDER`].
+    #[inline]
+    pub fn max_baud(&self) -> Hertz {
+        self.freq / M::MAX_CLOCK_DIVIDER
+    }
+
     /// Set the baud rate
     ///
     /// This function will calculate the best BAUD register setting based on the
     /// stored GCLK frequency and desired baud rate. The maximum baud rate is
-    /// half the GCLK frequency. The minimum baud rate is the GCLK frequency /
-    /// 512. Values outside this range will saturate at the extremes.
+    /// given by [`max_baud`](Self::max_baud). The minimum baud rate is the GCLK
+    /// frequency / 512. Values outside this range will saturate at the
+    /// extremes.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above [`max_baud`](Self::max_baud).
     #[inline]
     pub fn set_baud(&mut self, baud: Hertz) {
+        debug_assert!(
+            baud <= self.max_baud(),
+            "SPI baud rate above GCLK / {}",
+            M::MAX_CLOCK_DIVIDER
+        );
         self.regs.set_baud(self.freq, baud);
     }
 
@@ -1096,8 +1335,13 @@ where
     ///
     /// This function will calculate the best BAUD register setting based on the
     /// stored GCLK frequency and desired baud rate. The maximum baud rate is
-    /// half the GCLK frequency. The minimum baud rate is the GCLK frequency /
-    /// 512. Values outside this range will saturate at the extremes.
+    /// given by [`max_baud`](Self::max_baud). The minimum baud rate is the GCLK
+    /// frequency / 512. Values outside this range will saturate at the
+    /// extremes.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above [`max_baud`](Self::max_baud).
     #[inline]
     pub fn baud(mut self, baud: Hertz) -> Self {
         self.set_baud(baud);
@@ -1155,6 +1399,81 @@ where
         self
     }
 
+    /// Read the enable state of run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.regs.get_run_during_debug()
+    }
+
+    /// Enable or disable run during debug mode
+    ///
+    /// When disabled, the baud-rate generator is halted while the CPU is
+    /// halted by a debugger, so that no transaction makes progress past a
+    /// breakpoint. The SPI peripheral runs during debug by default.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, enabled: bool) {
+        self.regs.set_run_during_debug(enabled);
+    }
+
+    /// Enable or disable run during debug mode using the builder API
+    #[inline]
+    pub fn run_during_debug(mut self, enabled: bool) -> Self {
+        self.set_run_during_debug(enabled);
+        self
+    }
+
+    /// Read the enable state of internal loopback
+    #[inline]
+    pub fn get_loopback(&self) -> bool {
+        self.regs.get_dipo() == self.regs.data_out_pad()
+    }
+
+    /// Enable or disable internal loopback
+    ///
+    /// When enabled, data in is sampled from the data out pad instead of the
+    /// data in pad, so every word sent is also received. See the
+    /// [Loopback](self#loopback) section of the module documentation.
+    #[inline]
+    pub fn set_loopback(&mut self, enabled: bool) {
+        let dipo = if enabled {
+            self.regs.data_out_pad()
+        } else {
+            P::DIPO_DOPO.0
+        };
+        self.regs.set_dipo(dipo);
+    }
+
+    /// Enable or disable internal loopback using the builder API
+    #[inline]
+    pub fn loopback(mut self, enabled: bool) -> Self {
+        self.set_loopback(enabled);
+        self
+    }
+
+    /// Get a [`ConfigSummary`] of the current settings
+    ///
+    /// The settings are read back from the peripheral, so the summary reflects
+    /// what the hardware actually does.
+    #[inline]
+    #[hal_macro_helper]
+    pub fn config_summary(&self) -> ConfigSummary {
+        ConfigSummary {
+            baud: self.regs.get_baud(self.freq),
+            mode: self.get_spi_mode(),
+            bit_order: self.get_bit_order(),
+            dipo: self.regs.get_dipo(),
+            dopo: self.regs.get_dopo(),
+            #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+            char_size: match self.regs.get_char_size() {
+                1 => 9,
+                _ => 8,
+            },
+            #[hal_cfg("sercom0-d5x")]
+            length: self.regs.get_length(),
+            interrupts: self.regs.read_interrupts(),
+        }
+    }
+
     /// Enable the SPI peripheral
     ///
     /// SPI transactions are not possible until the peripheral is enabled.
@@ -1209,6 +1528,45 @@ where
     }
 }
 
+//=============================================================================
+// ConfigSummary
+//=============================================================================
+
+/// Snapshot of the human-relevant settings of an SPI peripheral
+///
+/// Returned by [`Config::config_summary`] and [`Spi::config_summary`]. It is
+/// meant to be printed during bring-up, rather than decoding the register
+/// values by hand.
+#[hal_macro_helper]
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigSummary {
+    /// Actual baud rate, including the rounding of the `BAUD` register
+    pub baud: Hertz,
+    /// Clock polarity and phase
+    pub mode: ehal::spi::Mode,
+    /// Bit order
+    pub bit_order: BitOrder,
+    /// Contents of the `CTRLA.DIPO` field
+    pub dipo: u8,
+    /// Contents of the `CTRLA.DOPO` field
+    pub dopo: u8,
+    /// Character size, in bits
+    #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+    pub char_size: u8,
+    /// Contents of the `LENGTH` register, i.e. the transaction length in bytes
+    #[hal_cfg("sercom0-d5x")]
+    pub length: u8,
+    /// Enabled interrupts
+    pub interrupts: Flags,
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ConfigSummary {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", defmt::Debug2Format(self));
+    }
+}
+
 //=============================================================================
 // AnyConfig
 //=============================================================================
@@ -1406,6 +1764,12 @@ where
         self.config.as_mut().regs.enable();
     }
 
+    /// Get a [`ConfigSummary`] of the live configuration
+    #[inline]
+    pub fn config_summary(&self) -> ConfigSummary {
+        self.config.as_ref().config_summary()
+    }
+
     /// Enable interrupts for the specified flags
     #[inline]
     pub fn enable_interrupts(&mut self, flags: Flags) {
@@ -1481,6 +1845,12 @@ where
     }
 
     /// Disable the SPI peripheral and return the [`Config`] struct
+    ///
+    /// The [`Config`] keeps every setting and the [`Pads`], so calling
+    /// [`Config::enable`] on it resumes the same `Spi`, for example after
+    /// sleeping. Ongoing transactions are cut short, so
+    /// [`flush`](crate::ehal::spi::SpiBus::flush) the bus first. DMA channels
+    /// are not held by the [`Config`], and must be taken out beforehand.
     #[inline]
     pub fn disable(mut self) -> C {
         self.config.as_mut().regs.rx_disable();
@@ -1661,6 +2031,32 @@ where
     }
 }
 
+#[cfg(feature = "dma")]
+impl<C> Spi<C, Duplex>
+where
+    C: ValidConfig,
+    C::OpMode: MasterMode,
+{
+    /// Attach a single TX DMA channel to this [`Spi`]. Its
+    /// [`SpiBus`](crate::ehal::spi::SpiBus) implementation will use DMA for
+    /// writes, and carry out reads and transfers word by word.
+    ///
+    /// This suits write-then-read protocols when DMA channels are scarce. See
+    /// the [module-level documentation](self#sharing-a-single-dma-channel).
+    #[cfg(feature = "dma")]
+    pub fn with_tx_channel<T>(self, tx: T) -> Spi<C, Duplex, NoneT, T>
+    where
+        T: crate::dmac::AnyChannel<Status = crate::dmac::Ready>,
+    {
+        Spi {
+            capability: self.capability,
+            config: self.config,
+            _rx_channel: NoneT,
+            _tx_channel: tx,
+        }
+    }
+}
+
 #[cfg(feature = "dma")]
 impl<C, D, R, T, S> Spi<C, D, R, T>
 where
@@ -1712,8 +2108,8 @@ impl<C: ValidConfig, R, T> AsMut<Spi<C, Rx, R, T>> for PanicOnWrite<Spi<C, Rx, R
     }
 }
 
-impl<C: ValidConfig, R, T> Spi<C, Tx, R, T> {
-    /// Turn a [`Tx`] [`Spi`] into a [`PanicOnWrite`]
+impl<C: ValidConfig, R, T> Spi<C, Rx, R, T> {
+    /// Turn a [`Rx`] [`Spi`] into a [`PanicOnWrite`]
     pub fn into_panic_on_write(self) -> PanicOnWrite<Self> {
         PanicOnWrite(self)
     }
@@ -1749,7 +2145,7 @@ impl<C: ValidConfig, R, T> AsMut<Spi<C, Tx, R, T>> for PanicOnRead<Spi<C, Tx, R,
 }
 
 impl<C: ValidConfig, R, T> Spi<C, Tx, R, T> {
-    /// Turn a [`Rx`] [`Spi`] into a [`PanicOnRead`]
+    /// Turn a [`Tx`] [`Spi`] into a [`PanicOnRead`]
     pub fn into_panic_on_read(self) -> PanicOnRead<Self> {
         PanicOnRead(self)
     }
@@ -22,6 +22,12 @@ pub use dma::*;
 use super::{Receive, Slave, Transmit};
 
 /// Interrupt handler for async SPI operarions
+///
+/// Bind it to the `SERCOMx` interrupt(s) of the SERCOM used by the
+/// [`SpiFuture`]. It wakes the task waiting to receive or to transmit,
+/// depending on the flags which fired. DMA transfers are completed by the
+/// `dmac::InterruptHandler`, which must be bound as well when DMA channels are
+/// used.
 pub struct InterruptHandler<S: Sercom> {
     _private: (),
     _sercom: PhantomData<S>,
@@ -0,0 +1,369 @@
+//! [`SpiDevice`] with chip select setup and hold delays
+
+use crate::ehal::delay::DelayNs;
+use crate::ehal::digital::OutputPin;
+use crate::ehal::spi::{self, ErrorKind, ErrorType, Operation, SpiBus, SpiDevice};
+use crate::time::Nanoseconds;
+
+use super::Spi;
+
+/// Error of a [`CsTimingDevice`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DeviceError<B, C> {
+    /// The SPI bus returned an error
+    Spi(B),
+    /// The chip select pin returned an error
+    Cs(C),
+}
+
+impl<B: spi::Error, C: core::fmt::Debug> spi::Error for DeviceError<B, C> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Spi(e) => e.kind(),
+            Self::Cs(_) => ErrorKind::ChipSelectFault,
+        }
+    }
+}
+
+/// An [`SpiDevice`] owning an SPI bus and a chip select pin, which waits for
+/// a configurable time after asserting the chip select, and before
+/// deasserting it
+///
+/// Some slaves need more time between the falling edge of their chip select
+/// and the first clock edge than the SPI peripheral leaves, for example to
+/// wake up from power down, or need their chip select held after the last
+/// clock edge. A `CsTimingDevice` inserts these delays around every
+/// transaction, using `D` as the delay source. The bus is always flushed
+/// before the hold delay starts, so the hold time is measured from the end
+/// of the last word.
+///
+/// The chip select stays asserted across all [`Operation`]s passed to a
+/// single [`SpiDevice::transaction`] call. [`Operation::DelayNs`] flushes the
+/// bus, then waits using the same delay source, so drivers can batch e.g. a
+/// command, a conversion delay and a read into one transaction.
+///
+/// A `CsTimingDevice` is created with [`Spi::with_cs_timing`], or with
+/// [`CsTimingDevice::new`] for any other [`SpiBus`].
+pub struct CsTimingDevice<B, CS, D> {
+    bus: B,
+    cs: CS,
+    delay: D,
+    setup: Nanoseconds,
+    hold: Nanoseconds,
+}
+
+impl<B, CS, D> CsTimingDevice<B, CS, D>
+where
+    CS: OutputPin,
+    D: DelayNs,
+{
+    /// Create a new `CsTimingDevice`, and deassert the chip select
+    ///
+    /// `setup` is the delay between asserting the chip select and the start
+    /// of the transaction, and `hold` is the delay between the end of the
+    /// transaction and deasserting the chip select.
+    #[inline]
+    pub fn new(
+        bus: B,
+        mut cs: CS,
+        delay: D,
+        setup: Nanoseconds,
+        hold: Nanoseconds,
+    ) -> Result<Self, CS::Error> {
+        cs.set_high()?;
+        Ok(Self {
+            bus,
+            cs,
+            delay,
+            setup,
+            hold,
+        })
+    }
+
+    /// Change the setup and hold delays
+    #[inline]
+    pub fn set_cs_timing(&mut self, setup: Nanoseconds, hold: Nanoseconds) {
+        self.setup = setup;
+        self.hold = hold;
+    }
+
+    /// Get the setup and hold delays
+    #[inline]
+    pub fn get_cs_timing(&self) -> (Nanoseconds, Nanoseconds) {
+        (self.setup, self.hold)
+    }
+
+    /// Get a mutable reference to the underlying bus
+    ///
+    /// The bus can be used directly between transactions, since the chip
+    /// select is deasserted.
+    #[inline]
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Get a mutable reference to the delay source
+    ///
+    /// This allows reusing the delay source of the device for other delays.
+    #[inline]
+    pub fn delay_mut(&mut self) -> &mut D {
+        &mut self.delay
+    }
+
+    /// Return the underlying bus, chip select pin and delay source
+    #[inline]
+    pub fn free(self) -> (B, CS, D) {
+        (self.bus, self.cs, self.delay)
+    }
+}
+
+impl<B, CS, D> ErrorType for CsTimingDevice<B, CS, D>
+where
+    B: ErrorType,
+    CS: OutputPin,
+{
+    type Error = DeviceError<B::Error, CS::Error>;
+}
+
+impl<W, B, CS, D> SpiDevice<W> for CsTimingDevice<B, CS, D>
+where
+    W: Copy + 'static,
+    B: SpiBus<W>,
+    CS: OutputPin,
+    D: DelayNs,
+{
+    fn transaction(&mut self, operations: &mut [Operation<'_, W>]) -> Result<(), Self::Error> {
+        self.cs.set_low().map_err(DeviceError::Cs)?;
+        self.delay.delay_ns(self.setup.to_nanos());
+
+        let result = operations.iter_mut().try_for_each(|op| match op {
+            Operation::Read(words) => self.bus.read(words),
+            Operation::Write(words) => self.bus.write(words),
+            Operation::Transfer(read, write) => self.bus.transfer(read, write),
+            Operation::TransferInPlace(words) => self.bus.transfer_in_place(words),
+            Operation::DelayNs(ns) => {
+                self.bus.flush()?;
+                self.delay.delay_ns(*ns);
+                Ok(())
+            }
+        });
+
+        // Deassert the chip select even if the transaction failed
+        let flush = self.bus.flush();
+        self.delay.delay_ns(self.hold.to_nanos());
+        let cs = self.cs.set_high();
+
+        result.map_err(DeviceError::Spi)?;
+        flush.map_err(DeviceError::Spi)?;
+        cs.map_err(DeviceError::Cs)
+    }
+}
+
+impl<C, A, RxDma, TxDma> Spi<C, A, RxDma, TxDma>
+where
+    C: super::ValidConfig,
+    A: super::Capability,
+{
+    /// Combine this bus with a chip select pin into a [`CsTimingDevice`]
+    ///
+    /// The chip select is asserted `setup` before every transaction starts,
+    /// and deasserted `hold` after it ends, using `delay` to wait.
+    #[inline]
+    pub fn with_cs_timing<CS, D>(
+        self,
+        cs: CS,
+        delay: D,
+        setup: Nanoseconds,
+        hold: Nanoseconds,
+    ) -> Result<CsTimingDevice<Self, CS, D>, CS::Error>
+    where
+        CS: OutputPin,
+        D: DelayNs,
+    {
+        CsTimingDevice::new(self, cs, delay, setup, hold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+    use core::convert::Infallible;
+    use fugit::ExtU32;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Event {
+        CsLow,
+        CsHigh,
+        Delay(u32),
+        Write(u8),
+        Read(usize),
+        Transfer(usize),
+        Flush,
+    }
+
+    type Log = RefCell<heapless::Vec<Event, 16>>;
+
+    struct MockBus<'a>(&'a Log, bool);
+    struct MockCs<'a>(&'a Log);
+    struct MockDelay<'a>(&'a Log);
+
+    fn push(log: &Log, event: Event) {
+        log.borrow_mut().push(event).unwrap();
+    }
+
+    impl ErrorType for MockBus<'_> {
+        type Error = ErrorKind;
+    }
+
+    impl SpiBus for MockBus<'_> {
+        fn read(&mut self, words: &mut [u8]) -> Result<(), ErrorKind> {
+            push(self.0, Event::Read(words.len()));
+            words.fill(0x5A);
+            Ok(())
+        }
+        fn write(&mut self, words: &[u8]) -> Result<(), ErrorKind> {
+            if self.1 {
+                return Err(ErrorKind::Overrun);
+            }
+            words.iter().for_each(|&w| push(self.0, Event::Write(w)));
+            Ok(())
+        }
+        fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), ErrorKind> {
+            if self.1 {
+                return Err(ErrorKind::Overrun);
+            }
+            push(self.0, Event::Transfer(read.len().max(write.len())));
+            read.fill(0x5A);
+            Ok(())
+        }
+        fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), ErrorKind> {
+            if self.1 {
+                return Err(ErrorKind::Overrun);
+            }
+            push(self.0, Event::Transfer(words.len()));
+            words.fill(0x5A);
+            Ok(())
+        }
+        fn flush(&mut self) -> Result<(), ErrorKind> {
+            push(self.0, Event::Flush);
+            Ok(())
+        }
+    }
+
+    impl crate::ehal::digital::ErrorType for MockCs<'_> {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for MockCs<'_> {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            push(self.0, Event::CsLow);
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            push(self.0, Event::CsHigh);
+            Ok(())
+        }
+    }
+
+    impl DelayNs for MockDelay<'_> {
+        fn delay_ns(&mut self, ns: u32) {
+            push(self.0, Event::Delay(ns));
+        }
+    }
+
+    fn device(log: &Log, fail: bool) -> CsTimingDevice<MockBus<'_>, MockCs<'_>, MockDelay<'_>> {
+        let device = CsTimingDevice::new(
+            MockBus(log, fail),
+            MockCs(log),
+            MockDelay(log),
+            10.micros(),
+            500.nanos(),
+        )
+        .unwrap();
+        log.borrow_mut().clear();
+        device
+    }
+
+    #[test]
+    fn delays_surround_transaction() {
+        let log = Log::default();
+        device(&log, false).write(&[0xA5]).unwrap();
+        assert_eq!(
+            log.borrow().as_slice(),
+            [
+                Event::CsLow,
+                Event::Delay(10_000),
+                Event::Write(0xA5),
+                Event::Flush,
+                Event::Delay(500),
+                Event::CsHigh,
+            ]
+        );
+    }
+
+    #[test]
+    fn cs_released_on_error() {
+        let log = Log::default();
+        let result = device(&log, true).write(&[0xA5]);
+        assert_eq!(result, Err(DeviceError::Spi(ErrorKind::Overrun)));
+        assert_eq!(log.borrow().last(), Some(&Event::CsHigh));
+    }
+
+    #[test]
+    fn cs_held_across_operations() {
+        let log = Log::default();
+        let mut data = [0; 2];
+        device(&log, false)
+            .transaction(&mut [
+                Operation::Write(&[0x0B]),
+                Operation::DelayNs(2_000),
+                Operation::Read(&mut data),
+            ])
+            .unwrap();
+        assert_eq!(data, [0x5A; 2]);
+        assert_eq!(
+            log.borrow().as_slice(),
+            [
+                Event::CsLow,
+                Event::Delay(10_000),
+                Event::Write(0x0B),
+                Event::Flush,
+                Event::Delay(2_000),
+                Event::Read(2),
+                Event::Flush,
+                Event::Delay(500),
+                Event::CsHigh,
+            ]
+        );
+    }
+
+    #[test]
+    fn transfers_reach_bus() {
+        let log = Log::default();
+        let mut read = [0; 3];
+        let mut words = [0xC3; 2];
+        device(&log, false)
+            .transaction(&mut [
+                Operation::Transfer(&mut read, &[0x9F]),
+                Operation::TransferInPlace(&mut words),
+            ])
+            .unwrap();
+        assert_eq!(read, [0x5A; 3]);
+        assert_eq!(words, [0x5A; 2]);
+        assert_eq!(
+            log.borrow().as_slice(),
+            [
+                Event::CsLow,
+                Event::Delay(10_000),
+                Event::Transfer(3),
+                Event::Transfer(2),
+                Event::Flush,
+                Event::Delay(500),
+                Event::CsHigh,
+            ]
+        );
+    }
+}
@@ -9,9 +9,11 @@ use crate::sercom::dma::{
     SinkSourceBuffer,
 };
 
+use crate::typelevel::NoneT;
+
 use super::{
-    Capability, Config, DataWidth, Duplex, Error, MasterMode, OpMode, Receive, Sercom, Size, Slave,
-    Spi, Transmit, ValidConfig, ValidPads, Word,
+    exchange_in_place, Capability, Config, DataWidth, Duplex, Error, Flags, MasterMode, OpMode,
+    Receive, Sercom, Size, Slave, Spi, Status, Transmit, ValidConfig, ValidPads, Word,
 };
 
 impl<P, M, Z, D, R, T> Spi<Config<P, M, Z>, D, R, T>
@@ -289,6 +291,77 @@ where
     }
 }
 
+/// [`SpiBus`] implementation for [`Duplex`] [`Spi`]s with a single TX DMA
+/// channel.
+///
+/// Writes use DMA transfers. Reads and transfers are carried out word by
+/// word, since clocking in a word requires writing one at the same time.
+impl<P, M, S, C, T> SpiBus<Word<C>> for Spi<Config<P, M, C>, Duplex, NoneT, T>
+where
+    Config<P, M, C>: ValidConfig<Sercom = S>,
+    S: Sercom,
+    P: ValidPads,
+    M: MasterMode,
+    C: Size + 'static,
+    C::Word: PrimInt + AsPrimitive<DataWidth> + Beat,
+    DataWidth: AsPrimitive<C::Word>,
+    T: AnyChannel<Status = Ready>,
+{
+    #[inline]
+    fn read(&mut self, words: &mut [C::Word]) -> Result<(), Self::Error> {
+        self.read_word_by_word(words)
+    }
+
+    #[inline]
+    fn write(&mut self, words: &[C::Word]) -> Result<(), Self::Error> {
+        self.write_dma(words)?;
+        self.discard_rx();
+        Ok(())
+    }
+
+    #[inline]
+    fn transfer(&mut self, read: &mut [C::Word], write: &[C::Word]) -> Result<(), Self::Error> {
+        self.transfer_word_by_word(read, write)
+    }
+
+    #[inline]
+    fn transfer_in_place(&mut self, words: &mut [C::Word]) -> Result<(), Self::Error> {
+        exchange_in_place(words, |word| self.transfer_word_in_place(word))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Error> {
+        self.flush_tx();
+        Ok(())
+    }
+}
+
+impl<P, M, S, C, T> Spi<Config<P, M, C>, Duplex, NoneT, T>
+where
+    Config<P, M, C>: ValidConfig<Sercom = S>,
+    S: Sercom,
+    P: ValidPads,
+    M: MasterMode,
+    C: Size + 'static,
+    C::Word: PrimInt + AsPrimitive<DataWidth> + Beat,
+    DataWidth: AsPrimitive<C::Word>,
+    T: AnyChannel<Status = Ready>,
+{
+    /// Wait for the end of a DMA write, and drop any word received while the
+    /// receiver was being reenabled, so that the next read starts in sync
+    /// with the bus.
+    #[inline]
+    fn discard_rx(&mut self) {
+        self.flush_tx();
+        while self.read_flags().contains(Flags::RXC) {
+            unsafe {
+                self.read_data();
+            }
+        }
+        self.clear_status(Status::BUFOVF);
+    }
+}
+
 /// [`embedded_io::Write`] implementation for [`Transmit`] [`Spi`]s in either
 /// [`Slave`] or [`MasterMode`], using DMA transfers.
 impl<P, M, Z, D, R, T, S> embedded_io::Write for Spi<Config<P, M, Z>, D, R, T>
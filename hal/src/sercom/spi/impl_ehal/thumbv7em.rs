@@ -794,7 +794,10 @@ impl_blocking_spi_write_iter!(U1, U2, U3, U4);
 ///
 /// This function exists to avoid both code duplication and monomorphization
 /// bloat. It will take a `[u8]` and transfer it four bytes at a time.
-fn transfer_slice<'w>(sercom: &RegisterBlock, buf: &'w mut [u8]) -> Result<&'w [u8], Error> {
+pub(super) fn transfer_slice<'w>(
+    sercom: &RegisterBlock,
+    buf: &'w mut [u8],
+) -> Result<&'w [u8], Error> {
     let cells = core::cell::Cell::from_mut(buf).as_slice_of_cells();
     let mut to_send = cells.iter();
     let mut to_recv = cells.iter();
@@ -842,7 +845,7 @@ fn transfer_slice<'w>(sercom: &RegisterBlock, buf: &'w mut [u8]) -> Result<&'w [
 /// every DRE flag. If the `duplex` argument is true, it will read as many times
 /// as it writes. Otherwise, it will skip reading the `DATA` register entirely.
 /// If `duplex` is false, buffer overflow errors are ignored
-fn write_slice(sercom: &RegisterBlock, buf: &[u8], duplex: bool) -> Result<(), Error> {
+pub(super) fn write_slice(sercom: &RegisterBlock, buf: &[u8], duplex: bool) -> Result<(), Error> {
     let mut to_send = buf.iter();
     let mut to_recv: usize = to_send.len();
     while to_recv > 0 {
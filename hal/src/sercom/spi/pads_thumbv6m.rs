@@ -206,6 +206,23 @@ where
     CK: OptionalPad,
     SS: OptionalPad,
 {
+    /// Describe the SERCOM and pad of each role at run time
+    ///
+    /// See [`uart::Pads::describe`](crate::sercom::uart::Pads::describe) for
+    /// an example.
+    #[inline]
+    pub fn describe(&self) -> PadsDescription<4> {
+        PadsDescription::new(
+            S::NUM,
+            [
+                ("DI", <DI as OptionalPad>::DYN),
+                ("DO", <DO as OptionalPad>::DYN),
+                ("SCK", <CK as OptionalPad>::DYN),
+                ("SS", <SS as OptionalPad>::DYN),
+            ],
+        )
+    }
+
     /// Consume the [`Pads`] and return each individual
     /// [`Pin`](crate::gpio::Pin)
     #[inline]
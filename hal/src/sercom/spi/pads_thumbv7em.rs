@@ -227,6 +227,23 @@ where
         }
     }
 
+    /// Describe the SERCOM, IOSET and pad of each role at run time
+    ///
+    /// See [`uart::Pads::describe`](crate::sercom::uart::Pads::describe) for
+    /// an example.
+    #[inline]
+    pub fn describe(&self) -> PadsDescription<4> {
+        PadsDescription::new(
+            S::NUM,
+            [
+                ("DI", <DI as OptionalPad>::DYN),
+                ("DO", <DO as OptionalPad>::DYN),
+                ("SCK", <CK as OptionalPad>::DYN),
+                ("SS", <SS as OptionalPad>::DYN),
+            ],
+        )
+    }
+
     /// Consume the [`Pads`] and return each individual
     /// [`Pin`](crate::gpio::Pin)
     #[inline]
@@ -71,6 +71,47 @@ impl<S: Sercom> Registers<S> {
         });
     }
 
+    /// Select the pad sampled for data in
+    #[inline]
+    pub fn set_dipo(&mut self, dipo: u8) {
+        self.spi()
+            .ctrla()
+            .modify(|_, w| unsafe { w.dipo().bits(dipo) });
+    }
+
+    /// Get the pad sampled for data in
+    #[inline]
+    pub fn get_dipo(&self) -> u8 {
+        self.spi().ctrla().read().dipo().bits()
+    }
+
+    /// Get the DOPO setting
+    #[inline]
+    pub fn get_dopo(&self) -> u8 {
+        self.spi().ctrla().read().dopo().bits()
+    }
+
+    /// Get the pad driven for data out
+    #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+    #[inline]
+    pub fn data_out_pad(&self) -> u8 {
+        match self.spi().ctrla().read().dopo().bits() {
+            1 => 2,
+            2 => 3,
+            _ => 0,
+        }
+    }
+
+    /// Get the pad driven for data out
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn data_out_pad(&self) -> u8 {
+        match self.spi().ctrla().read().dopo().bits() {
+            2 => 3,
+            dopo => dopo,
+        }
+    }
+
     /// Configure the SPI operating mode
     ///
     /// For maximum flexibility, this module chooses to always operate in 32-bit
@@ -118,6 +159,13 @@ impl<S: Sercom> Registers<S> {
             .modify(|_, w| unsafe { w.chsize().bits(bits) });
     }
 
+    /// Get the character size
+    #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+    #[inline]
+    pub fn get_char_size(&self) -> u8 {
+        self.spi().ctrlb().read().chsize().bits()
+    }
+
     /// Get the clock polarity
     #[inline]
     pub fn get_cpol(&self) -> Polarity {
@@ -214,7 +262,7 @@ impl<S: Sercom> Registers<S> {
 
     /// Get the baud rate
     #[inline]
-    pub fn get_baud(&mut self, freq: Hertz) -> Hertz {
+    pub fn get_baud(&self, freq: Hertz) -> Hertz {
         let baud = self.spi().baud().read().baud().bits() as u32 + 1;
         freq / 2 / baud
     }
@@ -222,9 +270,7 @@ impl<S: Sercom> Registers<S> {
     /// Set the baud rate
     #[inline]
     pub fn set_baud(&mut self, freq: Hertz, baud: Hertz) {
-        let baud = baud.to_Hz().max(1);
-        let bits = (freq.to_Hz() / 2 / baud).saturating_sub(1);
-        let bits = bits.try_into().unwrap_or(u8::MAX);
+        let bits = baud_bits(freq, baud);
         self.spi()
             .baud()
             .modify(|_, w| unsafe { w.baud().bits(bits) });
@@ -254,6 +300,18 @@ impl<S: Sercom> Registers<S> {
         self.spi().ctrla().modify(|_, w| w.runstdby().bit(set));
     }
 
+    /// Get run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.spi().dbgctrl().read().dbgstop().bit_is_clear()
+    }
+
+    /// Set run during debug mode
+    #[inline]
+    pub fn set_run_during_debug(&mut self, set: bool) {
+        self.spi().dbgctrl().write(|w| w.dbgstop().bit(!set));
+    }
+
     /// Enable interrupts for the specified flags
     #[inline]
     pub fn enable_interrupts(&mut self, flags: Flags) {
@@ -262,6 +320,13 @@ impl<S: Sercom> Registers<S> {
             .write(|w| unsafe { w.bits(flags.bits()) });
     }
 
+    /// Read the enabled interrupts
+    #[inline]
+    pub fn read_interrupts(&self) -> Flags {
+        let bits = self.spi().intenset().read().bits();
+        Flags::from_bits_truncate(bits)
+    }
+
     /// Disable interrupts for the specified flags
     #[inline]
     pub fn disable_interrupts(&mut self, flags: Flags) {
@@ -344,3 +409,39 @@ impl<S: Sercom> Registers<S> {
         Ok(self.read_flags())
     }
 }
+
+/// Calculate the contents of the `BAUD` register for a baud rate of `baud`,
+/// with a GCLK frequency of `freq`
+///
+/// Baud rates outside of the achievable range saturate.
+#[inline]
+pub(super) fn baud_bits(freq: Hertz, baud: Hertz) -> u8 {
+    let baud = baud.to_Hz().max(1);
+    let bits = (freq.to_Hz() / 2 / baud).saturating_sub(1);
+    bits.try_into().unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Master, OpMode, Slave};
+    use super::*;
+
+    #[test]
+    fn master_baud_rate_limit() {
+        let freq = Hertz::MHz(48);
+        let max = freq / Master::MAX_CLOCK_DIVIDER;
+        assert_eq!(max, Hertz::MHz(24));
+        // The maximum is the fastest setting of the `BAUD` register
+        assert_eq!(baud_bits(freq, max), 0);
+        assert_eq!(baud_bits(freq, max / 2), 1);
+        // Faster rates saturate
+        assert_eq!(baud_bits(freq, max + Hertz::Hz(1)), 0);
+    }
+
+    #[test]
+    fn slave_baud_rate_limit() {
+        let freq = Hertz::MHz(48);
+        assert_eq!(freq / Slave::MAX_CLOCK_DIVIDER, Hertz::MHz(12));
+        const _: () = assert!(Slave::MAX_CLOCK_DIVIDER > Master::MAX_CLOCK_DIVIDER);
+    }
+}
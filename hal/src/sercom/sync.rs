@@ -0,0 +1,126 @@
+//! Register synchronization
+
+use bitflags::bitflags;
+
+use crate::pac::sercom0::RegisterBlock;
+
+bitflags! {
+    /// Bits of the `SYNCBUSY` register
+    ///
+    /// Some SERCOM registers live in the peripheral clock domain. Writing to
+    /// them starts a synchronization, during which the corresponding bit is
+    /// set, and the new value doesn't take effect until it clears. The
+    /// `SYNCBUSY` register is at the same address in every SERCOM mode, but
+    /// not every bit exists in every mode. Bits which don't exist in the
+    /// current mode always read as zero.
+    ///
+    /// The [`uart`](super::uart), [`spi`](super::spi) and [`i2c`](super::i2c)
+    /// drivers already wait where necessary. Code which accesses a SERCOM
+    /// through the PAC should call
+    /// [`Sercom::wait_syncbusy`](super::Sercom::wait_syncbusy) after:
+    ///
+    /// * Writing `CTRLA.SWRST` or `CTRLA.ENABLE`, in every mode
+    /// * Writing `CTRLB` while the peripheral is enabled, in USART and SPI
+    ///   modes
+    /// * Writing `CTRLB.CMD`, `STATUS.BUSSTATE`, `ADDR` or `DATA`, in I2C
+    ///   master mode
+    /// * Writing `LENGTH`, in the modes which have it (SAMx5x only)
+    /// * Reading `RXERRCNT`, in USART mode (SAMx5x only)
+    ///
+    /// Enable-protected registers, such as `CTRLA` and `BAUD`, can only be
+    /// written while `CTRLA.ENABLE` is cleared. Disabling the peripheral is
+    /// itself synchronized, so wait for [`SyncBusy::ENABLE`] to clear before
+    /// writing them.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SyncBusy: u32 {
+        /// Software reset (`CTRLA.SWRST`)
+        const SWRST = 0x01;
+        /// Peripheral enable (`CTRLA.ENABLE`)
+        const ENABLE = 0x02;
+        /// `CTRLB` (USART and SPI modes)
+        const CTRLB = 0x04;
+        /// System operation (I2C master mode)
+        ///
+        /// This is the same bit as [`CTRLB`](Self::CTRLB).
+        const SYSOP = 0x04;
+        /// `RXERRCNT` (USART mode, SAMx5x only)
+        const RXERRCNT = 0x08;
+        /// `LENGTH` (USART, SPI and I2C slave modes, SAMx5x only)
+        const LENGTH = 0x10;
+    }
+}
+
+/// Read the `SYNCBUSY` register of `sercom`, whatever its mode
+#[inline]
+pub(super) fn read_syncbusy(sercom: &RegisterBlock) -> SyncBusy {
+    // `SYNCBUSY` has the same address in every mode, so any view of the
+    // register block can be used to read it
+    from_bits(sercom.i2cm().syncbusy().read().bits())
+}
+
+/// Convert a raw `SYNCBUSY` value, dropping the reserved bits
+#[inline]
+fn from_bits(bits: u32) -> SyncBusy {
+    SyncBusy::from_bits_truncate(bits)
+}
+
+/// Spin until none of the `mask` bits are set in the `SYNCBUSY` register of
+/// `sercom`
+#[inline]
+pub(super) fn wait_syncbusy(sercom: &RegisterBlock, mask: SyncBusy) {
+    spin_while_busy(|| read_syncbusy(sercom), mask);
+}
+
+/// Call `read` until none of the `mask` bits are set in the value it returns
+#[inline]
+fn spin_while_busy(mut read: impl FnMut() -> SyncBusy, mask: SyncBusy) {
+    while read().intersects(mask) {}
+}
+
+/// Issue a software reset of `sercom`, and wait for it to complete
+#[inline]
+pub(super) fn reset(sercom: &RegisterBlock) {
+    // `CTRLA.SWRST` has the same position in every mode
+    sercom.i2cm().ctrla().write(|w| w.swrst().set_bit());
+    wait_syncbusy(sercom, SyncBusy::SWRST);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Readings of `SYNCBUSY` over time, with the number of reads made
+    fn readings<'a>(values: &'a [u32], reads: &'a mut usize) -> impl FnMut() -> SyncBusy + 'a {
+        move || {
+            let value = values[*reads];
+            *reads += 1;
+            from_bits(value)
+        }
+    }
+
+    #[test]
+    fn reserved_bits_are_dropped() {
+        assert_eq!(from_bits(0x02), SyncBusy::ENABLE);
+        assert_eq!(from_bits(0xFFFF_FF02), SyncBusy::ENABLE);
+        assert!(from_bits(0).is_empty());
+    }
+
+    #[test]
+    fn spin_until_masked_bits_clear() {
+        let mut reads = 0;
+        spin_while_busy(readings(&[0x03, 0x02, 0x00], &mut reads), SyncBusy::ENABLE);
+        assert_eq!(reads, 3);
+    }
+
+    #[test]
+    fn spin_ignores_unrelated_bits() {
+        // Only the `ENABLE` synchronization is still in progress, so waiting
+        // for `SWRST` and `CTRLB` must return after a single read
+        let mut reads = 0;
+        spin_while_busy(
+            readings(&[0x02], &mut reads),
+            SyncBusy::SWRST | SyncBusy::CTRLB,
+        );
+        assert_eq!(reads, 1);
+    }
+}
@@ -70,6 +70,23 @@
 //!     .tx(pins.pa08);
 //! ```
 //!
+//! When the `Pads` type is already named, for example with a [`PadsFromIds`]
+//! alias, [`Pads::new`] builds it in a single call instead. It takes the pin
+//! of every role, in any [`PinMode`], and [`NoneT`] for the unused roles.
+//!
+//! ```
+//! use atsamd_hal::pac::Peripherals;
+//! use atsamd_hal::gpio::{PA08, PA09, Pins};
+//! use atsamd_hal::sercom::{Sercom0, uart};
+//! use atsamd_hal::typelevel::NoneT;
+//!
+//! type Pads = uart::PadsFromIds<Sercom0, PA09, PA08>;
+//!
+//! let mut peripherals = Peripherals::take().unwrap();
+//! let pins = Pins::new(peripherals.port);
+//! let pads = Pads::new(pins.pa09, pins.pa08, NoneT, NoneT);
+//! ```
+//!
 //! To be accepted as [`ValidPads`], a set of [`Pads`] must do two things:
 //! - Specify a type for at least one of `RX` or `TX`
 //! - Satisfy the `RxpoTxpo` trait (SAMD11/SAMD21), or the `Rxpo` and `Txpo`
@@ -147,6 +164,70 @@
 //! To be accepted as a [`ValidConfig`], the [`Config`] must have at least one
 //! of `Rx` or `Tx` pads.
 //!
+//! ## Baud rate and oversampling
+//!
+//! The [`BaudMode`] passed to [`baud`](Config::baud) selects both how the
+//! `BAUD` register is computed, and how many times the receiver samples each
+//! bit. With a GCLK frequency `f` and `S` samples per bit, the achievable baud
+//! rates are:
+//!
+//! | Mode                       | Baud rate                    | Range                           |
+//! |----------------------------|------------------------------|---------------------------------|
+//! | [`Arithmetic`](BaudMode::Arithmetic) | `f / S * (1 - BAUD / 65536)` | `f / (S * 65536)` to `f / S`    |
+//! | [`Fractional`](BaudMode::Fractional) | `f / (S * (BAUD + FP / 8))`  | `f / (S * 8191.875)` to `f / S` |
+//!
+//! Arithmetic mode has a fine resolution at low baud rates, but a coarse one
+//! close to the maximum. Fractional mode has a resolution of an eighth of a
+//! clock period at every rate, which usually gives a lower error at high baud
+//! rates. Rates outside of the range saturate.
+//!
+//! [`Oversampling::Bits8`] doubles the maximum baud rate for a given GCLK
+//! frequency, at the cost of noise immunity. The receiver decides the value of
+//! each bit from the samples taken around its middle, so with fewer samples
+//! per bit, it tolerates less jitter and baud rate mismatch with the
+//! transmitter. Prefer [`Oversampling::Bits16`] unless the baud rate can't
+//! otherwise be reached, or its error would be larger. For example, with an
+//! 8 MHz GCLK, a 1 MBaud rate is only achievable with 8x oversampling.
+//!
+//! Which of the samples decide the value of a bit can be shifted later with
+//! [`sample_adjustment`](Config::sample_adjustment), which may help with a
+//! transmitter whose edges are slow, or whose baud rate is slightly too low.
+//! The default, [`SampleAdjustment::Centered`], suits most links.
+//!
+//! [`get_baud_rate`](Config::get_baud_rate) returns the baud rate actually
+//! configured, which can be compared to the requested one:
+//!
+//! ```
+//! # use atsamd_hal::sercom::uart::{BaudMode, Config, EightBit, Oversampling, ValidPads};
+//! # use atsamd_hal::fugit::RateExtU32;
+//! # fn example<P: ValidPads>(config: Config<P, EightBit>) {
+//! let config = config.baud(1.MHz(), BaudMode::Fractional(Oversampling::Bits8));
+//! let error = config.get_baud_rate().to_Hz().abs_diff(1_000_000);
+//! # }
+//! ```
+//!
+//! ## Baud rate detection
+//!
+//! When the host starts by sending a known [`SYNC_BYTE`] (`0x55`), the baud
+//! rate can be measured instead of configured. The RX pin is first read as a
+//! GPIO input, while [`capture_sync_edges`] timestamps the edges of the sync
+//! byte with a free-running counter. [`Config::baud_from_sync`] then computes
+//! the baud rate from these edges, and returns an
+//! [`AutoBaudError::OutOfRange`] error if the SERCOM can't generate it. To
+//! lock onto one of a few standard rates instead, pass the result of
+//! [`measure_sync_byte`] through a lookup, and configure it with
+//! [`baud`](Config::baud).
+//!
+//! The sync byte itself is consumed by the measurement, and is never received
+//! by the [`Uart`].
+//!
+//! When the sync byte follows a LIN break, the SERCOM can measure it in
+//! hardware instead. [`Config::into_auto_baud`] enables the peripheral in
+//! auto-baud mode, and returns an [`AutoBaudUart`]. Its
+//! [`wait_for_sync`](AutoBaudUart::wait_for_sync) method completes once a
+//! break and a sync byte have been measured, and returns the detected baud
+//! rate.
+//!
 //! ## [`CharSize`]
 //!
 //! The UART peripheral can be configured to use different character sizes. By
@@ -159,11 +240,21 @@
 //! [`set_dyn_char_size`](Config::set_dyn_char_size) method when calling
 //! [`reconfigure`](Uart::reconfigure).
 //!
+//! With [`NineBit`] or [`DynCharSize`], words are `u16`s, and the
+//! `embedded_hal_nb` [`Read`](embedded_hal_nb::serial::Read) and
+//! [`Write`](embedded_hal_nb::serial::Write) implementations transfer all nine
+//! bits. The ninth bit is bit 8 of the `DATA` register, so no special handling
+//! is needed. As usual, the error flags are checked before `DATA` is read. The
+//! byte-oriented [`embedded_io`] traits are only implemented for [`EightBit`].
+//!
 //! ## Reading the current configuration
 //!
 //! It is possible to read the current configuration by using the getter methods
 //! provided: for example [`get_baud`](Config::get_baud),
 //! [`get_stop_bits`](Config::get_stop_bits), etc.
+//! [`config_summary`](Config::config_summary) gathers the human-relevant
+//! settings, such as the actual baud rate, the frame format and the enabled
+//! interrupts, into a [`ConfigSummary`] which can be printed with `{:?}`.
 //!
 //! # [`Uart`] and capabilities
 //!
@@ -212,6 +303,71 @@
 //! block!(uart_tx.write(0x0fe));
 //! ```
 //!
+//! For simple cases, [`Uart`]s with 8-bit words also provide blocking
+//! [`write_byte`](Uart::write_byte),
+//! [`write_all_blocking`](Uart::write_all_blocking),
+//! [`read_byte`](Uart::read_byte) and
+//! [`read_exact_blocking`](Uart::read_exact_blocking) methods, which don't
+//! require the `nb` crate. The `_blocking` suffix keeps them apart from the
+//! [`embedded_io`] trait methods of the same name:
+//!
+//! ```
+//! uart_tx.write_all_blocking(b"Hello, world!")?;
+//! ```
+//!
+//! Every method and trait implementation that touches the RX or TX side of
+//! the peripheral is bounded by [`Receive`] or [`Transmit`] respectively.
+//! [`Duplex`] implements both. Calling a transmit method on a receive-only
+//! [`Uart`] is therefore a compile error, rather than a silent no-op:
+//!
+//! ```
+//! use atsamd_hal::ehal_nb::serial::{Read, Write};
+//! use atsamd_hal::sercom::uart::{Duplex, Uart, ValidConfig};
+//!
+//! fn echo<C: ValidConfig<Word = u8>>(uart: &mut Uart<C, Duplex>) {
+//!     if let Ok(word) = uart.read() {
+//!         let _ = uart.write(word);
+//!     }
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use atsamd_hal::ehal_nb::serial::Write;
+//! use atsamd_hal::sercom::uart::{Rx, Uart, ValidConfig};
+//!
+//! fn send<C: ValidConfig<Word = u8>>(uart: &mut Uart<C, Rx>) {
+//!     let _ = uart.write(0x42);
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use atsamd_hal::ehal_nb::serial::Read;
+//! use atsamd_hal::sercom::uart::{TxDuplex, Uart, ValidConfig};
+//!
+//! fn receive<C: ValidConfig<Word = u8>>(uart: &mut Uart<C, TxDuplex>) {
+//!     let _ = uart.read();
+//! }
+//! ```
+//!
+//! # Formatted output
+//!
+//! [`Uart`]s with 8-bit characters and a [`Transmit`] capability implement
+//! [`core::fmt::Write`], so they can be used with [`write!`] directly. Each
+//! byte blocks until the data register is empty, so no buffer or allocation
+//! is needed. Text is sent unchanged; [`Uart::crlf`] returns a [`CrLf`]
+//! adapter which translates every `\n` into the `\r\n` line ending most
+//! terminals expect.
+//!
+//! ```
+//! use core::fmt::Write;
+//! use atsamd_hal::sercom::uart::{Config, EightBit, Tx, Uart, ValidPads};
+//!
+//! fn log<P: ValidPads>(uart: &mut Uart<Config<P, EightBit>, Tx>, x: u32) -> core::fmt::Result {
+//!     write!(uart, "x = {}\r\n", x)?;
+//!     writeln!(uart.crlf(), "x = {}", x)
+//! }
+//! ```
+//!
 //! # UART flow control (CTS/RTS)
 //!
 //! This module supports CTS and RTS pins.
@@ -233,6 +389,17 @@
 //! note](https://www.silabs.com/documents/public/application-notes/an0059.0-uart-flow-control.pdf)
 //! provides more information about UART hardware flow control.
 //!
+//! # RS-485
+//!
+//! On SAMx5x chips, a UART can drive the driver-enable (`DE`, also called
+//! transmit-enable) input of an RS-485 transceiver. Set the enable pin with
+//! `Pads::de`, which takes the place of the `RTS` pad on `Pad2`, and leave the
+//! `CTS` pad empty. The SERCOM then drives the pin high before the first start
+//! bit of each transmission, and low again after the last stop bit. Use
+//! `Config::rs485` to hold it high for up to 7 additional bit times, to let
+//! the line settle before the transceiver switches back to receiving. RS-485
+//! mode is not available on SAMD11/SAMD21 chips.
+//!
 //! # Splitting
 //!
 //! A `Uart<C, Duplex>` can be split into its [`RxDuplex`] and [`TxDuplex`]
@@ -244,6 +411,23 @@
 //! let (rx, tx) = uart.split();
 //! ```
 //!
+//! Both halves alias the same SERCOM, but each one only touches its side of
+//! the peripheral. The [`RxDuplex`] half reads the `DATA` register, and can
+//! only read or clear the RX interrupt flags (`RXC`, `RXS`, `RXBRK`, `ERROR`)
+//! and the error status flags. The [`TxDuplex`] half writes the `DATA`
+//! register, and can only read or clear the TX interrupt flags (`DRE`,
+//! `TXC`). Each half may also toggle its own enable bit in `CTRLB`:
+//! [`reset_rx`](Uart::reset_rx) on the [`RxDuplex`] half, and
+//! [`release_tx`](Uart::release_tx), [`drive_tx`](Uart::drive_tx) and
+//! [`clear_collision`](Uart::clear_collision) on the [`TxDuplex`] half. These
+//! read-modify-writes run in a critical section, so they don't race with the
+//! other half. Neither half can change the configuration or disable the
+//! whole peripheral, which is why [`send_break`](Uart::send_break) isn't
+//! available on the [`TxDuplex`] half. The halves can thus be moved to
+//! different tasks or interrupt handlers, each with its own DMA channel.
+//! Reconfiguring requires both halves, either [joined](self#joining) back, or
+//! through the [`AsMut`] implementation below.
+//!
 //! # Joining
 //!
 //! When a `Uart<C, Duplex>` has been split into its [`RxDuplex`] and
@@ -320,6 +504,168 @@
 //! let config = uart.disable();
 //! ```
 //!
+//! A disabled [`Config`] can also be changed in place with
+//! [`Config::reconfigure`], which keeps the [`Pads`] and the [`CharSize`]. The
+//! [`Config`] can then be enabled again:
+//!
+//! ```
+//! let config = uart.disable().reconfigure(|c| c.set_stop_bits(StopBits::TwoBits));
+//! let uart = config.enable();
+//! ```
+//!
+//! Disabling is the first step of a full teardown, which gives back every
+//! resource passed in at creation. [`Config::free`] resets the SERCOM, and
+//! returns its PAC struct along with the [`Pads`], which in turn can be
+//! [`free`](Pads::free)d to recover the [`Pin`]s:
+//!
+//! ```
+//! // Uart -> Config -> (Sercom, Pads) -> Pins
+//! let (sercom, pads) = uart.disable().free();
+//! let (rx, tx, rts, cts) = pads.free();
+//! ```
+//!
+//! The SERCOM and pins can then be used to set up another driver, such as an
+//! [`spi::Config`](crate::sercom::spi::Config), without ever resorting to
+//! `steal()`. The `sercom_mode_switch` example of the `metro_m4` BSP switches a
+//! SERCOM between UART and SPI this way.
+//!
+//! ## Parking
+//!
+//! A disabled [`Config`] keeps every setting, and still owns the [`Pads`].
+//! A [`Uart`] can therefore be parked, for example while the chip sleeps, by
+//! calling [`disable`](Uart::disable), and resumed unchanged with
+//! [`Config::enable`]. Unlike [`reconfigure`](Uart::reconfigure), no setting
+//! is touched in between.
+//!
+//! Disabling stops the transmitter immediately, so flush the [`Uart`] first
+//! to let the last word go out. `disable` does not do it on its own, because
+//! the `TXC` flag is never set if nothing was sent since the [`Uart`] was
+//! enabled. Take the DMA channels out of the [`Uart`] before disabling it, as
+//! [`Config`] does not hold them.
+//!
+//! ```
+//! // Wait for the last word to be sent
+//! nb::block!(uart.flush())?;
+//! let config = uart.disable();
+//!
+//! // Sleep...
+//!
+//! let mut uart = config.enable();
+//! ```
+//!
+//! # Error recovery
+//!
+//! Receive errors, such as [`Error::Overflow`] or [`Error::FrameError`], are
+//! sticky: every read returns the same error until it is cleared. Call
+//! [`Uart::clear_errors`] after an error to discard the corrupted character
+//! and resume reading, or [`Uart::reset_rx`] to also drop every buffered
+//! character and resynchronize the receiver.
+//!
+//! ```no_run
+//! # use atsamd_hal::sercom::uart::{Config, Duplex, EightBit, Error, Uart, ValidPads};
+//! # fn example<P: ValidPads<Capability = Duplex>>(uart: &mut Uart<Config<P, EightBit>, Duplex>) {
+//! let byte = loop {
+//!     match uart.read_byte() {
+//!         Ok(byte) => break byte,
+//!         Err(Error::Overflow) => {
+//!             uart.clear_errors();
+//!         }
+//!         Err(_) => uart.reset_rx(),
+//!     }
+//! };
+//! # }
+//! ```
+//!
+//! ## Overflow notification
+//!
+//! By default, an overflow is reported in its place within the data stream:
+//! the characters received before the overflow are read first, and
+//! `Status::BUFOVF`, along with `Flags::ERROR`, is only set once the lost
+//! character would have reached the top of the receive buffer. Setting
+//! [`Config::immediate_overflow_notification`] (`CTRLA.IBON`) instead sets
+//! them as soon as the overflow happens, so that the error is seen by the very
+//! next read or interrupt. This lets error handling react sooner, at the cost
+//! of no longer knowing which of the buffered characters preceded the loss.
+//!
+//! # Loopback
+//!
+//! [`Config::loopback`] makes the receiver sample the TX pad instead of the RX
+//! pad, so that every character sent is also received. This allows testing
+//! protocol code on the target, without any external wiring:
+//!
+//! ```no_run
+//! # use atsamd_hal::sercom::uart::{Config, Duplex, EightBit, ValidPads};
+//! # use atsamd_hal::ehal_nb::serial::{Read, Write};
+//! # use atsamd_hal::nb;
+//! # fn self_test<P: ValidPads<Capability = Duplex>>(config: Config<P, EightBit>) {
+//! let mut uart = config.loopback(true).enable();
+//! nb::block!(uart.write(0x5A)).unwrap();
+//! assert_eq!(nb::block!(uart.read()).unwrap(), 0x5A);
+//! # }
+//! ```
+//!
+//! The loopback goes through the TX pad, so the transmitted signal is still
+//! visible on the TX pin. The RX pad is left unused in the meantime, and
+//! anything arriving on it is ignored. A Duplex [`Pads`] is required, so that
+//! the [`Uart`] has the capability to read, unless the [`Config`] is enabled
+//! with [`Config::enable_loopback`]. It takes transmit-only [`Pads`], so no
+//! RX pin is needed, and returns a [`Duplex`] [`Uart`] looped back on itself.
+//! A receive-only [`Pads`] can't be looped back, as the receiver can only
+//! sample a pad driven by the transmitter.
+//!
+//! # Line inversion
+//!
+//! On SAMx5x chips, the `invert_rx` and `invert_tx` methods of [`Config`]
+//! invert the levels of the RX and TX lines respectively, for boards which
+//! place an inverting transceiver between the pins and the bus. The inversion only
+//! applies to the data lines, the RTS and CTS lines are not affected.
+//!
+//! # Error injection
+//!
+//! Receive errors are hard to provoke on purpose, which makes the error
+//! handling of a protocol difficult to test on hardware. As a testing aid, the
+//! `error-injection` Cargo feature adds `Config::frame_error_loopback` on
+//! SAMx5x chips. It sets up a loopback with an inverted TX line, so that each
+//! transmitted character makes the receiver report an [`Error::FrameError`]:
+//!
+//! ```no_run
+//! # use atsamd_hal::sercom::uart::{Config, Duplex, EightBit, Error, ValidPads};
+//! # fn self_test<P: ValidPads<Capability = Duplex>>(config: Config<P, EightBit>) {
+//! let mut uart = config.frame_error_loopback(true).enable();
+//! uart.write_byte(0x00).unwrap();
+//! assert_eq!(uart.read_byte(), Err(Error::FrameError));
+//! uart.clear_errors();
+//!
+//! // Back to normal operation
+//! uart.reconfigure(|c| c.set_frame_error_loopback(false));
+//! # }
+//! ```
+//!
+//! This API is only meant for tests, and should not be enabled in production
+//! firmware.
+//!
+//! # Multi-drop lines
+//!
+//! The transmitter drives the TX pin high whenever it is idle, which prevents
+//! other nodes from talking on a shared line. [`Uart::release_tx`] disables
+//! the transmitter, leaving the TX pin as a high-impedance input, and
+//! [`Uart::drive_tx`] enables it again. A pull-up on the shared line keeps it
+//! at the idle level while no node drives it.
+//!
+//! ```no_run
+//! # use atsamd_hal::sercom::uart::{Config, Duplex, EightBit, Error, Uart, ValidPads};
+//! # use atsamd_hal::ehal_nb::serial::Write;
+//! # use atsamd_hal::nb;
+//! # fn example<P: ValidPads<Capability = Duplex>>(uart: &mut Uart<Config<P, EightBit>, Duplex>) -> Result<(), Error> {
+//! uart.drive_tx();
+//! uart.write_all_blocking(b"reply")?;
+//! nb::block!(uart.flush())?;
+//! // Let the other nodes talk
+//! uart.release_tx();
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Non-supported advanced features
 //!
 //! * Synchronous mode (USART) is not supported
@@ -381,6 +727,11 @@
 //! let (chan1, rx, rx_buffer) = rx_dma.wait();
 //! ```
 //!
+//! For continuous reception, [`receive_circular_dma`] starts a circular
+//! transfer into a ring buffer, which never completes. The returned
+//! [`CircularRx`] tracks the DMA write cursor, and lets the received words be
+//! consumed behind it.
+//!
 //! # `async` operation <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
 //!
 //! A [`Uart`] can be used for `async` operations. Configuring a [`Uart`] in
@@ -496,6 +847,26 @@
 //! As you can see, unsoundness is relatively hard to come by - however, caution
 //! should still be exercised.
 //!
+//! # Interrupt-driven buffering <span class="stab portability" title="Available on crate feature `async` only"><code>async</code></span>
+//!
+//! A [`Uart`] can also be turned into a [`BufferedUart`] by calling
+//! [`Uart::into_buffered`]. The SERCOM interrupt, bound to a
+//! [`BufferedInterruptHandler`], moves bytes between the peripheral and a pair
+//! of `'static` ring buffers. The non-blocking
+//! [`try_read`](BufferedUart::try_read) and
+//! [`try_write`](BufferedUart::try_write) methods then operate on those
+//! buffers, without requiring an `async` executor.
+//!
+//! ```
+//! // Assume uart is a Uart<C, Duplex>, and Irqs binds the SERCOM interrupt
+//! // source to uart::BufferedInterruptHandler
+//! let mut uart = uart.into_buffered(Irqs, rx_buffer, tx_buffer);
+//!
+//! let mut buf = [0; 16];
+//! let n = uart.try_read(&mut buf);
+//! let written = uart.try_write(&buf[..n]);
+//! ```
+//!
 //! [`enable`]: Config::enable
 //! [`disable`]: Uart::disable
 //! [`reconfigure`]: Uart::reconfigure
@@ -509,6 +880,8 @@
 //! [`NoneT`]: crate::typelevel::NoneT
 //! [`receive_with_dma`]: Uart::receive_with_dma
 //! [`send_with_dma`]: Uart::send_with_dma
+//! [`receive_circular_dma`]: Uart::receive_circular_dma
+//! [`CircularRx`]: crate::sercom::dma::CircularRx
 //! [`Transfer`]: crate::dmac::Transfer
 //! [`Channel`]: crate::dmac::Channel
 //! [`async_hal`]: crate::async_hal
@@ -534,6 +907,7 @@ mod pads {}
 pub use pads::*;
 
 mod reg;
+pub use reg::baud_error_ppm;
 use reg::Registers;
 
 mod charsize;
@@ -545,15 +919,30 @@ pub use flags::*;
 mod config;
 pub use config::*;
 
+mod autobaud;
+pub use autobaud::*;
+
+#[hal_cfg("sercom0-d5x")]
+mod lin;
+#[hal_cfg("sercom0-d5x")]
+pub use lin::*;
+
 pub mod impl_ehal;
 
+mod fmt;
+pub use fmt::*;
+
 #[cfg(feature = "async")]
 mod async_api;
 #[cfg(feature = "async")]
 pub use async_api::*;
 
+mod buffered;
+pub use buffered::*;
+
 use crate::{
     sercom::pad::SomePad,
+    time::Hertz,
     typelevel::{NoneT, Sealed},
 };
 use core::marker::PhantomData;
@@ -616,6 +1005,58 @@ pub enum Oversampling {
     Bits16 = 16,
 }
 
+/// Position of the samples that decide the value of a received bit
+///
+/// With 16x or 8x oversampling, the receiver takes a majority vote of three
+/// of the samples of each bit. The variants shift these samples from the
+/// middle of the bit towards its end. Sample numbers, counted from the start
+/// of the bit, are:
+///
+/// | Variant      | [`Bits16`](Oversampling::Bits16) | [`Bits8`](Oversampling::Bits8) |
+/// |--------------|----------------------------------|--------------------------------|
+/// | [`Centered`](Self::Centered) | 7, 8, 9   | 3, 4, 5 |
+/// | [`Late`](Self::Late)         | 9, 10, 11 | 4, 5, 6 |
+/// | [`Later`](Self::Later)       | 11, 12, 13 | 5, 6, 7 |
+/// | [`Latest`](Self::Latest)     | 13, 14, 15 | 6, 7, 8 |
+///
+/// [`Centered`](Self::Centered) is the reset value.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SampleAdjustment {
+    /// Samples in the middle of the bit
+    #[default]
+    Centered = 0,
+    /// Samples slightly after the middle of the bit
+    Late = 1,
+    /// Samples in the last third of the bit
+    Later = 2,
+    /// Samples at the end of the bit
+    Latest = 3,
+}
+
+impl SampleAdjustment {
+    /// Numbers of the samples that decide the value of a bit, counted from
+    /// the start of the bit
+    #[inline]
+    pub const fn samples(self, oversampling: Oversampling) -> [u8; 3] {
+        let first = match oversampling {
+            Oversampling::Bits16 => 7 + 2 * self as u8,
+            Oversampling::Bits8 => 3 + self as u8,
+        };
+        [first, first + 1, first + 2]
+    }
+
+    #[inline]
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0x3 {
+            0 => Self::Centered,
+            1 => Self::Late,
+            2 => Self::Later,
+            _ => Self::Latest,
+        }
+    }
+}
+
 /// Baudrate calculation in asynchronous mode
 #[derive(Debug, Clone, Copy)]
 pub enum BaudMode {
@@ -759,6 +1200,8 @@ where
     capability: PhantomData<D>,
     rx_channel: RxDma,
     tx_channel: TxDma,
+    /// Set when a word is written, and cleared once `TXC` is seen
+    tx_pending: bool,
 }
 
 impl<C, D, R, T> Uart<C, D, R, T>
@@ -787,6 +1230,12 @@ where
         status & Status::from_bits_retain(D::STATUS_MASK)
     }
 
+    /// Get a [`ConfigSummary`] of the live configuration
+    #[inline]
+    pub fn config_summary(&self) -> ConfigSummary {
+        self.config.as_ref().config_summary()
+    }
+
     /// Read the interrupt flags
     #[inline]
     pub fn read_flags(&self) -> Flags {
@@ -939,6 +1388,10 @@ where
     D: Simplex,
 {
     /// Disable the UART peripheral and return the underlying [`Config`]
+    ///
+    /// Ongoing transmissions are cut short, so flush the UART first. The
+    /// [`Config`] can be enabled again to resume. See the [module-level
+    /// documentation](self#parking).
     #[inline]
     pub fn disable(self) -> C {
         let mut config = self.config;
@@ -983,6 +1436,7 @@ where
             capability: self.capability,
             tx_channel: self.tx_channel,
             rx_channel,
+            tx_pending: self.tx_pending,
         }
     }
 }
@@ -1005,6 +1459,7 @@ where
             capability: self.capability,
             rx_channel: self.rx_channel,
             tx_channel,
+            tx_pending: self.tx_pending,
         }
     }
 }
@@ -1026,6 +1481,7 @@ where
                 capability: self.capability,
                 tx_channel: self.tx_channel,
                 rx_channel: NoneT,
+                tx_pending: self.tx_pending,
             },
             self.rx_channel,
         )
@@ -1049,6 +1505,7 @@ where
                 capability: self.capability,
                 rx_channel: self.rx_channel,
                 tx_channel: NoneT,
+                tx_pending: self.tx_pending,
             },
             self.tx_channel,
         )
@@ -1070,17 +1527,23 @@ where
                 capability: PhantomData,
                 rx_channel: self.rx_channel,
                 tx_channel: NoneT,
+                tx_pending: false,
             },
             Uart {
                 config,
                 capability: PhantomData,
                 rx_channel: NoneT,
                 tx_channel: self.tx_channel,
+                tx_pending: self.tx_pending,
             },
         )
     }
 
     /// Disable the UART peripheral and return the underlying [`Config`]
+    ///
+    /// Ongoing transmissions are cut short, so flush the UART first. The
+    /// [`Config`] can be enabled again to resume. See the [module-level
+    /// documentation](self#parking).
     #[inline]
     pub fn disable(self) -> C {
         let mut config = self.config;
@@ -1114,6 +1577,7 @@ where
             capability: PhantomData,
             rx_channel: rx.rx_channel,
             tx_channel: tx.tx_channel,
+            tx_pending: tx.tx_pending,
         }
     }
 }
@@ -1204,6 +1668,54 @@ where
             Status::BUFOVF | Status::FERR | Status::PERR | Status::ISF | Status::COLL,
         );
     }
+
+    /// Recover from a receive error, and return the errors that were cleared
+    ///
+    /// Receive errors are sticky: until they are cleared, every read returns
+    /// the same [`Error`]. The `PERR` and `FERR` errors belong to the
+    /// character at the head of the receive buffer, so that character is read
+    /// and discarded *before* clearing the `STATUS` bits. Clearing them first
+    /// would let the corrupted character through on the next read. A `BUFOVF`
+    /// error only means that characters were lost, so the buffered characters
+    /// are kept. The `ERROR` interrupt flag is cleared as well.
+    ///
+    /// After this call, the next read returns the next character received
+    /// correctly. To also drop the buffered characters, use
+    /// [`reset_rx`](Self::reset_rx) instead.
+    #[inline]
+    pub fn clear_errors(&mut self) -> Status {
+        let errors = Self::capability_status(self.read_status())
+            & (Status::PERR | Status::FERR | Status::BUFOVF | Status::ISF | Status::COLL);
+        if errors.intersects(Status::PERR | Status::FERR) && self.read_flags().contains(Flags::RXC)
+        {
+            let _data = unsafe { self.config.as_mut().registers.read_data() };
+        }
+        self.clear_status(Status::from_bits_retain(errors.bits()));
+        self.clear_flags(Flags::ERROR);
+        errors
+    }
+
+    /// Reset the receiver, dropping any partially or fully received characters
+    ///
+    /// The receiver is disabled and re-enabled, which flushes the receive
+    /// buffer and the shift register, then every receive error is cleared.
+    /// This resynchronizes the receiver on the next start bit, which is useful
+    /// after a burst of framing errors caused by a baud rate mismatch or a
+    /// glitch on the line.
+    ///
+    /// Only the `RXEN` bit of `CTRLB` is changed, in a critical section, so
+    /// this can be called on the [`RxDuplex`] half of a split [`Uart`]
+    /// without disturbing the [`TxDuplex`] half.
+    #[inline]
+    pub fn reset_rx(&mut self) {
+        let registers = &mut self.config.as_mut().registers;
+        registers.set_rx_enabled(false);
+        registers.set_rx_enabled(true);
+        self.clear_status(
+            Status::BUFOVF | Status::FERR | Status::PERR | Status::ISF | Status::COLL,
+        );
+        self.clear_flags(Flags::ERROR);
+    }
 }
 
 impl<C, D, R, T> Uart<C, D, R, T>
@@ -1221,5 +1733,200 @@ where
     #[inline]
     pub unsafe fn write_data(&mut self, data: DataReg) {
         self.config.as_mut().registers.write_data(data);
+        self.tx_pending = true;
+    }
+
+    /// Release the TX line
+    ///
+    /// Disable the transmitter, so that it stops driving the TX pin. The pin
+    /// becomes a high-impedance input, letting other nodes drive a shared
+    /// (multi-drop) line. Any transmission in progress is aborted, so
+    /// [`flush`](embedded_hal_nb::serial::Write::flush) the [`Uart`] first.
+    ///
+    /// Call [`drive_tx`](Self::drive_tx) before transmitting again.
+    ///
+    /// Only the `TXEN` bit of `CTRLB` is changed, in a critical section, so
+    /// this can be called on the [`TxDuplex`] half of a split [`Uart`]
+    /// without disturbing the [`RxDuplex`] half.
+    #[inline]
+    pub fn release_tx(&mut self) {
+        self.config.as_mut().registers.set_tx_enabled(false);
+    }
+
+    /// Drive the TX line
+    ///
+    /// Re-enable the transmitter after a call to
+    /// [`release_tx`](Self::release_tx). The TX pin is driven to the idle
+    /// (high) level until the next transmission. Like `release_tx`, this
+    /// only changes the `TXEN` bit of `CTRLB`, in a critical section.
+    #[inline]
+    pub fn drive_tx(&mut self) {
+        self.config.as_mut().registers.set_tx_enabled(true);
+    }
+
+    /// Clear a collision, and re-enable the transmitter
+    ///
+    /// When collision detection is enabled with
+    /// [`Config::collision_detection`], a [`Uart`] whose TX and RX pins share
+    /// a half-duplex line reads back every bit it transmits. If the line
+    /// doesn't match, the SERCOM aborts the transmission, flushes the
+    /// transmit buffer, disables the transmitter and sets the `COLL` status
+    /// flag. Writes then return [`Error::CollisionDetected`] until the
+    /// collision is cleared with this method.
+    ///
+    /// The `COLL` flag is cleared and `TXEN` set in a critical section, and
+    /// no other bit of `CTRLB` is changed, so this can be called on the
+    /// [`TxDuplex`] half of a split [`Uart`] without disturbing the
+    /// [`RxDuplex`] half.
+    #[inline]
+    pub fn clear_collision(&mut self) {
+        let registers = &mut self.config.as_mut().registers;
+        critical_section::with(|_| {
+            registers.clear_status(Status::COLL);
+            registers.set_tx_enabled(true);
+        });
+    }
+
+    /// Return [`Error::CollisionDetected`] if a collision disabled the
+    /// transmitter
+    #[inline]
+    fn check_collision(&self) -> Result<(), Error> {
+        if self.read_status().contains(Status::COLL) {
+            Err(Error::CollisionDetected)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<C, D, R, T> Uart<C, D, R, T>
+where
+    C: ValidConfig,
+    D: Transmit + SingleOwner,
+{
+    /// Transmit a break, and wait for it to complete
+    ///
+    /// A break holds the TX line low for longer than a character, which
+    /// receivers report as a framing error. It marks frame boundaries in LIN
+    /// and other protocols. Here, the line is held low for at least
+    /// `bit_times` bit periods of the current baud rate, e.g. 13 for a LIN
+    /// break. A `bit_times` shorter than a character is rounded up to one
+    /// character.
+    ///
+    /// The break is generated by transmitting an all-zero character at a
+    /// lower baud rate, so that its start and data bits last `bit_times` bit
+    /// periods in total. The baud rate is then restored. The SERCOM is briefly
+    /// disabled to change the baud rate, so this first waits until every word
+    /// written to the [`Uart`] has been transmitted. Expect the receiver to
+    /// report a framing error if it sees the break. The length of the break is
+    /// limited by the lowest baud rate of the current [`BaudMode`]. On SAMx5x
+    /// chips, a LIN master can instead let the hardware generate the whole
+    /// header, with `Uart::send_lin_header`.
+    ///
+    /// Disabling the SERCOM would also disrupt reception, so this is not
+    /// available on the [`TxDuplex`] half of a split [`Uart`].
+    #[inline]
+    pub fn send_break(&mut self, bit_times: u8) {
+        let baud = self.start_break(bit_times);
+        while !self.read_flags().contains(Flags::TXC) {}
+        self.finish_break(baud);
+    }
+
+    /// Wait for pending words to be transmitted, then lower the baud rate and
+    /// start transmitting a break. Return the previous contents of the `BAUD`
+    /// register.
+    fn start_break(&mut self, bit_times: u8) -> u16 {
+        // Changing BAUD disables the SERCOM, which would cut short the words
+        // still in the DATA register or the shift register
+        while !self.read_flags().contains(Flags::DRE) {}
+        if self.tx_pending {
+            while !self.read_flags().contains(Flags::TXC) {}
+        }
+
+        let config = self.config.as_mut();
+        let (baud, mode) = config.get_baud();
+        let rate = config.get_baud_rate().to_Hz() as u64;
+        // The line stays low for the start bit and every data bit
+        let low_bits = 1 + match config.registers.get_char_size() {
+            CharSizeEnum::FiveBit => 5,
+            CharSizeEnum::SixBit => 6,
+            CharSizeEnum::SevenBit => 7,
+            CharSizeEnum::EightBit => 8,
+            CharSizeEnum::NineBit => 9,
+        };
+        let break_rate = (rate * low_bits / bit_times.max(1) as u64).min(rate);
+        self._reconfigure(|c| c.set_baud(Hertz::from_raw(break_rate as u32), mode));
+
+        self.clear_flags(Flags::TXC);
+        unsafe { self.write_data(0) };
+        baud
+    }
+
+    /// Restore the baud rate once a break is complete
+    fn finish_break(&mut self, baud: u16) {
+        self.clear_flags(Flags::TXC);
+        self.tx_pending = false;
+        self._reconfigure(|c| c.registers.set_baud_raw(baud));
+    }
+}
+
+//=============================================================================
+// Blocking byte transfers
+//=============================================================================
+
+impl<C, D, R, T> Uart<C, D, R, T>
+where
+    C: ValidConfig<Word = u8>,
+    D: Receive,
+    DataReg: AsPrimitive<u8>,
+{
+    /// Read a single byte, blocking until one is received
+    ///
+    /// This is equivalent to calling [`nb::block!`] on the
+    /// [`embedded_hal_nb::serial::Read`] implementation.
+    #[inline]
+    pub fn read_byte(&mut self) -> Result<u8, Error> {
+        nb::block!(<Self as embedded_hal_nb::serial::Read<u8>>::read(self))
+    }
+
+    /// Fill `buf` with received bytes, blocking until it is full
+    ///
+    /// Stops at the first error, leaving the rest of `buf` untouched.
+    #[inline]
+    pub fn read_exact_blocking(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        for byte in buf {
+            *byte = self.read_byte()?;
+        }
+        Ok(())
+    }
+}
+
+impl<C, D, R, T> Uart<C, D, R, T>
+where
+    C: ValidConfig<Word = u8>,
+    D: Transmit,
+{
+    /// Write a single byte, blocking until it can be queued for transmission
+    ///
+    /// This is equivalent to calling [`nb::block!`] on the
+    /// [`embedded_hal_nb::serial::Write`] implementation.
+    #[inline]
+    pub fn write_byte(&mut self, byte: u8) -> Result<(), Error> {
+        nb::block!(<Self as embedded_hal_nb::serial::Write<u8>>::write(
+            self, byte
+        ))
+    }
+
+    /// Write every byte of `buf`, blocking until the last one has been queued
+    /// for transmission
+    ///
+    /// Use [`embedded_hal_nb::serial::Write::flush`] to wait for the
+    /// transmission to complete.
+    #[inline]
+    pub fn write_all_blocking(&mut self, buf: &[u8]) -> Result<(), Error> {
+        for byte in buf {
+            self.write_byte(*byte)?;
+        }
+        Ok(())
     }
 }
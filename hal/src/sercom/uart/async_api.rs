@@ -11,6 +11,8 @@ use crate::{
 };
 use atsamd_hal_macros::hal_macro_helper;
 use core::{marker::PhantomData, task::Poll};
+use embedded_hal_async::delay::DelayNs;
+use futures::future::{select, Either};
 use num_traits::AsPrimitive;
 
 /// Interrupt handler for async UART operarions
@@ -118,6 +120,7 @@ where
                     capability: PhantomData,
                     rx_channel: self.uart.rx_channel,
                     tx_channel: NoneT,
+                    tx_pending: false,
                 },
             },
             UartFuture {
@@ -126,6 +129,7 @@ where
                     capability: PhantomData,
                     rx_channel: NoneT,
                     tx_channel: self.uart.tx_channel,
+                    tx_pending: self.uart.tx_pending,
                 },
             },
         )
@@ -144,6 +148,7 @@ where
                 capability: PhantomData,
                 rx_channel: rx.uart.rx_channel,
                 tx_channel: tx.uart.tx_channel,
+                tx_pending: tx.uart.tx_pending,
             },
         }
     }
@@ -228,6 +233,7 @@ where
                 capability: PhantomData,
                 rx_channel,
                 tx_channel: self.uart.tx_channel,
+                tx_pending: self.uart.tx_pending,
             },
         }
     }
@@ -267,6 +273,48 @@ where
         }
         Ok(())
     }
+
+    /// Read words into a buffer until the RX line goes idle, and return the
+    /// number of words read
+    ///
+    /// This waits as long as necessary for the first word. After each word,
+    /// the line must then stay idle for the number of bit times set with
+    /// [`rx_idle_timeout`](crate::sercom::uart::Config::rx_idle_timeout) to
+    /// end the frame. The SERCOM has no receive timeout, so the idle time is
+    /// measured with `delay`, e.g. an `async` timer. It is measured from the
+    /// `RXC` flag of the previous word, while the next word may still be
+    /// shifted in, so the delay lasts one whole frame, with its parity and
+    /// stop bits, on top of the idle time.
+    ///
+    /// If `buffer` fills up before the line goes idle, the full count is
+    /// returned, and the rest of the frame is left to the next read.
+    #[inline]
+    pub async fn read_until_idle<Dl: DelayNs>(
+        &mut self,
+        buffer: &mut [C::Word],
+        delay: &mut Dl,
+    ) -> Result<usize, Error> {
+        let Some((first, rest)) = buffer.split_first_mut() else {
+            return Ok(0);
+        };
+        *first = self.read_word().await?;
+
+        let config = self.uart.config.as_ref();
+        let idle_bits = config.frame_bits() as u64 + config.get_rx_idle_timeout() as u64;
+        let idle_ns = idle_bits * 1_000_000_000 / config.get_baud_rate().to_Hz() as u64;
+        let idle_ns = idle_ns.min(u32::MAX as u64) as u32;
+
+        for (i, word) in rest.iter_mut().enumerate() {
+            let read = self.read_word();
+            let idle = delay.delay_ns(idle_ns);
+            futures::pin_mut!(read, idle);
+            match select(read, idle).await {
+                Either::Left((result, _)) => *word = result?,
+                Either::Right(_) => return Ok(i + 1),
+            }
+        }
+        Ok(buffer.len())
+    }
 }
 
 impl<C, D, S, R> embedded_io_async::Read for UartFuture<C, D, NoneT, R>
@@ -301,6 +349,7 @@ where
                 capability: PhantomData,
                 rx_channel: self.uart.rx_channel,
                 tx_channel,
+                tx_pending: self.uart.tx_pending,
             },
         }
     }
@@ -311,6 +360,44 @@ where
         self.wait_flags(Flags::DRE).await;
         unsafe { self.uart.write_data(word.as_()) };
     }
+
+    /// Wait until every word written has been transmitted
+    ///
+    /// This waits for the `TXC` flag, which is set once both the `DATA`
+    /// register and the shift register are empty. The UART can then be
+    /// disabled, or the chip powered down, without cutting the last word
+    /// short. If nothing was written since the last flush, this returns at
+    /// once.
+    #[inline]
+    pub async fn flush(&mut self) {
+        if self.uart.tx_pending {
+            self.wait_flags(Flags::TXC).await;
+            self.uart.tx_pending = false;
+        }
+    }
+}
+
+impl<C, D, S, R, T> UartFuture<C, D, R, T>
+where
+    C: ValidConfig<Sercom = S>,
+    D: Transmit + SingleOwner,
+    S: Sercom,
+{
+    /// Transmit a break, and wait for it to complete
+    ///
+    /// See [`Uart::send_break`] for details. If the future is dropped before
+    /// completion, the break is cut short and the baud rate is not restored.
+    /// Use [`reconfigure`](Uart::reconfigure) to set it again.
+    #[inline]
+    pub async fn send_break(&mut self, bit_times: u8) {
+        self.wait_flags(Flags::DRE).await;
+        if self.uart.tx_pending {
+            self.wait_flags(Flags::TXC).await;
+        }
+        let baud = self.uart.start_break(bit_times);
+        self.wait_flags(Flags::TXC).await;
+        self.uart.finish_break(baud);
+    }
 }
 
 impl<C, D, S, R> UartFuture<C, D, R, NoneT>
@@ -340,6 +427,13 @@ where
         self.write(buffer).await;
         Ok(buffer.len())
     }
+
+    /// Wait for a `TXC` flag
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.flush().await;
+        Ok(())
+    }
 }
 
 impl<C, D, R, T, S> AsRef<Uart<C, D, R, T>> for UartFuture<C, D, R, T>
@@ -486,8 +580,10 @@ mod dma {
             let uart_ptr = self.uart.sercom_ptr();
 
             let mut words = SharedSliceBuffer::from_slice(words);
+            self.uart.tx_pending = true;
             write_dma::<_, _, S>(&mut self.uart.tx_channel, uart_ptr, &mut words).await?;
             self.wait_flags(Flags::TXC).await;
+            self.uart.tx_pending = false;
             Ok(())
         }
     }
@@ -504,6 +600,13 @@ mod dma {
             self.write(words).await?;
             Ok(words.len())
         }
+
+        /// Wait for a `TXC` flag
+        #[inline]
+        async fn flush(&mut self) -> Result<(), Error> {
+            self.flush().await;
+            Ok(())
+        }
     }
 }
 
@@ -0,0 +1,297 @@
+//! Baud rate detection from a sync byte
+//!
+//! The hardware auto-baud of the SERCOM only works with LIN frames, which
+//! start with a break. A host which simply sends [`SYNC_BYTE`] can instead be
+//! measured in software, by timing the edges of the RX line before the pin is
+//! handed over to the SERCOM.
+//!
+//! With 8 data bits, LSB first, the sync byte toggles the line at every bit
+//! boundary, from the falling edge of the start bit, to the rising edge of
+//! the stop bit. Its [`SYNC_EDGES`] edges are thus exactly one bit period
+//! apart, which both gives the baud rate and makes it easy to reject any other
+//! character.
+//!
+//! When the host does send a break first, [`Config::into_auto_baud`] uses the
+//! hardware auto-baud instead.
+
+use core::convert::Infallible;
+
+use nb::Error::WouldBlock;
+use num_traits::AsPrimitive;
+
+use super::{
+    BaudMode, CharSize, Config, DataReg, Error, Flags, Oversampling, Receive, Status, Uart,
+    ValidConfig, ValidPads,
+};
+use crate::ehal::digital::InputPin;
+use crate::time::Hertz;
+
+/// Character sent by the host for baud rate detection
+pub const SYNC_BYTE: u8 = 0x55;
+
+/// Number of edges of a [`SYNC_BYTE`] frame, from the start bit to the stop
+/// bit
+pub const SYNC_EDGES: usize = 10;
+
+/// Errors of the baud rate detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoBaudError {
+    /// The line didn't change for longer than the timeout, in the middle of
+    /// the character
+    Timeout,
+    /// The edges were not evenly spaced, so the character was not a
+    /// [`SYNC_BYTE`]
+    NotSync,
+    /// The detected baud rate can't be generated from the clock of the SERCOM
+    OutOfRange(Hertz),
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for AutoBaudError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Self::Timeout => defmt::write!(f, "Timeout"),
+            Self::NotSync => defmt::write!(f, "NotSync"),
+            Self::OutOfRange(rate) => defmt::write!(f, "OutOfRange({} Hz)", rate.to_Hz()),
+        }
+    }
+}
+
+/// Record the timestamps of the edges of a sync byte on the RX line
+///
+/// This function waits for the line to be idle, then for as long as
+/// necessary for the falling edge of the start bit. Every following edge must
+/// come within `timeout` ticks of the previous one, otherwise
+/// [`AutoBaudError::Timeout`] is returned.
+///
+/// `now` returns a timestamp, in ticks of any free-running counter which is
+/// allowed to wrap around, such as the DWT cycle counter. The counter must be
+/// at least a few times faster than the baud rate, and the loop must be able
+/// to sample the pin at that rate, so interrupts should be disabled while the
+/// edges are captured.
+pub fn capture_sync_edges<P, T>(
+    rx: &mut P,
+    mut now: T,
+    timeout: u32,
+) -> Result<[u32; SYNC_EDGES], AutoBaudError>
+where
+    P: InputPin<Error = Infallible>,
+    T: FnMut() -> u32,
+{
+    let mut is_high = || match rx.is_high() {
+        Ok(level) => level,
+        Err(e) => match e {},
+    };
+
+    while !is_high() {}
+    while is_high() {}
+
+    let mut edges = [0; SYNC_EDGES];
+    edges[0] = now();
+    for i in 1..SYNC_EDGES {
+        // Odd edges are rising, and even edges are falling
+        let level = i % 2 == 1;
+        loop {
+            let time = now();
+            if is_high() == level {
+                edges[i] = time;
+                break;
+            }
+            if time.wrapping_sub(edges[i - 1]) > timeout {
+                return Err(AutoBaudError::Timeout);
+            }
+        }
+    }
+    Ok(edges)
+}
+
+/// Compute the baud rate from the edges of a sync byte
+///
+/// `edges` are timestamps in ticks of `tick_rate`, as returned by
+/// [`capture_sync_edges`]. Each bit may deviate from the average bit period by
+/// a quarter of a period, to tolerate the sampling jitter. Otherwise, the
+/// character is rejected with [`AutoBaudError::NotSync`].
+pub fn measure_sync_byte(
+    edges: &[u32; SYNC_EDGES],
+    tick_rate: Hertz,
+) -> Result<Hertz, AutoBaudError> {
+    const BITS: u64 = SYNC_EDGES as u64 - 1;
+
+    let span = edges[SYNC_EDGES - 1].wrapping_sub(edges[0]) as u64;
+    if span < BITS {
+        return Err(AutoBaudError::NotSync);
+    }
+
+    // Compare every bit with the average, scaled by `BITS` to stay in
+    // integers
+    let consistent = edges
+        .windows(2)
+        .all(|w| (w[1].wrapping_sub(w[0]) as u64 * BITS).abs_diff(span) <= span / 4);
+    if !consistent {
+        return Err(AutoBaudError::NotSync);
+    }
+
+    let baud = (tick_rate.to_Hz() as u64 * BITS + span / 2) / span;
+    Ok(Hertz::from_raw(baud as u32))
+}
+
+//=============================================================================
+// Hardware auto-baud
+//=============================================================================
+
+/// A [`Uart`] in auto-baud mode, measuring its baud rate from LIN headers
+///
+/// Created by [`Config::into_auto_baud`]. The SERCOM waits for a break, then
+/// measures the following [`SYNC_BYTE`] and writes the result to its `BAUD`
+/// register. The next character, usually the protected identifier of a LIN
+/// frame, is received at the measured rate. Every break restarts the
+/// measurement.
+pub struct AutoBaudUart<C, D>
+where
+    C: ValidConfig,
+    D: Receive,
+{
+    uart: Uart<C, D>,
+    break_detected: bool,
+}
+
+impl<P, C> Config<P, C>
+where
+    P: ValidPads,
+    C: CharSize,
+    Self: ValidConfig,
+    P::Capability: Receive,
+{
+    /// Enable the UART peripheral in auto-baud mode, and return an
+    /// [`AutoBaudUart`]
+    ///
+    /// Auto-baud requires fractional baud rate generation with 16x
+    /// oversampling, which is selected here. The current baud rate is kept
+    /// until the first sync byte is measured. The parity setting is kept.
+    #[inline]
+    pub fn into_auto_baud(mut self) -> AutoBaudUart<Self, P::Capability> {
+        let mode = BaudMode::Fractional(Oversampling::Bits16);
+        let baud = self.get_baud_rate().min(self.baud_rate_range(mode).1);
+        self.set_baud(baud, mode);
+        self.registers.set_auto_baud(true);
+        AutoBaudUart {
+            uart: self.enable(),
+            break_detected: false,
+        }
+    }
+}
+
+impl<C, D> AutoBaudUart<C, D>
+where
+    C: ValidConfig,
+    D: Receive,
+    DataReg: AsPrimitive<C::Word>,
+{
+    /// Wait for a break and a sync byte, and return the measured baud rate
+    ///
+    /// This completes once the character following the sync byte has been
+    /// received. It is left in the receive buffer, and can be read through
+    /// [`as_mut`](AsMut::as_mut). Characters received before the break are
+    /// discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AutoBaudFailed`] if the sync byte was inconsistent,
+    /// or if a frame error occurred during the measurement. The receiver is
+    /// then reset, and the next call waits for a new break.
+    #[inline]
+    pub fn wait_for_sync(&mut self) -> nb::Result<Hertz, Error> {
+        if self
+            .uart
+            .read_status()
+            .intersects(Status::ISF | Status::FERR)
+        {
+            self.uart.reset_rx();
+            self.uart.clear_flags(Flags::RXBRK);
+            self.break_detected = false;
+            return Err(nb::Error::Other(Error::AutoBaudFailed));
+        }
+
+        let flags = self.uart.read_flags();
+        if flags.contains(Flags::RXBRK) {
+            self.uart.clear_flags(Flags::RXBRK);
+            self.break_detected = true;
+        }
+        if flags.contains(Flags::RXC) {
+            if self.break_detected {
+                self.break_detected = false;
+                return Ok(self.uart.config.as_ref().get_baud_rate());
+            }
+            let _ = unsafe { self.uart.read_data() };
+        }
+        Err(WouldBlock)
+    }
+
+    /// Leave auto-baud mode, and return the [`Uart`]
+    ///
+    /// The last measured baud rate is kept.
+    #[inline]
+    pub fn free(mut self) -> Uart<C, D> {
+        self.uart._reconfigure(|c| c.registers.set_auto_baud(false));
+        self.uart
+    }
+}
+
+impl<C, D> AsMut<Uart<C, D>> for AutoBaudUart<C, D>
+where
+    C: ValidConfig,
+    D: Receive,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut Uart<C, D> {
+        &mut self.uart
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edges of a sync byte at `baud`, timed by a `tick_rate` counter starting
+    /// at `start`
+    fn edges(baud: u32, tick_rate: u32, start: u32) -> [u32; SYNC_EDGES] {
+        let mut edges = [0; SYNC_EDGES];
+        for (i, edge) in edges.iter_mut().enumerate() {
+            *edge = start.wrapping_add((i as u64 * tick_rate as u64 / baud as u64) as u32);
+        }
+        edges
+    }
+
+    #[test]
+    fn standard_rates_are_detected() {
+        let tick_rate = Hertz::MHz(120);
+        for baud in [9600, 115_200] {
+            let edges = edges(baud, tick_rate.to_Hz(), 1000);
+            let detected = measure_sync_byte(&edges, tick_rate).unwrap();
+            assert!(detected.to_Hz().abs_diff(baud) <= baud / 1000);
+        }
+    }
+
+    #[test]
+    fn counter_may_wrap_around() {
+        let tick_rate = Hertz::MHz(48);
+        let edges = edges(115_200, tick_rate.to_Hz(), u32::MAX - 1000);
+        let detected = measure_sync_byte(&edges, tick_rate).unwrap();
+        assert!(detected.to_Hz().abs_diff(115_200) <= 115);
+    }
+
+    #[test]
+    fn other_characters_are_rejected() {
+        // Another character, or noise, merges some of the bits
+        let mut edges = edges(9600, 48_000_000, 0);
+        edges[3] = edges[4] - 1;
+        assert_eq!(
+            measure_sync_byte(&edges, Hertz::MHz(48)),
+            Err(AutoBaudError::NotSync)
+        );
+        assert_eq!(
+            measure_sync_byte(&[0; SYNC_EDGES], Hertz::MHz(48)),
+            Err(AutoBaudError::NotSync)
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Interrupt-driven, software-buffered UART
+//!
+//! A [`BufferedUart`] uses the `RXC` and `DRE` interrupts to move bytes
+//! between the SERCOM `DATA` register and a pair of user-provided ring
+//! buffers. Reads and writes never block: [`try_read`](BufferedUart::try_read)
+//! drains whatever bytes have already been received, and
+//! [`try_write`](BufferedUart::try_write) queues as many bytes as will fit in
+//! the TX buffer, returning the number of bytes actually transferred.
+//!
+//! Unlike [`UartFuture`](super::UartFuture), a [`BufferedUart`] does not
+//! require an executor, nor the `async` feature. It only needs the SERCOM
+//! interrupt to be bound to a [`BufferedInterruptHandler`] using
+//! [`bind_interrupts`](crate::bind_interrupts) (SAMD11/SAMD21) or
+//! [`bind_multiple_interrupts`](crate::bind_multiple_interrupts) (SAMx5x).
+//!
+//! ```no_run
+//! use atsamd_hal::sercom::{uart, Sercom3};
+//!
+//! atsamd_hal::bind_multiple_interrupts!(struct Irqs {
+//!     SERCOM3: [SERCOM3_0, SERCOM3_1, SERCOM3_2, SERCOM3_OTHER] => uart::BufferedInterruptHandler<Sercom3>;
+//! });
+//!
+//! static mut RX_BUFFER: [u8; 64] = [0; 64];
+//! static mut TX_BUFFER: [u8; 64] = [0; 64];
+//!
+//! // Assume uart is a Uart<C, Duplex>
+//! let mut uart = uart.into_buffered(Irqs, unsafe { &mut RX_BUFFER }, unsafe { &mut TX_BUFFER });
+//!
+//! let mut buf = [0; 16];
+//! let n = uart.try_read(&mut buf);
+//! uart.try_write(&buf[..n]);
+//! ```
+
+use super::{
+    Capability, DataReg, Flags, Receive, SingleOwner, Transmit, Uart, ValidConfig, RX_STATUS_MASK,
+};
+use crate::{
+    async_hal::interrupts::{Binding, Handler, InterruptSource},
+    sercom::Sercom,
+    typelevel::NoneT,
+};
+use atsamd_hal_macros::hal_macro_helper;
+use core::{
+    marker::PhantomData,
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering},
+};
+
+//=============================================================================
+// RingBuffer
+//=============================================================================
+
+/// Single-producer, single-consumer byte queue shared between a
+/// [`BufferedUart`] and its interrupt handler.
+///
+/// One slot is always kept empty to distinguish a full buffer from an empty
+/// one, so the usable capacity is one less than the length of the backing
+/// storage.
+struct RingBuffer {
+    buf: AtomicPtr<u8>,
+    len: AtomicUsize,
+    start: AtomicUsize,
+    end: AtomicUsize,
+}
+
+impl RingBuffer {
+    const fn new() -> Self {
+        Self {
+            buf: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            start: AtomicUsize::new(0),
+            end: AtomicUsize::new(0),
+        }
+    }
+
+    /// Attach a backing buffer. Must not be called while the interrupt handler
+    /// may access the queue.
+    fn init(&self, buf: &'static mut [u8]) {
+        self.start.store(0, Ordering::Relaxed);
+        self.end.store(0, Ordering::Relaxed);
+        self.len.store(buf.len(), Ordering::Relaxed);
+        self.buf.store(buf.as_mut_ptr(), Ordering::Release);
+    }
+
+    /// Detach the backing buffer. Must not be called while the interrupt
+    /// handler may access the queue.
+    fn deinit(&self) {
+        self.buf.store(ptr::null_mut(), Ordering::Relaxed);
+        self.len.store(0, Ordering::Relaxed);
+        self.start.store(0, Ordering::Relaxed);
+        self.end.store(0, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn wrap(&self, index: usize, len: usize) -> usize {
+        if index + 1 == len {
+            0
+        } else {
+            index + 1
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.start.load(Ordering::Acquire) == self.end.load(Ordering::Acquire)
+    }
+
+    /// Push a byte. Returns `false` if the queue is full.
+    #[inline]
+    fn push(&self, byte: u8) -> bool {
+        let buf = self.buf.load(Ordering::Acquire);
+        let len = self.len.load(Ordering::Relaxed);
+        if buf.is_null() || len < 2 {
+            return false;
+        }
+
+        let end = self.end.load(Ordering::Relaxed);
+        let next = self.wrap(end, len);
+        if next == self.start.load(Ordering::Acquire) {
+            return false;
+        }
+
+        // SAFETY: `end < len`, and only the producer ever writes to the slot
+        // at `end`.
+        unsafe { buf.add(end).write_volatile(byte) };
+        self.end.store(next, Ordering::Release);
+        true
+    }
+
+    /// Pop a byte. Returns `None` if the queue is empty.
+    #[inline]
+    fn pop(&self) -> Option<u8> {
+        let buf = self.buf.load(Ordering::Acquire);
+        let len = self.len.load(Ordering::Relaxed);
+        if buf.is_null() {
+            return None;
+        }
+
+        let start = self.start.load(Ordering::Relaxed);
+        if start == self.end.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // SAFETY: `start < len`, and the producer never writes to the slot at
+        // `start` until the consumer has advanced past it.
+        let byte = unsafe { buf.add(start).read_volatile() };
+        self.start.store(self.wrap(start, len), Ordering::Release);
+        Some(byte)
+    }
+}
+
+/// Per-SERCOM state shared between a [`BufferedUart`] and its interrupt
+/// handler.
+struct State {
+    rx: RingBuffer,
+    tx: RingBuffer,
+    rx_error: AtomicBool,
+}
+
+impl State {
+    const fn new() -> Self {
+        Self {
+            rx: RingBuffer::new(),
+            tx: RingBuffer::new(),
+            rx_error: AtomicBool::new(false),
+        }
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NEW_STATE: State = State::new();
+static STATES: [State; crate::sercom::NUM_SERCOM] = [NEW_STATE; crate::sercom::NUM_SERCOM];
+
+#[inline]
+fn state<S: Sercom>() -> &'static State {
+    &STATES[S::NUM]
+}
+
+//=============================================================================
+// Interrupt handler
+//=============================================================================
+
+/// Interrupt handler for [`BufferedUart`]s
+///
+/// Bind the SERCOM interrupt source to this handler instead of the regular
+/// [`InterruptHandler`](super::InterruptHandler) when using a
+/// [`BufferedUart`].
+pub struct BufferedInterruptHandler<S: Sercom> {
+    _private: (),
+    _sercom: PhantomData<S>,
+}
+
+impl<S: Sercom> crate::typelevel::Sealed for BufferedInterruptHandler<S> {}
+
+impl<S: Sercom> Handler<S::Interrupt> for BufferedInterruptHandler<S> {
+    #[inline]
+    #[hal_macro_helper]
+    unsafe fn on_interrupt() {
+        let mut peripherals = crate::pac::Peripherals::steal();
+
+        #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
+        let uart = S::reg_block(&mut peripherals).usart();
+        #[hal_cfg("sercom0-d5x")]
+        let uart = S::reg_block(&mut peripherals).usart_int();
+
+        let state = state::<S>();
+        let flags = Flags::from_bits_retain(uart.intflag().read().bits());
+        let enabled = Flags::from_bits_retain(uart.intenset().read().bits());
+
+        if enabled.contains(Flags::RXC) && flags.contains(Flags::RXC) {
+            // Errors are only reported through `take_rx_error`; clear them so
+            // reception can continue.
+            let status = uart.status().read().bits();
+            if status & RX_STATUS_MASK != 0 {
+                uart.status().write(|w| w.bits(status & RX_STATUS_MASK));
+                state.rx_error.store(true, Ordering::Relaxed);
+            }
+
+            let byte = uart.data().read().data().bits() as u8;
+            if !state.rx.push(byte) {
+                state.rx_error.store(true, Ordering::Relaxed);
+            }
+        }
+
+        if enabled.contains(Flags::DRE) && flags.contains(Flags::DRE) {
+            match state.tx.pop() {
+                Some(byte) => uart.data().write(|w| w.data().bits(byte as DataReg)),
+                None => uart.intenclr().write(|w| w.bits(Flags::DRE.bits())),
+            }
+        }
+    }
+}
+
+//=============================================================================
+// BufferedUart
+//=============================================================================
+
+/// Interrupt-driven [`Uart`] with software RX and TX queues.
+///
+/// Create this struct by calling [`Uart::into_buffered`]. See the
+/// [module-level documentation](super#interrupt-driven-buffering) for more information.
+pub struct BufferedUart<C, D>
+where
+    C: ValidConfig,
+    D: Capability,
+{
+    uart: Uart<C, D>,
+}
+
+impl<C, D, S> Uart<C, D, NoneT, NoneT>
+where
+    C: ValidConfig<Sercom = S, Word = u8>,
+    D: SingleOwner,
+    S: Sercom,
+{
+    /// Turn a [`Uart`] into a [`BufferedUart`].
+    ///
+    /// `rx_buffer` and `tx_buffer` back the software queues; one byte of each
+    /// is reserved to tell a full queue from an empty one. Pass an empty slice
+    /// for a direction that the [`Uart`]'s [`Capability`] does not support.
+    #[inline]
+    pub fn into_buffered<I>(
+        mut self,
+        _interrupts: I,
+        rx_buffer: &'static mut [u8],
+        tx_buffer: &'static mut [u8],
+    ) -> BufferedUart<C, D>
+    where
+        I: Binding<S::Interrupt, BufferedInterruptHandler<S>>,
+    {
+        S::Interrupt::disable();
+
+        let state = state::<S>();
+        state.rx.init(rx_buffer);
+        state.tx.init(tx_buffer);
+        state.rx_error.store(false, Ordering::Relaxed);
+
+        self.disable_interrupts(Flags::all());
+        if D::RXEN {
+            self.enable_interrupts(Flags::RXC);
+        }
+
+        S::Interrupt::unpend();
+        unsafe { S::Interrupt::enable() };
+
+        BufferedUart { uart: self }
+    }
+}
+
+impl<C, D, S> BufferedUart<C, D>
+where
+    C: ValidConfig<Sercom = S, Word = u8>,
+    D: SingleOwner,
+    S: Sercom,
+{
+    /// Stop interrupt-driven operation and return the underlying [`Uart`].
+    ///
+    /// Any bytes still sitting in the software queues are discarded.
+    #[inline]
+    pub fn free(mut self) -> Uart<C, D> {
+        S::Interrupt::disable();
+        self.uart.disable_interrupts(Flags::all());
+
+        let state = state::<S>();
+        state.rx.deinit();
+        state.tx.deinit();
+
+        self.uart
+    }
+}
+
+impl<C, D, S> BufferedUart<C, D>
+where
+    C: ValidConfig<Sercom = S, Word = u8>,
+    D: Receive,
+    S: Sercom,
+{
+    /// Copy already-received bytes into `buf` without blocking.
+    ///
+    /// Returns the number of bytes copied, which may be zero.
+    #[inline]
+    pub fn try_read(&mut self, buf: &mut [u8]) -> usize {
+        let rx = &state::<S>().rx;
+        let mut count = 0;
+        for byte in buf.iter_mut() {
+            match rx.pop() {
+                Some(b) => *byte = b,
+                None => break,
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Check whether received bytes were dropped because the RX queue was
+    /// full, or whether the hardware reported a parity, framing or overflow
+    /// error since the last call. Calling this method clears the condition.
+    #[inline]
+    pub fn take_rx_error(&mut self) -> bool {
+        // thumbv6m has no atomic swap, and the interrupt handler may set the
+        // flag between the load and the store
+        critical_section::with(|_| {
+            let rx_error = &state::<S>().rx_error;
+            let error = rx_error.load(Ordering::Relaxed);
+            rx_error.store(false, Ordering::Relaxed);
+            error
+        })
+    }
+}
+
+impl<C, D, S> BufferedUart<C, D>
+where
+    C: ValidConfig<Sercom = S, Word = u8>,
+    D: Transmit,
+    S: Sercom,
+{
+    /// Queue as many bytes of `buf` as fit in the TX queue without blocking.
+    ///
+    /// Returns the number of bytes queued, which may be zero.
+    #[inline]
+    pub fn try_write(&mut self, buf: &[u8]) -> usize {
+        let tx = &state::<S>().tx;
+        let count = buf.iter().take_while(|b| tx.push(**b)).count();
+        if count > 0 {
+            self.uart.enable_interrupts(Flags::DRE);
+        }
+        count
+    }
+
+    /// Check whether all queued bytes have been handed to the hardware and the
+    /// last one has finished shifting out.
+    #[inline]
+    pub fn is_tx_idle(&self) -> bool {
+        state::<S>().tx.is_empty() && self.uart.read_flags().contains(Flags::TXC)
+    }
+}
+
+impl<C, D> AsRef<Uart<C, D>> for BufferedUart<C, D>
+where
+    C: ValidConfig,
+    D: Capability,
+{
+    #[inline]
+    fn as_ref(&self) -> &Uart<C, D> {
+        &self.uart
+    }
+}
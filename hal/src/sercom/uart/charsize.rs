@@ -43,6 +43,7 @@ pub enum DynCharSize {}
 
 /// `enum` version of [`CharSize`]
 #[repr(u8)]
+#[derive(Debug, Clone, Copy)]
 pub enum CharSizeEnum {
     FiveBit = 0x5,
     SixBit = 0x6,
@@ -2,10 +2,14 @@
 
 use atsamd_hal_macros::hal_cfg;
 
+use super::reg::baud_rate_range;
 use super::{
-    BaudMode, BitOrder, Capability, CharSize, CharSizeEnum, DataReg, DynCharSize, EightBit,
-    FixedCharSize, Parity, Registers, StopBits, Uart, ValidConfig, ValidPads,
+    measure_sync_byte, AutoBaudError, BaudMode, BitOrder, Capability, CharSize, CharSizeEnum,
+    DataReg, Duplex, DynCharSize, EightBit, FixedCharSize, Flags, Parity, Registers,
+    SampleAdjustment, StopBits, Tx, Uart, ValidConfig, ValidPads, SYNC_EDGES,
 };
+#[hal_cfg("sercom0-d5x")]
+use crate::sercom::pad::{Pad2, SomePad};
 use crate::{
     pac,
     sercom::Sercom,
@@ -48,8 +52,13 @@ where
     pads: P,
     chsize: PhantomData<C>,
     freq: Hertz,
+    rx_idle_bits: u16,
+    loopback: bool,
 }
 
+/// Default idle time that ends a frame, in bit times
+const DEFAULT_RX_IDLE_BITS: u16 = 10;
+
 /// Clock type needed to create a new [`Config`]. [`Pm`](pac::Pm) for thumbv6m
 /// targets.
 #[hal_cfg(any("sercom0-d11", "sercom0-d21"))]
@@ -98,6 +107,8 @@ impl<P: ValidPads> Config<P> {
             pads,
             chsize: PhantomData,
             freq: freq.into(),
+            rx_idle_bits: DEFAULT_RX_IDLE_BITS,
+            loopback: false,
         }
     }
 }
@@ -118,6 +129,8 @@ where
             pads: self.pads,
             chsize: PhantomData,
             freq: self.freq,
+            rx_idle_bits: self.rx_idle_bits,
+            loopback: self.loopback,
         }
     }
 
@@ -136,6 +149,39 @@ where
         (self.registers.free(), self.pads)
     }
 
+    /// Apply several changes to the [`Config`] at once
+    ///
+    /// `update` receives an `&mut Config`, so it can use any of the `set_*`
+    /// methods, such as [`set_baud`](Self::set_baud),
+    /// [`set_stop_bits`](Self::set_stop_bits) or
+    /// [`set_parity`](Self::set_parity). The [`Pads`](super::Pads) stay in the
+    /// [`Config`], and its [`CharSize`] can't change, since that requires the
+    /// by-value [`char_size`](Self::char_size).
+    ///
+    /// A [`Config`] always has its SERCOM disabled, so every register can be
+    /// written. This is checked with a `debug_assert`. To reconfigure an
+    /// enabled UART, use [`Uart::reconfigure`] instead.
+    ///
+    /// ```
+    /// # use atsamd_hal::sercom::uart::{BaudMode, Config, EightBit, Oversampling, ValidPads};
+    /// # use atsamd_hal::fugit::RateExtU32;
+    /// # fn example<P: ValidPads>(config: Config<P, EightBit>) {
+    /// let config = config.reconfigure(|c| {
+    ///     c.set_baud(115_200.Hz(), BaudMode::Fractional(Oversampling::Bits16));
+    ///     c.set_stop_bits(atsamd_hal::sercom::uart::StopBits::TwoBits);
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    pub fn reconfigure(mut self, update: impl FnOnce(&mut Self)) -> Self {
+        debug_assert!(
+            !self.registers.get_enabled(),
+            "The SERCOM of a uart::Config must be disabled"
+        );
+        update(&mut self);
+        self
+    }
+
     /// Change the [`CharSize`].
     #[inline]
     pub fn char_size<C2: FixedCharSize>(mut self) -> Config<P, C2> {
@@ -162,6 +208,11 @@ where
     }
 
     /// Change the bit order of transmission (setter version)
+    ///
+    /// `CTRLA.DORD` is enable-protected. A [`Config`] is always disabled, so
+    /// the bit order can be set freely. To change it on an enabled [`Uart`],
+    /// use [`Uart::reconfigure`], which disables and re-enables the
+    /// peripheral. The default is [`BitOrder::LsbFirst`].
     #[inline]
     pub fn set_bit_order(&mut self, bit_order: BitOrder) {
         self.registers.set_bit_order(bit_order);
@@ -174,6 +225,12 @@ where
     }
 
     /// Change the parity setting (builder pattern version)
+    ///
+    /// The parity bit is sent after the data bits, so it doesn't take one of
+    /// the [`CharSize`] bits: an 8-bit character with parity is 9 bits long,
+    /// plus the start and stop bits. A received character failing the parity
+    /// check is reported as [`Error::ParityError`](super::Error::ParityError)
+    /// by the read that would have returned it.
     #[inline]
     pub fn parity(mut self, parity: Parity) -> Self {
         self.set_parity(parity);
@@ -193,6 +250,23 @@ where
     }
 
     /// Change the stop bit setting (builder pattern version)
+    ///
+    /// The setting is written to `CTRLB.SBMODE`, and chains with the other
+    /// builder methods:
+    ///
+    /// ```
+    /// # use atsamd_hal::sercom::uart::{
+    /// #     BaudMode, Config, EightBit, Oversampling, Parity, StopBits, ValidPads,
+    /// # };
+    /// # use atsamd_hal::fugit::RateExtU32;
+    /// # fn example<P: ValidPads>(config: Config<P, EightBit>) {
+    /// let config = config
+    ///     .baud(9600.Hz(), BaudMode::Arithmetic(Oversampling::Bits16))
+    ///     .parity(Parity::Even)
+    ///     .stop_bits(StopBits::TwoBits);
+    /// assert!(matches!(config.get_stop_bits(), StopBits::TwoBits));
+    /// # }
+    /// ```
     #[inline]
     pub fn stop_bits(mut self, stop_bits: StopBits) -> Self {
         self.set_stop_bits(stop_bits);
@@ -211,6 +285,33 @@ where
         self.registers.get_stop_bits()
     }
 
+    /// Change the sample adjustment setting (builder pattern version)
+    ///
+    /// Selects which samples decide the value of a received bit. See
+    /// [`SampleAdjustment`] for the positions in each oversampling mode. It
+    /// has no effect with 3x oversampling, which this module doesn't use.
+    #[inline]
+    pub fn sample_adjustment(mut self, adjustment: SampleAdjustment) -> Self {
+        self.set_sample_adjustment(adjustment);
+        self
+    }
+
+    /// Change the sample adjustment setting (setter version)
+    ///
+    /// Selects which samples decide the value of a received bit. See
+    /// [`SampleAdjustment`] for the positions in each oversampling mode. It
+    /// has no effect with 3x oversampling, which this module doesn't use.
+    #[inline]
+    pub fn set_sample_adjustment(&mut self, adjustment: SampleAdjustment) {
+        self.registers.set_sample_adjustment(adjustment);
+    }
+
+    /// Get the current sample adjustment setting
+    #[inline]
+    pub fn get_sample_adjustment(&self) -> SampleAdjustment {
+        self.registers.get_sample_adjustment()
+    }
+
     /// Enable or disable the start of frame detector (builder pattern version)
     ///
     /// When set, the UART will generate interrupts for
@@ -239,7 +340,10 @@ where
     /// Enable or disable the collision detector (builder pattern version)
     ///
     /// When set, the UART will detect collisions and update the
-    /// corresponding flag in the STATUS register.
+    /// corresponding flag in the STATUS register. The transmitter is then
+    /// disabled, and writes return
+    /// [`Error::CollisionDetected`](super::Error::CollisionDetected) until
+    /// [`Uart::clear_collision`] is called.
     #[inline]
     pub fn collision_detection(mut self, enabled: bool) -> Self {
         self.set_collision_detection(enabled);
@@ -261,6 +365,73 @@ where
         self.registers.get_collision_detection()
     }
 
+    /// Set the idle time that ends a frame, in bit times (builder pattern
+    /// version)
+    ///
+    /// `UartFuture::read_until_idle` returns once the RX line has been idle
+    /// for this long after the last received word. The SERCOM has no receive
+    /// timeout, so this is only a setting for the `async` API, and doesn't
+    /// touch the peripheral. The default is 10 bit times, one 8N1 character.
+    ///
+    /// The delay starts when a word is received, so the next word is still
+    /// being shifted in meanwhile. The length of a whole frame, including
+    /// the start bit, parity and stop bits, is therefore added to this idle
+    /// time.
+    #[inline]
+    pub fn rx_idle_timeout(mut self, bits: u16) -> Self {
+        self.set_rx_idle_timeout(bits);
+        self
+    }
+
+    /// Set the idle time that ends a frame, in bit times (setter version)
+    #[inline]
+    pub fn set_rx_idle_timeout(&mut self, bits: u16) {
+        self.rx_idle_bits = bits;
+    }
+
+    /// Get the idle time that ends a frame, in bit times
+    #[inline]
+    pub fn get_rx_idle_timeout(&self) -> u16 {
+        self.rx_idle_bits
+    }
+
+    /// Get the length of a frame, in bit times
+    ///
+    /// This counts the start bit, the data bits, the parity bit if any, and
+    /// the stop bits.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub(super) fn frame_bits(&self) -> u16 {
+        let data_bits = match self.registers.get_char_size() {
+            CharSizeEnum::FiveBit => 5,
+            CharSizeEnum::SixBit => 6,
+            CharSizeEnum::SevenBit => 7,
+            CharSizeEnum::EightBit => 8,
+            CharSizeEnum::NineBit => 9,
+        };
+        let parity_bits = match self.get_parity() {
+            Parity::None => 0,
+            Parity::Even | Parity::Odd => 1,
+        };
+        let stop_bits = match self.get_stop_bits() {
+            StopBits::OneBit => 1,
+            StopBits::TwoBits => 2,
+        };
+        1 + data_bits + parity_bits + stop_bits
+    }
+
+    /// Get the lowest and highest baud rates achievable in `mode`
+    ///
+    /// The highest baud rate is the GCLK frequency divided by the
+    /// [`Oversampling`] of `mode`, whose value is the number of samples per
+    /// bit.
+    ///
+    /// [`Oversampling`]: super::Oversampling
+    #[inline]
+    pub fn baud_rate_range(&self, mode: BaudMode) -> (Hertz, Hertz) {
+        baud_rate_range(self.freq, mode)
+    }
+
     /// Set the baud rate (builder pattern version)
     ///
     /// This function will calculate the best BAUD register setting based on the
@@ -269,6 +440,11 @@ where
     /// the maximum supported baud rate.
     ///
     /// Note that 3x oversampling is not supported.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above the maximum baud rate
+    /// returned by [`baud_rate_range`](Self::baud_rate_range).
     #[inline]
     pub fn baud(mut self, baud: Hertz, mode: BaudMode) -> Self {
         self.set_baud(baud, mode);
@@ -283,8 +459,17 @@ where
     /// the maximum supported baud rate.
     ///
     /// Note that 3x oversampling is not supported.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `baud` is above the maximum baud rate
+    /// returned by [`baud_rate_range`](Self::baud_rate_range).
     #[inline]
     pub fn set_baud(&mut self, baud: Hertz, mode: BaudMode) {
+        debug_assert!(
+            baud <= self.baud_rate_range(mode).1,
+            "UART baud rate above GCLK / oversampling"
+        );
         self.registers.set_baud(self.freq, baud, mode);
     }
 
@@ -297,11 +482,54 @@ where
         self.registers.get_baud()
     }
 
+    /// Get the actual baud rate
+    ///
+    /// The baud rate is computed from the contents of the `BAUD` register and
+    /// the GCLK frequency, so it includes the rounding and saturation applied
+    /// by [`set_baud`](Self::set_baud). Comparing it to the requested rate
+    /// gives the baud rate error.
+    #[inline]
+    pub fn get_baud_rate(&self) -> Hertz {
+        self.registers.get_baud_rate(self.freq)
+    }
+
+    /// Set the baud rate to the one measured from a sync byte
+    ///
+    /// `edges` are the timestamps of the edges of a
+    /// [`SYNC_BYTE`](super::SYNC_BYTE), in ticks of `tick_rate`, as returned
+    /// by [`capture_sync_edges`](super::capture_sync_edges). The measured rate
+    /// is used as is, so it may be worth rounding it to the nearest standard
+    /// rate with [`measure_sync_byte`](super::measure_sync_byte) and
+    /// [`baud`](Self::baud) instead.
+    ///
+    /// Returns [`AutoBaudError::OutOfRange`] if the measured rate can't be
+    /// generated from the GCLK frequency in `mode`, in which case the
+    /// [`Config`] is dropped.
+    #[inline]
+    pub fn baud_from_sync(
+        mut self,
+        edges: &[u32; SYNC_EDGES],
+        tick_rate: Hertz,
+        mode: BaudMode,
+    ) -> Result<Self, AutoBaudError> {
+        let baud = measure_sync_byte(edges, tick_rate)?;
+        let (min, max) = self.baud_rate_range(mode);
+        if baud < min || baud > max {
+            return Err(AutoBaudError::OutOfRange(baud));
+        }
+        self.set_baud(baud, mode);
+        Ok(self)
+    }
+
     /// Control the buffer overflow notification (builder pattern version)
     ///
     /// If set to true, an [`Error::Overflow`](super::Error::Overflow) will be
     /// issued as soon as an overflow occurs. Otherwise, it will not be
     /// issued until its place within the data stream.
+    ///
+    /// This maps to `CTRLA.IBON`, and changes when `Status::BUFOVF` and
+    /// [`Flags::ERROR`](super::Flags::ERROR) are set. See the
+    /// [module-level documentation](super#overflow-notification) for details.
     #[inline]
     pub fn immediate_overflow_notification(mut self, set: bool) -> Self {
         self.set_immediate_overflow_notification(set);
@@ -313,6 +541,10 @@ where
     /// If set to true, an [`Error::Overflow`](super::Error::Overflow) will be
     /// issued as soon as an overflow occurs. Otherwise, it will not be
     /// issued until its place within the data stream.
+    ///
+    /// This maps to `CTRLA.IBON`, and changes when `Status::BUFOVF` and
+    /// [`Flags::ERROR`](super::Flags::ERROR) are set. See the
+    /// [module-level documentation](super#overflow-notification) for details.
     #[inline]
     pub fn set_immediate_overflow_notification(&mut self, set: bool) {
         self.registers.set_immediate_overflow_notification(set);
@@ -324,6 +556,64 @@ where
         self.registers.get_immediate_overflow_notification()
     }
 
+    /// Invert the RX line (builder pattern version)
+    ///
+    /// When set, the level sampled on the RX pad is inverted before being
+    /// decoded. This allows connecting transceivers or level shifters which
+    /// invert the signal. Only available on SAMx5x chips.
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn invert_rx(mut self, set: bool) -> Self {
+        self.set_invert_rx(set);
+        self
+    }
+
+    /// Invert the RX line (setter version)
+    ///
+    /// When set, the level sampled on the RX pad is inverted before being
+    /// decoded. This allows connecting transceivers or level shifters which
+    /// invert the signal. Only available on SAMx5x chips.
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn set_invert_rx(&mut self, set: bool) {
+        self.registers.set_rx_inversion(set);
+    }
+
+    /// Get the current RX line inversion setting
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn get_invert_rx(&self) -> bool {
+        self.registers.get_rx_inversion()
+    }
+
+    /// Invert the TX line (builder pattern version)
+    ///
+    /// When set, the level driven on the TX pad is inverted, including the
+    /// idle state, which becomes low. Only available on SAMx5x chips.
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn invert_tx(mut self, set: bool) -> Self {
+        self.set_invert_tx(set);
+        self
+    }
+
+    /// Invert the TX line (setter version)
+    ///
+    /// When set, the level driven on the TX pad is inverted, including the
+    /// idle state, which becomes low. Only available on SAMx5x chips.
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn set_invert_tx(&mut self, set: bool) {
+        self.registers.set_tx_inversion(set);
+    }
+
+    /// Get the current TX line inversion setting
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn get_invert_tx(&self) -> bool {
+        self.registers.get_tx_inversion()
+    }
+
     /// Run in standby mode (builder pattern version)
     ///
     /// When set, the UART peripheral will run in standby mode. See the
@@ -349,6 +639,110 @@ where
         self.registers.get_run_in_standby()
     }
 
+    /// Run during debug mode (builder pattern version)
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger, so that no transfer makes progress past a breakpoint.
+    /// The UART runs during debug by default.
+    #[inline]
+    pub fn run_during_debug(mut self, set: bool) -> Self {
+        self.set_run_during_debug(set);
+        self
+    }
+
+    /// Run during debug mode (setter version)
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger, so that no transfer makes progress past a breakpoint.
+    /// The UART runs during debug by default.
+    #[inline]
+    pub fn set_run_during_debug(&mut self, set: bool) {
+        self.registers.set_run_during_debug(set);
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub fn get_run_during_debug(&self) -> bool {
+        self.registers.get_run_during_debug()
+    }
+
+    /// Enable or disable internal loopback (builder pattern version)
+    ///
+    /// When enabled, the receiver samples the TX pad instead of the RX pad, so
+    /// every transmitted character is received back. See the
+    /// [Loopback](super#loopback) section of the module documentation.
+    #[inline]
+    pub fn loopback(mut self, set: bool) -> Self {
+        self.set_loopback(set);
+        self
+    }
+
+    /// Enable or disable internal loopback (setter version)
+    ///
+    /// When enabled, the receiver samples the TX pad instead of the RX pad, so
+    /// every transmitted character is received back. See the
+    /// [Loopback](super#loopback) section of the module documentation.
+    #[inline]
+    pub fn set_loopback(&mut self, set: bool) {
+        let rxpo = if set {
+            self.registers.tx_pad()
+        } else {
+            P::RXPO
+        };
+        self.registers.set_rxpo(rxpo);
+        self.loopback = set;
+    }
+
+    /// Get the current internal loopback setting
+    ///
+    /// The setting is tracked by the [`Config`], rather than read back from
+    /// `RXPO`, since the RX pad of some [`Pads`](super::Pads) is the TX pad
+    /// anyway.
+    #[inline]
+    pub fn get_loopback(&self) -> bool {
+        self.loopback
+    }
+
+    /// Enable or disable a loopback which reports framing errors (builder
+    /// pattern version)
+    ///
+    /// This is a testing aid, to run the receive error path of an application
+    /// on real hardware. It is not meant for production use. See the [Error
+    /// injection](super#error-injection) section of the module documentation.
+    /// Only available on SAMx5x chips, with the `error-injection` Cargo
+    /// feature.
+    #[cfg(feature = "error-injection")]
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn frame_error_loopback(mut self, set: bool) -> Self {
+        self.set_frame_error_loopback(set);
+        self
+    }
+
+    /// Enable or disable a loopback which reports framing errors (setter
+    /// version)
+    ///
+    /// The receiver samples the TX pad, as with [`loopback`](Self::loopback),
+    /// but the TX line is inverted. Every transmitted character then ends
+    /// with a falling edge, which the receiver decodes as the start of a
+    /// character whose stop bit is low, and reports as
+    /// [`Error::FrameError`](super::Error::FrameError).
+    #[cfg(feature = "error-injection")]
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn set_frame_error_loopback(&mut self, set: bool) {
+        self.set_loopback(set);
+        self.set_invert_tx(set);
+    }
+
+    /// Get the current framing error loopback setting
+    #[cfg(feature = "error-injection")]
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub fn get_frame_error_loopback(&self) -> bool {
+        self.get_loopback() && self.get_invert_tx()
+    }
+
     /// Enable or disable IrDA encoding (builder pattern version)
     ///
     /// The pulse length controls the minimum pulse length that is required for
@@ -376,6 +770,66 @@ where
     pub fn get_irda_encoding(&self) -> Option<u8> {
         self.registers.get_irda_encoding()
     }
+
+    /// Get a [`ConfigSummary`] of the current settings
+    ///
+    /// The settings are read back from the peripheral, so the summary reflects
+    /// what the hardware actually does.
+    #[inline]
+    pub fn config_summary(&self) -> ConfigSummary {
+        let (_, baud_mode) = self.get_baud();
+        ConfigSummary {
+            baud: self.get_baud_rate(),
+            baud_mode,
+            char_size: self.registers.get_char_size(),
+            parity: self.get_parity(),
+            stop_bits: self.get_stop_bits(),
+            bit_order: self.get_bit_order(),
+            rxpo: self.registers.get_rxpo(),
+            txpo: self.registers.get_txpo(),
+            interrupts: self.registers.read_interrupts(),
+        }
+    }
+}
+
+#[hal_cfg("sercom0-d5x")]
+impl<P, C> Config<P, C>
+where
+    P: ValidPads<Cts = NoneT>,
+    P::Rts: SomePad<PadNum = Pad2>,
+    C: CharSize,
+{
+    /// Enable RS-485 mode, with a guard time of `guard_time` bit times
+    ///
+    /// RS-485 mode requires a driver-enable pad, set with
+    /// [`Pads::de`](super::Pads::de), and no `CTS` pad. The hardware drives
+    /// the driver-enable pad high before the first start bit of a
+    /// transmission, and low `guard_time` bit times after the last stop bit.
+    /// The guard time is at most 7 bit times. Larger values saturate.
+    ///
+    /// This mode is selected by the pads alone. This method only makes the
+    /// guard time explicit, and is only available for matching [`Pads`].
+    ///
+    /// [`Pads`]: super::Pads
+    #[inline]
+    pub fn rs485(mut self, guard_time: u8) -> Self {
+        self.set_guard_time(guard_time);
+        self
+    }
+
+    /// Change the RS-485 guard time, in bit times (setter version)
+    ///
+    /// The guard time is at most 7 bit times. Larger values saturate.
+    #[inline]
+    pub fn set_guard_time(&mut self, guard_time: u8) {
+        self.registers.set_guard_time(guard_time.min(7));
+    }
+
+    /// Get the current RS-485 guard time, in bit times
+    #[inline]
+    pub fn get_guard_time(&self) -> u8 {
+        self.registers.get_guard_time()
+    }
 }
 
 impl<P: ValidPads> Config<P, DynCharSize> {
@@ -410,10 +864,79 @@ where
             capability: PhantomData,
             rx_channel: NoneT,
             tx_channel: NoneT,
+            tx_pending: false,
+        }
+    }
+}
+
+impl<P, C> Config<P, C>
+where
+    P: ValidPads<Capability = Tx>,
+    C: CharSize,
+    Self: ValidConfig,
+{
+    /// Enable the UART peripheral in internal loopback, and return a
+    /// [`Duplex`] [`Uart`]
+    ///
+    /// With transmit-only [`Pads`], the receiver can still sample the TX pad.
+    /// This enables both the transmitter and the receiver, so the returned
+    /// [`Uart`] reads back every character it sends, without an RX pin. The
+    /// receiver has no other pad to sample, so the loopback stays enabled
+    /// until the [`Uart`] is [`disable`](Uart::disable)d.
+    ///
+    /// [`Pads`]: super::Pads
+    #[inline]
+    pub fn enable_loopback(mut self) -> Uart<Self, Duplex> {
+        self.set_loopback(true);
+        self.registers.enable(true, true);
+        Uart {
+            config: self,
+            capability: PhantomData,
+            rx_channel: NoneT,
+            tx_channel: NoneT,
+            tx_pending: false,
         }
     }
 }
 
+//=============================================================================
+// ConfigSummary
+//=============================================================================
+
+/// Snapshot of the human-relevant settings of a UART
+///
+/// Returned by [`Config::config_summary`] and
+/// [`Uart::config_summary`](super::Uart::config_summary). It is meant to be
+/// printed during bring-up, rather than decoding the register values by hand.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigSummary {
+    /// Actual baud rate, including the rounding of the `BAUD` register
+    pub baud: Hertz,
+    /// Baud rate calculation mode and oversampling
+    pub baud_mode: BaudMode,
+    /// Character size
+    pub char_size: CharSizeEnum,
+    /// Parity setting
+    pub parity: Parity,
+    /// Number of stop bits
+    pub stop_bits: StopBits,
+    /// Bit order
+    pub bit_order: BitOrder,
+    /// Contents of the `CTRLA.RXPO` field
+    pub rxpo: u8,
+    /// Contents of the `CTRLA.TXPO` field
+    pub txpo: u8,
+    /// Enabled interrupts
+    pub interrupts: Flags,
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ConfigSummary {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", defmt::Debug2Format(self));
+    }
+}
+
 //=============================================================================
 // AnyConfig
 //=============================================================================
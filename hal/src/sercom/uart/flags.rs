@@ -26,7 +26,7 @@ bitflags! {
     /// The available interrupt flags are `DRE`, `TXC`, `RXC`, `RXS`, `CTSIC`, `RXBRK` and
     /// `ERROR`. The binary format of the underlying bits exactly matches the
     /// INTFLAG bits.
-    #[derive(Clone, Copy)]
+    #[derive(Debug, Clone, Copy)]
     pub struct Flags: u8 {
         const DRE = DRE;
         const TXC = TXC;
@@ -128,6 +128,8 @@ pub enum Error {
     InconsistentSyncField,
     /// Detected a collision
     CollisionDetected,
+    /// The baud rate could not be measured from a break and sync byte
+    AutoBaudFailed,
     /// DMA error
     #[cfg(feature = "dma")]
     Dma(crate::dmac::Error),
@@ -143,6 +145,7 @@ impl From<Error> for Status {
             Overflow => Status::BUFOVF,
             InconsistentSyncField => Status::ISF,
             CollisionDetected => Status::COLL,
+            AutoBaudFailed => Status::ISF.union(Status::FERR),
             #[cfg(feature = "dma")]
             Dma(_) => unimplemented!(),
         }
@@ -0,0 +1,115 @@
+//! [`core::fmt::Write`] support for [`Uart`]s
+//!
+//! See the [module-level](super#formatted-output) documentation for details.
+
+use core::fmt;
+
+use super::{Config, EightBit, Transmit, Uart, ValidPads};
+use crate::typelevel::NoneT;
+
+/// Write formatted text to the [`Uart`], one byte at a time
+///
+/// Each byte blocks until the `DRE` flag is set. The text is sent unchanged,
+/// use [`Uart::crlf`] to translate line endings. Any error reported by the
+/// [`Uart`] is mapped to [`fmt::Error`].
+impl<P, D, R> fmt::Write for Uart<Config<P, EightBit>, D, R, NoneT>
+where
+    P: ValidPads,
+    D: Transmit,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        embedded_io::Write::write_all(self, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+impl<P, D, R> Uart<Config<P, EightBit>, D, R, NoneT>
+where
+    P: ValidPads,
+    D: Transmit,
+{
+    /// Borrow the [`Uart`] as a [`fmt::Write`] sink which translates every
+    /// `\n` into `\r\n`
+    #[inline]
+    pub fn crlf(&mut self) -> CrLf<&mut Self> {
+        CrLf::new(self)
+    }
+}
+
+/// [`fmt::Write`] adapter which translates every `\n` into `\r\n`
+///
+/// Most serial terminals expect `\r\n` line endings, which allows using
+/// [`writeln!`] directly. `CrLf` can wrap any [`embedded_io::Write`]
+/// implementation, but is usually obtained with [`Uart::crlf`]. Errors from the
+/// inner writer are mapped to [`fmt::Error`].
+pub struct CrLf<W> {
+    inner: W,
+}
+
+impl<W: embedded_io::Write> CrLf<W> {
+    /// Wrap `inner` in a [`CrLf`] adapter
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Return the inner writer
+    #[inline]
+    pub fn free(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: embedded_io::Write> fmt::Write for CrLf<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.inner.write_all(b"\r\n").map_err(|_| fmt::Error)?;
+            }
+            self.inner
+                .write_all(line.as_bytes())
+                .map_err(|_| fmt::Error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    /// Writer which records everything into a fixed-size buffer, and fails
+    /// once it is full
+    struct Sink(heapless::Vec<u8, 32>);
+
+    impl embedded_io::ErrorType for Sink {
+        type Error = embedded_io::ErrorKind;
+    }
+
+    impl embedded_io::Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            self.0
+                .extend_from_slice(buf)
+                .map_err(|_| embedded_io::ErrorKind::OutOfMemory)?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn crlf_translates_line_endings() {
+        let mut writer = CrLf::new(Sink(heapless::Vec::new()));
+        write!(writer, "x = {}\ny\n\n", 42).unwrap();
+        assert_eq!(writer.free().0.as_slice(), b"x = 42\r\ny\r\n\r\n");
+    }
+
+    #[test]
+    fn crlf_maps_errors() {
+        let mut writer = CrLf::new(Sink(heapless::Vec::new()));
+        assert!(write!(writer, "{:40}", "too long").is_err());
+    }
+}
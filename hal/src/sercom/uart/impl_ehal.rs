@@ -163,8 +163,12 @@ where
     D: Transmit,
 {
     /// Wait for a `DRE` flag, then write a word
+    ///
+    /// Returns [`UartError::CollisionDetected`] if a collision disabled the
+    /// transmitter.
     #[inline]
     fn write(&mut self, word: C::Word) -> nb::Result<(), Self::Error> {
+        self.check_collision()?;
         if self.read_flags().contains(Flags::DRE) {
             unsafe { self.write_data(word.as_()) };
             Ok(())
@@ -174,10 +178,15 @@ where
     }
 
     /// Wait for a `TXC` flag
+    ///
+    /// Returns [`UartError::CollisionDetected`] if a collision aborted the
+    /// transmission.
     #[inline]
     fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.check_collision()?;
         if self.read_flags().contains(Flags::TXC) {
             self.clear_flags(Flags::TXC);
+            self.tx_pending = false;
             Ok(())
         } else {
             Err(WouldBlock)
@@ -0,0 +1,169 @@
+//! LIN master header generation
+//!
+//! A LIN frame starts with a header sent by the master: a break field, the
+//! [`SYNC_BYTE`], and the protected identifier of the frame. In LIN master
+//! mode, enabled with [`Config::lin_master`], the SERCOM generates the whole
+//! header in hardware, when [`Uart::send_lin_header`] writes the protected
+//! identifier to the `DATA` register. The response bytes are then written and
+//! read like any other character, with the usual blocking or `nb` methods.
+//!
+//! LIN frames use 8 data bits, no parity and one stop bit, the default
+//! settings of a [`Config`].
+//!
+//! [`SYNC_BYTE`]: super::SYNC_BYTE
+
+use super::{CharSize, Config, DataReg, Flags, Transmit, Uart, ValidConfig, ValidPads};
+
+/// Length of the break field of a LIN header
+///
+/// The LIN specification requires a break of at least 13 bit times.
+/// [`Bits13`](Self::Bits13) is the reset value.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BreakLength {
+    /// 13 bit times
+    #[default]
+    Bits13 = 0,
+    /// 17 bit times
+    Bits17 = 1,
+    /// 21 bit times
+    Bits21 = 2,
+    /// 26 bit times
+    Bits26 = 3,
+}
+
+/// Delays between the fields of a LIN header
+///
+/// Each variant gives the delay between the break field and the sync byte,
+/// then the delay between the sync byte and the protected identifier.
+/// [`Bits1`](Self::Bits1) is the reset value.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeaderDelay {
+    /// 1 bit time, then 1 bit time
+    #[default]
+    Bits1 = 0,
+    /// 4 bit times, then 4 bit times
+    Bits4 = 1,
+    /// 8 bit times, then 4 bit times
+    Bits8 = 2,
+    /// 14 bit times, then 4 bit times
+    Bits14 = 3,
+}
+
+/// Settings of LIN master mode
+///
+/// The default settings, a 13-bit break and 1-bit delays, are the shortest
+/// header allowed by the LIN specification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinConfig {
+    /// Length of the break field
+    pub break_length: BreakLength,
+    /// Delays between the break field, the sync byte and the identifier
+    pub header_delay: HeaderDelay,
+}
+
+/// Compute the protected identifier of a 6-bit LIN frame identifier
+///
+/// The protected identifier holds the identifier in bits 0 to 5, and its two
+/// parity bits in bits 6 and 7. Bits 6 and 7 of `id` are ignored.
+#[inline]
+pub const fn protected_id(id: u8) -> u8 {
+    let id = id & 0x3F;
+    let p0 = (id ^ id >> 1 ^ id >> 2 ^ id >> 4) & 1;
+    let p1 = !(id >> 1 ^ id >> 3 ^ id >> 4 ^ id >> 5) & 1;
+    id | p0 << 6 | p1 << 7
+}
+
+impl<P, C> Config<P, C>
+where
+    P: ValidPads,
+    C: CharSize,
+{
+    /// Enable LIN master mode, with the given [`LinConfig`]
+    ///
+    /// In LIN master mode, [`Uart::send_lin_header`] generates the break
+    /// field and the sync byte in hardware. Changing the parity afterwards
+    /// leaves LIN master mode.
+    #[inline]
+    pub fn lin_master(mut self, lin: LinConfig) -> Self {
+        self.set_lin_master(lin);
+        self
+    }
+
+    /// Enable LIN master mode, with the given [`LinConfig`] (setter version)
+    #[inline]
+    pub fn set_lin_master(&mut self, lin: LinConfig) {
+        self.registers
+            .set_lin_master(lin.break_length as u8, lin.header_delay as u8);
+    }
+
+    /// Get the current [`LinConfig`], or `None` if LIN master mode is
+    /// disabled
+    #[inline]
+    pub fn get_lin_master(&self) -> Option<LinConfig> {
+        self.registers
+            .get_lin_master()
+            .map(|(break_length, header_delay)| LinConfig {
+                break_length: match break_length {
+                    0 => BreakLength::Bits13,
+                    1 => BreakLength::Bits17,
+                    2 => BreakLength::Bits21,
+                    _ => BreakLength::Bits26,
+                },
+                header_delay: match header_delay {
+                    0 => HeaderDelay::Bits1,
+                    1 => HeaderDelay::Bits4,
+                    2 => HeaderDelay::Bits8,
+                    _ => HeaderDelay::Bits14,
+                },
+            })
+    }
+}
+
+impl<C, D, R, T> Uart<C, D, R, T>
+where
+    C: ValidConfig<Word = u8>,
+    D: Transmit,
+{
+    /// Send a LIN header, and wait for it to complete
+    ///
+    /// The SERCOM sends the break field, the [`SYNC_BYTE`] and `pid`, a
+    /// protected identifier as computed by [`protected_id`]. The [`Config`]
+    /// must be in LIN master mode, see [`Config::lin_master`].
+    ///
+    /// A character still waiting in the `DATA` register is sent before the
+    /// header. Once the header is sent, the [`Uart`] is back to normal
+    /// transmission, for the response bytes.
+    ///
+    /// [`SYNC_BYTE`]: super::SYNC_BYTE
+    #[inline]
+    pub fn send_lin_header(&mut self, pid: u8) {
+        debug_assert!(
+            self.config.as_ref().registers.get_lin_master().is_some(),
+            "LIN headers can only be sent in LIN master mode"
+        );
+        // Don't clobber a pending character
+        while !self.read_flags().contains(Flags::DRE) {}
+        self.config.as_mut().registers.set_lin_auto_header(true);
+        self.clear_flags(Flags::TXC);
+        unsafe { self.write_data(pid as DataReg) };
+        while !self.read_flags().contains(Flags::TXC) {}
+        self.clear_flags(Flags::TXC);
+        self.config.as_mut().registers.set_lin_auto_header(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_id_parity() {
+        assert_eq!(protected_id(0x00), 0x80);
+        assert_eq!(protected_id(0x3C), 0x3C);
+        assert_eq!(protected_id(0x3D), 0x7D);
+        // The parity bits of the input are ignored
+        assert_eq!(protected_id(0xFD), 0x7D);
+    }
+}
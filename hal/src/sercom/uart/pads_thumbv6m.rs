@@ -199,6 +199,79 @@ where
     RTS: OptionalPad,
     CTS: OptionalPad,
 {
+    /// Create a set of [`Pads`] from the pins of every role at once
+    ///
+    /// Each pin can be in any [`PinMode`](crate::gpio::PinMode), and is
+    /// converted to the mode of its [`Pad`]. Pass [`NoneT`] for the roles
+    /// which are not used. Since the pad types can't be inferred from the
+    /// pins alone, this is most useful to build a `PadsFromIds` type in a
+    /// single call, on the chips which have it.
+    ///
+    /// ```
+    /// use atsamd_hal::pac::Peripherals;
+    /// use atsamd_hal::gpio::{PA08, PA09, Pins};
+    /// use atsamd_hal::sercom::{Sercom0, uart};
+    /// use atsamd_hal::typelevel::NoneT;
+    ///
+    /// pub type Pads = uart::PadsFromIds<Sercom0, PA09, PA08>;
+    ///
+    /// pub fn create_pads() -> Pads {
+    ///     let peripherals = Peripherals::take().unwrap();
+    ///     let pins = Pins::new(peripherals.port);
+    ///     Pads::new(pins.pa09, pins.pa08, NoneT, NoneT)
+    /// }
+    /// ```
+    #[inline]
+    pub fn new(
+        rx: impl Into<RX>,
+        tx: impl Into<TX>,
+        rts: impl Into<RTS>,
+        cts: impl Into<CTS>,
+    ) -> Self {
+        Self {
+            sercom: PhantomData,
+            receive: rx.into(),
+            transmit: tx.into(),
+            ready_to_send: rts.into(),
+            clear_to_send: cts.into(),
+        }
+    }
+
+    /// Describe the SERCOM and pad of each role at run time
+    ///
+    /// This is meant to be logged during the bring-up of a new board, to
+    /// check the pin assignment against the schematic.
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use atsamd_hal::pac::Peripherals;
+    /// use atsamd_hal::gpio::{PA08, PA09, Pins};
+    /// use atsamd_hal::sercom::{uart, Sercom0};
+    /// use atsamd_hal::typelevel::NoneT;
+    ///
+    /// pub fn log_pads(log: &mut impl Write) {
+    ///     let peripherals = Peripherals::take().unwrap();
+    ///     let pins = Pins::new(peripherals.port);
+    ///     let pads = uart::PadsFromIds::<Sercom0, PA09, PA08>::new(
+    ///         pins.pa09, pins.pa08, NoneT, NoneT,
+    ///     );
+    ///     // RX on pad 1 and TX on pad 0
+    ///     writeln!(log, "{:?}", pads.describe()).unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn describe(&self) -> PadsDescription<4> {
+        PadsDescription::new(
+            S::NUM,
+            [
+                ("RX", <RX as OptionalPad>::DYN),
+                ("TX", <TX as OptionalPad>::DYN),
+                ("RTS", <RTS as OptionalPad>::DYN),
+                ("CTS", <CTS as OptionalPad>::DYN),
+            ],
+        )
+    }
+
     /// Consume the [`Pads`] and return each individual [`Pad`]
     #[inline]
     pub fn free(self) -> (RX, TX, RTS, CTS) {
@@ -363,8 +436,8 @@ where
 ///
 /// pub fn create_pads() -> Pads {
 ///     let peripherals = Peripherals::take().unwrap();
-///     let pins = Pins::new(peripherals.PORT);
-///     uart::Pads::default().rx(pins.pa09).tx(pins.pa08)
+///     let pins = Pins::new(peripherals.port);
+///     Pads::new(pins.pa08, pins.pa09, NoneT, NoneT)
 /// }
 /// ```
 ///
@@ -199,6 +199,44 @@ where
     CTS: OptionalPad,
     (RX, TX, RTS, CTS): ShareIoSet,
 {
+    /// Create a set of [`Pads`] from the pins of every role at once
+    ///
+    /// Each pin can be in any [`PinMode`](crate::gpio::PinMode), and is
+    /// converted to the mode of its [`Pad`]. Pass [`NoneT`] for the roles
+    /// which are not used. Since the pad types can't be inferred from the
+    /// pins alone, this is most useful to build a [`PadsFromIds`] type in a
+    /// single call.
+    ///
+    /// ```
+    /// use atsamd_hal::pac::Peripherals;
+    /// use atsamd_hal::gpio::{PA08, PA09, Pins};
+    /// use atsamd_hal::sercom::{Sercom0, uart};
+    /// use atsamd_hal::typelevel::NoneT;
+    ///
+    /// pub type Pads = uart::PadsFromIds<Sercom0, PA09, PA08>;
+    ///
+    /// pub fn create_pads() -> Pads {
+    ///     let peripherals = Peripherals::take().unwrap();
+    ///     let pins = Pins::new(peripherals.port);
+    ///     Pads::new(pins.pa09, pins.pa08, NoneT, NoneT)
+    /// }
+    /// ```
+    #[inline]
+    pub fn new(
+        rx: impl Into<RX>,
+        tx: impl Into<TX>,
+        rts: impl Into<RTS>,
+        cts: impl Into<CTS>,
+    ) -> Self {
+        Self {
+            sercom: PhantomData,
+            receive: rx.into(),
+            transmit: tx.into(),
+            ready_to_send: rts.into(),
+            clear_to_send: cts.into(),
+        }
+    }
+
     /// Set the `RX` [`Pad`]
     #[inline]
     pub fn rx<Id>(self, pin: impl AnyPin<Id = Id>) -> Pads<S, Pad<S, Id>, TX, RTS, CTS>
@@ -250,6 +288,21 @@ where
         }
     }
 
+    /// Set the RS-485 driver-enable [`Pad`], which is always [`Pad2`]
+    ///
+    /// This is the `RTS` pad. When there is no `CTS` pad, the SERCOM drives
+    /// it high while transmitting, to enable the driver of an RS-485
+    /// transceiver. See [`Config::rs485`](super::Config::rs485).
+    #[inline]
+    pub fn de<Id>(self, pin: impl AnyPin<Id = Id>) -> Pads<S, RX, TX, Pad<S, Id>, CTS>
+    where
+        Id: GetPad<S>,
+        (RX, TX, Pad<S, Id>, CTS): ShareIoSet,
+        Pin<Id, <Id as GetPad<S>>::PinMode>: IsPad,
+    {
+        self.rts(pin)
+    }
+
     /// Set the `CTS` [`Pad`], which is always [`Pad3`]
     #[inline]
     pub fn cts<Id>(self, pin: impl AnyPin<Id = Id>) -> Pads<S, RX, TX, RTS, Pad<S, Id>>
@@ -267,6 +320,42 @@ where
         }
     }
 
+    /// Describe the SERCOM, IOSET and pad of each role at run time
+    ///
+    /// This is meant to be logged during the bring-up of a new board, to
+    /// check the pin assignment against the schematic.
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use atsamd_hal::pac::Peripherals;
+    /// use atsamd_hal::gpio::Pins;
+    /// use atsamd_hal::sercom::{pad::DynIoSet, uart, Sercom0};
+    ///
+    /// pub fn log_pads(log: &mut impl Write) {
+    ///     let peripherals = Peripherals::take().unwrap();
+    ///     let pins = Pins::new(peripherals.port);
+    ///     let pads = uart::Pads::<Sercom0>::default()
+    ///         .rx(pins.pa09)
+    ///         .tx(pins.pa08);
+    ///     let description = pads.describe();
+    ///     // RX on pad 1 and TX on pad 0, both in IOSET 1
+    ///     assert_eq!(description.io_set, Some(DynIoSet::IoSet1));
+    ///     writeln!(log, "{:?}", description).unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn describe(&self) -> PadsDescription<4> {
+        PadsDescription::new(
+            S::NUM,
+            [
+                ("RX", <RX as OptionalPad>::DYN),
+                ("TX", <TX as OptionalPad>::DYN),
+                ("RTS", <RTS as OptionalPad>::DYN),
+                ("CTS", <CTS as OptionalPad>::DYN),
+            ],
+        )
+    }
+
     /// Consume the [`Pads`] and return each individual [`Pad`]
     #[inline]
     pub fn free(self) -> (RX, TX, RTS, CTS) {
@@ -299,7 +388,7 @@ where
 ///
 /// pub fn create_pads() -> Pads {
 ///     let peripherals = Peripherals::take().unwrap();
-///     let pins = Pins::new(peripherals.PORT);
+///     let pins = Pins::new(peripherals.port);
 ///     uart::Pads::default().rx(pins.pa09).tx(pins.pa08)
 /// }
 /// ```
@@ -407,6 +496,18 @@ where
     type Capability = Tx;
 }
 
+/// Transmit-only [`Pads`] with an RS-485 driver-enable pad
+impl<S, TX, DE> ValidPads for Pads<S, NoneT, TX, DE, NoneT>
+where
+    S: Sercom,
+    TX: SomePad,
+    DE: SomePad,
+    (NoneT, TX, DE, NoneT): ShareIoSet,
+    Self: PadSet + RxpoTxpo,
+{
+    type Capability = Tx;
+}
+
 impl<S, RX, TX, RTS, CTS> ValidPads for Pads<S, RX, TX, RTS, CTS>
 where
     S: Sercom,
@@ -431,3 +532,36 @@ where
 pub trait ValidConfig: AnyConfig {}
 
 impl<P: ValidPads, C: CharSize> ValidConfig for Config<P, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rxpo_txpo<P: RxpoTxpo>() -> (u8, u8) {
+        (P::RXPO, P::TXPO)
+    }
+
+    /// Check the pad layouts of the `CTRLA.TXPO` table of the datasheet
+    #[test]
+    fn datasheet_txpo() {
+        // TXPO = 0: TX on Pad0, XCK on Pad1
+        assert_eq!(rxpo_txpo::<(Pad3, Pad0, NoneT, NoneT)>(), (3, 0));
+        assert_eq!(rxpo_txpo::<(NoneT, Pad0, NoneT, NoneT)>(), (0, 0));
+        // TXPO = 2: TX on Pad0, RTS on Pad2, CTS on Pad3
+        assert_eq!(rxpo_txpo::<(Pad1, Pad0, Pad2, Pad3)>(), (1, 2));
+        assert_eq!(rxpo_txpo::<(NoneT, Pad0, Pad2, Pad3)>(), (0, 2));
+        assert_eq!(rxpo_txpo::<(Pad1, NoneT, Pad2, Pad3)>(), (1, 2));
+        // TXPO = 3: TX on Pad0, RTS/TE on Pad2
+        assert_eq!(rxpo_txpo::<(Pad3, Pad0, Pad2, NoneT)>(), (3, 3));
+        assert_eq!(rxpo_txpo::<(NoneT, Pad0, Pad2, NoneT)>(), (0, 3));
+    }
+
+    /// Check the `CTRLA.RXPO` value of each RX pad
+    #[test]
+    fn datasheet_rxpo() {
+        assert_eq!(rxpo_txpo::<(Pad1, Pad0, Pad2, Pad3)>().0, 1);
+        assert_eq!(rxpo_txpo::<(Pad2, Pad0, NoneT, NoneT)>().0, 2);
+        assert_eq!(rxpo_txpo::<(Pad3, Pad0, NoneT, NoneT)>().0, 3);
+        assert_eq!(rxpo_txpo::<(Pad0, NoneT, NoneT, NoneT)>().0, 0);
+    }
+}
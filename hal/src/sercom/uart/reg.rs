@@ -2,7 +2,10 @@
 
 use atsamd_hal_macros::hal_cfg;
 
-use super::{BaudMode, BitOrder, CharSizeEnum, Flags, Oversampling, Parity, Status, StopBits};
+use super::{
+    BaudMode, BitOrder, CharSizeEnum, Flags, Oversampling, Parity, SampleAdjustment, Status,
+    StopBits,
+};
 
 use crate::pac;
 use crate::sercom::Sercom;
@@ -81,6 +84,37 @@ impl<S: Sercom> Registers<S> {
         });
     }
 
+    /// Select the pad sampled by the receiver
+    #[inline]
+    pub(super) fn set_rxpo(&mut self, rxpo: u8) {
+        self.usart()
+            .ctrla()
+            .modify(|_, w| unsafe { w.rxpo().bits(rxpo) });
+    }
+
+    /// Get the pad sampled by the receiver
+    #[inline]
+    pub(super) fn get_rxpo(&self) -> u8 {
+        self.usart().ctrla().read().rxpo().bits()
+    }
+
+    /// Get the TXPO setting
+    #[inline]
+    pub(super) fn get_txpo(&self) -> u8 {
+        self.usart().ctrla().read().txpo().bits()
+    }
+
+    /// Get the pad driven by the transmitter
+    #[inline]
+    pub(super) fn tx_pad(&self) -> u8 {
+        // TXPO 1 places TX on PAD2 (SAMD11/SAMD21 only). Every other setting
+        // places it on PAD0.
+        match self.usart().ctrla().read().txpo().bits() {
+            1 => 2,
+            _ => 0,
+        }
+    }
+
     /// Configure the character size
     #[inline]
     pub(super) fn set_char_size(&mut self, size: CharSizeEnum) {
@@ -147,6 +181,17 @@ impl<S: Sercom> Registers<S> {
             .modify(|_, w| unsafe { w.form().bits(enabled as u8) });
     }
 
+    /// Enable or disable the auto-baud frame format, keeping the parity
+    /// setting
+    #[inline]
+    pub(super) fn set_auto_baud(&mut self, enabled: bool) {
+        self.usart().ctrla().modify(|r, w| {
+            let parity = r.form().bits() & 0x1;
+            let auto_baud = if enabled { 0x4 } else { 0x0 };
+            unsafe { w.form().bits(auto_baud | parity) }
+        });
+    }
+
     /// Get the current parity setting
     #[inline]
     pub(super) fn get_parity(&self) -> Parity {
@@ -186,6 +231,20 @@ impl<S: Sercom> Registers<S> {
         }
     }
 
+    /// Change the sample adjustment setting
+    #[inline]
+    pub(super) fn set_sample_adjustment(&mut self, adjustment: SampleAdjustment) {
+        self.usart()
+            .ctrla()
+            .modify(|_, w| unsafe { w.sampa().bits(adjustment as u8) });
+    }
+
+    /// Get the current sample adjustment setting
+    #[inline]
+    pub(super) fn get_sample_adjustment(&self) -> SampleAdjustment {
+        SampleAdjustment::from_bits(self.usart().ctrla().read().sampa().bits())
+    }
+
     /// Enable or disable the start of frame detector.
     ///
     /// When set, the UART will generate interrupts for
@@ -288,6 +347,35 @@ impl<S: Sercom> Registers<S> {
         (baud, mode)
     }
 
+    /// Restore the contents of the `BAUD` register, as returned by
+    /// [`get_baud`](Self::get_baud)
+    #[inline]
+    pub(super) fn set_baud_raw(&mut self, baud: u16) {
+        self.usart()
+            .baud_usartfp_mode()
+            .write(|w| unsafe { w.bits(baud) });
+    }
+
+    /// Get the actual baud rate, computed from the contents of the `BAUD`
+    /// register, the baud mode and the GCLK frequency
+    #[inline]
+    pub(super) fn get_baud_rate(&self, freq: Hertz) -> Hertz {
+        let (baud, mode) = self.get_baud();
+        let rate = match mode {
+            BaudMode::Arithmetic(n) => baud_rate_asynchronous_arithm(baud, freq.to_Hz(), n as u8),
+            BaudMode::Fractional(n) => {
+                let reg = self.usart().baud_frac_mode().read();
+                baud_rate_asynchronous_fractional(
+                    reg.baud().bits(),
+                    reg.fp().bits(),
+                    freq.to_Hz(),
+                    n as u8,
+                )
+            }
+        };
+        Hertz::from_raw(rate)
+    }
+
     /// Control the buffer overflow notification
     ///
     /// If set to true, an [`RxError::Overflow`] will be issued as soon as an
@@ -304,6 +392,95 @@ impl<S: Sercom> Registers<S> {
         self.usart().ctrla().read().ibon().bit()
     }
 
+    /// Enable LIN master mode, with the given `BRKLEN` and `HDRDLY` values
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn set_lin_master(&mut self, break_length: u8, header_delay: u8) {
+        let usart = self.usart();
+        usart.ctrlc().modify(|_, w| unsafe {
+            w.brklen().bits(break_length);
+            w.hdrdly().bits(header_delay)
+        });
+        usart
+            .ctrla()
+            .modify(|_, w| w.form().usart_frame_lin_master_mode());
+    }
+
+    /// Get the `BRKLEN` and `HDRDLY` values, or `None` if LIN master mode is
+    /// disabled
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn get_lin_master(&self) -> Option<(u8, u8)> {
+        let usart = self.usart();
+        if !usart.ctrla().read().form().is_usart_frame_lin_master_mode() {
+            return None;
+        }
+        let ctrlc = usart.ctrlc().read();
+        Some((ctrlc.brklen().bits(), ctrlc.hdrdly().bits()))
+    }
+
+    /// Select whether the next write to `DATA` sends a full LIN header,
+    /// or a normal character
+    ///
+    /// Like [`set_tx_enabled`](Self::set_tx_enabled), the read-modify-write
+    /// of `CTRLB` runs in a critical section.
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn set_lin_auto_header(&mut self, enabled: bool) {
+        let usart = self.usart();
+        critical_section::with(|_| {
+            usart.ctrlb().modify(|_, w| match enabled {
+                true => w.lincmd().auto_transmit_cmd(),
+                false => w.lincmd().none(),
+            });
+            while usart.syncbusy().read().ctrlb().bit_is_set() {}
+        });
+    }
+
+    /// Set the RS-485 guard time, in bit times
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn set_guard_time(&mut self, bits: u8) {
+        self.usart()
+            .ctrlc()
+            .modify(|_, w| unsafe { w.gtime().bits(bits) });
+    }
+
+    /// Get the current RS-485 guard time, in bit times
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn get_guard_time(&self) -> u8 {
+        self.usart().ctrlc().read().gtime().bits()
+    }
+
+    /// Invert the RX line
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn set_rx_inversion(&mut self, set: bool) {
+        self.usart().ctrla().modify(|_, w| w.rxinv().bit(set));
+    }
+
+    /// Get the current RX line inversion setting
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn get_rx_inversion(&self) -> bool {
+        self.usart().ctrla().read().rxinv().bit()
+    }
+
+    /// Invert the TX line
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn set_tx_inversion(&mut self, set: bool) {
+        self.usart().ctrla().modify(|_, w| w.txinv().bit(set));
+    }
+
+    /// Get the current TX line inversion setting
+    #[hal_cfg("sercom0-d5x")]
+    #[inline]
+    pub(super) fn get_tx_inversion(&self) -> bool {
+        self.usart().ctrla().read().txinv().bit()
+    }
+
     /// Run in standby mode
     ///
     /// When set, the UART peripheral will run in standby mode. See the
@@ -319,6 +496,21 @@ impl<S: Sercom> Registers<S> {
         self.usart().ctrla().read().runstdby().bit()
     }
 
+    /// Run during debug mode
+    ///
+    /// When cleared, the baud-rate generator is halted while the CPU is halted
+    /// by a debugger.
+    #[inline]
+    pub(super) fn set_run_during_debug(&mut self, set: bool) {
+        self.usart().dbgctrl().write(|w| w.dbgstop().bit(!set));
+    }
+
+    /// Get the current run during debug mode
+    #[inline]
+    pub(super) fn get_run_during_debug(&self) -> bool {
+        self.usart().dbgctrl().read().dbgstop().bit_is_clear()
+    }
+
     /// Enable or disable IrDA encoding. The pulse length controls the minimum
     // pulse length that is required for a pulse to be accepted by the IrDA
     /// receiver with regards to the serial engine clock period.
@@ -369,6 +561,12 @@ impl<S: Sercom> Registers<S> {
             .write(|w| unsafe { w.bits(flags.bits()) });
     }
 
+    /// Read the enabled interrupts
+    #[inline]
+    pub(super) fn read_interrupts(&self) -> Flags {
+        Flags::from_bits_truncate(self.usart().intenset().read().bits())
+    }
+
     /// Disable specified interrupts
     #[inline]
     pub(super) fn disable_interrupts(&mut self, flags: Flags) {
@@ -426,6 +624,38 @@ impl<S: Sercom> Registers<S> {
         self.enable_peripheral(true);
     }
 
+    /// Enable or disable the receiver of an enabled UART
+    ///
+    /// Disabling the receiver flushes the receive buffer and the shift
+    /// register.
+    ///
+    /// The halves of a split UART both modify `CTRLB`, so the
+    /// read-modify-write runs in a critical section.
+    #[inline]
+    pub(super) fn set_rx_enabled(&mut self, enabled: bool) {
+        let usart = self.usart();
+        critical_section::with(|_| {
+            usart.ctrlb().modify(|_, w| w.rxen().bit(enabled));
+            while usart.syncbusy().read().ctrlb().bit_is_set() {}
+        });
+    }
+
+    /// Enable or disable the transmitter of an enabled UART
+    ///
+    /// While the transmitter is disabled, it no longer overrides the TX pin,
+    /// which the PORT then configures as an input.
+    ///
+    /// The halves of a split UART both modify `CTRLB`, so the
+    /// read-modify-write runs in a critical section.
+    #[inline]
+    pub(super) fn set_tx_enabled(&mut self, enabled: bool) {
+        let usart = self.usart();
+        critical_section::with(|_| {
+            usart.ctrlb().modify(|_, w| w.txen().bit(enabled));
+            while usart.syncbusy().read().ctrlb().bit_is_set() {}
+        });
+    }
+
     #[inline]
     pub(super) fn disable(&mut self) {
         let usart = self.usart();
@@ -441,6 +671,12 @@ impl<S: Sercom> Registers<S> {
         self.enable_peripheral(false);
     }
 
+    /// Return `true` if the SERCOM peripheral is enabled
+    #[inline]
+    pub(super) fn get_enabled(&self) -> bool {
+        self.usart().ctrla().read().enable().bit()
+    }
+
     /// Enable or disable the SERCOM peripheral, and wait for the ENABLE bit to
     /// synchronize.
     pub(super) fn enable_peripheral(&mut self, enable: bool) {
@@ -449,26 +685,241 @@ impl<S: Sercom> Registers<S> {
     }
 }
 
+/// Get the lowest and highest baud rates achievable in `mode`, with a GCLK
+/// frequency of `freq`
+#[inline]
+pub(super) fn baud_rate_range(freq: Hertz, mode: BaudMode) -> (Hertz, Hertz) {
+    let freq = freq.to_Hz();
+    let (min, max) = match mode {
+        BaudMode::Arithmetic(n) => (
+            baud_rate_asynchronous_arithm(u16::MAX, freq, n as u8),
+            baud_rate_asynchronous_arithm(0, freq, n as u8),
+        ),
+        BaudMode::Fractional(n) => (
+            baud_rate_asynchronous_fractional(0x1FFF, 7, freq, n as u8),
+            baud_rate_asynchronous_fractional(1, 0, freq, n as u8),
+        ),
+    };
+    (Hertz::from_raw(min), Hertz::from_raw(max))
+}
+
+/// Get the error of the baud rate generated in `mode`, with a GCLK frequency
+/// of `clock`, relative to `target`, in parts per million
+///
+/// The `BAUD` and `FP` values are computed the same way as in
+/// [`Config::baud`](super::Config::baud). The error is positive when the
+/// generated baud rate is faster than `target`. Comparing the errors of
+/// several [`BaudMode`]s helps picking one, especially with low GCLK
+/// frequencies.
+#[inline]
+pub fn baud_error_ppm(clock: Hertz, target: Hertz, mode: BaudMode) -> i32 {
+    let (clock, target) = (clock.to_Hz(), target.to_Hz().max(1));
+    // The generated rate is `num / den`
+    let (num, den) = match mode {
+        BaudMode::Arithmetic(n) => {
+            let baud = calculate_baud_asynchronous_arithm(target, clock, n as u8);
+            (clock as i128 * (65536 - baud as i128), n as i128 * 65536)
+        }
+        BaudMode::Fractional(n) => {
+            let (baud, frac) = calculate_baud_asynchronous_fractional(target, clock, n as u8);
+            let baud_mult = (baud as i128 * 8 + frac as i128).max(1);
+            (clock as i128 * 8, n as i128 * baud_mult)
+        }
+    };
+    let error = (num * 1_000_000 - den * 1_000_000 * target as i128) / (den * target as i128);
+    error.clamp(i32::MIN as i128, i32::MAX as i128) as i32
+}
+
 /// Calculate baudrate value using the asynchronous arithmetic method (Table
 /// 24-2)
+///
+/// Baud rates outside of the achievable range saturate.
 #[inline]
 fn calculate_baud_asynchronous_arithm(baudrate: u32, clk_freq: u32, n_samples: u8) -> u16 {
     const SHIFT: u8 = 32;
     let sample_rate = (n_samples as u64 * baudrate as u64) << SHIFT;
-    let ratio = sample_rate / clk_freq as u64;
+    let ratio = (sample_rate / clk_freq as u64).min(1 << SHIFT);
     let scale = (1u64 << SHIFT) - ratio;
     let baud_calculated = (65536u64 * scale) >> SHIFT;
-    baud_calculated as u16
+    baud_calculated.min(u16::MAX as u64) as u16
 }
 
 /// Calculate baudrate value using the asynchronous frational method (Table
 /// 24-2)
+///
+/// Baud rates outside of the achievable range saturate.
 #[inline]
 fn calculate_baud_asynchronous_fractional(
     baudrate: u32,
     clk_freq: u32,
     n_samples: u8,
 ) -> (u16, u8) {
-    let baud_mult = (clk_freq * 8) / (n_samples as u32 * baudrate);
+    // `BAUD` is 13 bits wide in fractional mode, and must not be zero. Round to
+    // the nearest eighth of a clock period.
+    let divisor = n_samples as u64 * baudrate.max(1) as u64;
+    let baud_mult = (clk_freq as u64 * 8 + divisor / 2) / divisor;
+    let baud_mult = baud_mult.clamp(8, 0xFFFF);
     ((baud_mult / 8) as u16, (baud_mult % 8) as u8)
 }
+
+/// Calculate the baud rate produced by a `BAUD` value in asynchronous
+/// arithmetic mode
+#[inline]
+fn baud_rate_asynchronous_arithm(baud: u16, clk_freq: u32, n_samples: u8) -> u32 {
+    let rate = clk_freq as u64 * (65536 - baud as u64) / (n_samples as u64 * 65536);
+    rate as u32
+}
+
+/// Calculate the baud rate produced by `BAUD` and `FP` values in asynchronous
+/// fractional mode
+#[inline]
+fn baud_rate_asynchronous_fractional(baud: u16, frac: u8, clk_freq: u32, n_samples: u8) -> u32 {
+    let baud_mult = (baud as u64 * 8 + frac as u64).max(1);
+    (clk_freq as u64 * 8 / (n_samples as u64 * baud_mult)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baud_rate_round_trips() {
+        let (baud, frac) = calculate_baud_asynchronous_fractional(115_200, 48_000_000, 16);
+        let rate = baud_rate_asynchronous_fractional(baud, frac, 48_000_000, 16);
+        // The resolution is an eighth of a clock period, about 0.5% here
+        assert!(rate.abs_diff(115_200) < 115_200 / 200);
+
+        let baud = calculate_baud_asynchronous_arithm(115_200, 48_000_000, 16);
+        let rate = baud_rate_asynchronous_arithm(baud, 48_000_000, 16);
+        assert!(rate.abs_diff(115_200) < 115_200 / 1000);
+    }
+
+    #[test]
+    fn eight_times_oversampling_extends_range() {
+        // 1 MBaud is out of reach of 16x oversampling with an 8 MHz clock, and
+        // saturates at 500 kBaud
+        let baud = calculate_baud_asynchronous_arithm(1_000_000, 8_000_000, 16);
+        assert_eq!(baud_rate_asynchronous_arithm(baud, 8_000_000, 16), 500_000);
+        let (baud, frac) = calculate_baud_asynchronous_fractional(1_000_000, 8_000_000, 16);
+        assert_eq!(
+            baud_rate_asynchronous_fractional(baud, frac, 8_000_000, 16),
+            500_000
+        );
+
+        // 8x oversampling reaches it exactly
+        let baud = calculate_baud_asynchronous_arithm(1_000_000, 8_000_000, 8);
+        assert_eq!(baud_rate_asynchronous_arithm(baud, 8_000_000, 8), 1_000_000);
+        let (baud, frac) = calculate_baud_asynchronous_fractional(1_000_000, 8_000_000, 8);
+        assert_eq!(
+            baud_rate_asynchronous_fractional(baud, frac, 8_000_000, 8),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn baud_rate_range_bounds() {
+        let (min, max) =
+            baud_rate_range(Hertz::MHz(48), BaudMode::Fractional(Oversampling::Bits16));
+        assert_eq!(max, Hertz::MHz(3));
+        assert_eq!(min.to_Hz(), 366);
+        let (_, max) = baud_rate_range(Hertz::MHz(48), BaudMode::Arithmetic(Oversampling::Bits8));
+        assert_eq!(max, Hertz::MHz(6));
+    }
+
+    #[test]
+    fn maximum_baud_rate_boundary() {
+        let mode = BaudMode::Fractional(Oversampling::Bits16);
+        let (_, max) = baud_rate_range(Hertz::MHz(48), mode);
+        assert_eq!(max, Hertz::MHz(48) / Oversampling::Bits16 as u32);
+        // The maximum itself is reached exactly
+        let (baud, frac) = calculate_baud_asynchronous_fractional(max.to_Hz(), 48_000_000, 16);
+        assert_eq!(
+            baud_rate_asynchronous_fractional(baud, frac, 48_000_000, 16),
+            max.to_Hz()
+        );
+        // Anything faster saturates at the maximum
+        let (baud, frac) = calculate_baud_asynchronous_fractional(max.to_Hz() + 1, 48_000_000, 16);
+        assert_eq!(
+            baud_rate_asynchronous_fractional(baud, frac, 48_000_000, 16),
+            max.to_Hz()
+        );
+        let baud = calculate_baud_asynchronous_arithm(max.to_Hz() + 1, 48_000_000, 16);
+        assert_eq!(
+            baud_rate_asynchronous_arithm(baud, 48_000_000, 16),
+            max.to_Hz()
+        );
+    }
+
+    #[test]
+    fn sample_adjustment_field() {
+        use SampleAdjustment::*;
+        for (adjustment, bits) in [(Centered, 0), (Late, 1), (Later, 2), (Latest, 3)] {
+            assert_eq!(adjustment as u8, bits);
+            assert_eq!(SampleAdjustment::from_bits(bits), adjustment);
+        }
+        assert_eq!(SampleAdjustment::default(), Centered);
+        assert_eq!(Centered.samples(Oversampling::Bits16), [7, 8, 9]);
+        assert_eq!(Latest.samples(Oversampling::Bits16), [13, 14, 15]);
+        assert_eq!(Later.samples(Oversampling::Bits8), [5, 6, 7]);
+    }
+
+    #[test]
+    fn low_baud_rates_saturate() {
+        assert_eq!(
+            calculate_baud_asynchronous_arithm(0, 48_000_000, 16),
+            u16::MAX
+        );
+        assert_eq!(
+            calculate_baud_asynchronous_fractional(110, 120_000_000, 16),
+            (0x1FFF, 7)
+        );
+    }
+
+    #[test]
+    fn datasheet_baud_values() {
+        // Table "BAUD Register Value vs. Baud Frequency", with a 48 MHz GCLK
+        // and 16x oversampling
+        assert_eq!(
+            calculate_baud_asynchronous_arithm(9_600, 48_000_000, 16),
+            65326
+        );
+        assert_eq!(
+            calculate_baud_asynchronous_arithm(115_200, 48_000_000, 16),
+            63019
+        );
+
+        // BAUD + FP / 8 = fref / (S * fbaud)
+        assert_eq!(
+            calculate_baud_asynchronous_fractional(9_600, 48_000_000, 16),
+            (312, 4)
+        );
+        assert_eq!(
+            calculate_baud_asynchronous_fractional(115_200, 48_000_000, 16),
+            (26, 0)
+        );
+        assert_eq!(
+            calculate_baud_asynchronous_fractional(115_200, 8_000_000, 16),
+            (4, 3)
+        );
+    }
+
+    #[test]
+    fn baud_error() {
+        let arithm = BaudMode::Arithmetic(Oversampling::Bits16);
+        let frac = BaudMode::Fractional(Oversampling::Bits16);
+        assert_eq!(baud_error_ppm(Hertz::MHz(48), Hertz::Hz(9_600), frac), 0);
+        assert_eq!(
+            baud_error_ppm(Hertz::MHz(8), Hertz::Hz(115_200), arithm),
+            33
+        );
+        assert_eq!(
+            baud_error_ppm(Hertz::MHz(8), Hertz::Hz(115_200), frac),
+            -7936
+        );
+        // Saturated rates are far off
+        assert_eq!(
+            baud_error_ppm(Hertz::MHz(8), Hertz::MHz(1), arithm),
+            -500_000
+        );
+    }
+}
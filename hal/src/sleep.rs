@@ -0,0 +1,85 @@
+//! # Sleep modes
+//!
+//! The CPU enters a sleep mode whenever it executes a `WFI` or `WFE`
+//! instruction. [`set_sleep_mode`] selects which one, so that code which
+//! already sleeps while waiting, such as the idle loop of an `async` executor,
+//! saves more power without any other change.
+//!
+//! # Standby and `async` executors
+//!
+//! The thread-mode executor of `embassy-executor` executes `WFE` whenever no
+//! task is ready to run. Selecting [`SleepMode::Standby`] before starting it
+//! therefore keeps the chip in standby between wakeups. When the tasks wait on
+//! the RTC monotonic of `rtc::rtic`, the RTC compare interrupt is the
+//! wakeup source:
+//!
+//! ```no_run
+//! # use atsamd_hal::{pac, sleep::{set_sleep_mode, SleepMode}};
+//! # let mut peripherals = pac::Peripherals::take().unwrap();
+//! # let mut core = pac::CorePeripherals::take().unwrap();
+//! set_sleep_mode(&mut peripherals.pm, &mut core.SCB, SleepMode::Standby);
+//! // Mono::start(rtc), then spawn tasks which call Mono::delay(..).await
+//! ```
+//!
+//! No alarm can be missed by going to sleep. Scheduling a new earliest alarm
+//! pends the RTC interrupt, and the interrupt handler checks the counter again
+//! after writing each compare value, so an alarm which is already due is
+//! handled right away instead of waiting for a compare match. A pending
+//! interrupt always prevents `WFE` from entering sleep.
+//!
+//! Only peripherals which can run in standby keep working while the chip
+//! sleeps. The RTC clock source must be configured to run in standby: on
+//! SAMD11/SAMD21 chips, this means enabling
+//! [`configure_standby`](crate::clock::GenericClockController::configure_standby)
+//! for the generator feeding the RTC. Likewise, a debugger usually loses its
+//! connection while the chip is in standby.
+//!
+//! On some SAMD21 silicon revisions, the chip may not wake up from standby
+//! unless `NVMCTRL.CTRLB.SLEEPPRM` is set to `Disabled` first. Refer to the
+//! errata of your device.
+
+use atsamd_hal_macros::hal_cfg;
+use cortex_m::peripheral::SCB;
+
+use crate::pac::Pm;
+
+/// Sleep mode entered by the `WFI` and `WFE` instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SleepMode {
+    /// Only the CPU clock is stopped. Every peripheral keeps running and any
+    /// interrupt wakes the CPU up. This is the reset default.
+    Idle,
+    /// All clocks are stopped, except the ones required by peripherals
+    /// configured to run in standby
+    Standby,
+}
+
+/// Select the sleep mode entered by the next `WFI` or `WFE` instructions
+#[hal_cfg(any("pm-d11", "pm-d21"))]
+#[inline]
+pub fn set_sleep_mode(pm: &mut Pm, scb: &mut SCB, mode: SleepMode) {
+    match mode {
+        SleepMode::Idle => {
+            pm.sleep().write(|w| w.idle().cpu());
+            scb.clear_sleepdeep();
+        }
+        SleepMode::Standby => scb.set_sleepdeep(),
+    }
+}
+
+/// Select the sleep mode entered by the next `WFI` or `WFE` instructions
+#[hal_cfg("pm-d5x")]
+#[inline]
+pub fn set_sleep_mode(pm: &mut Pm, scb: &mut SCB, mode: SleepMode) {
+    // SAMx5x chips select the sleep mode with SLEEPCFG only
+    let _ = scb;
+    match mode {
+        SleepMode::Idle => pm.sleepcfg().write(|w| w.sleepmode().idle()),
+        SleepMode::Standby => pm.sleepcfg().write(|w| w.sleepmode().standby()),
+    };
+    // The write takes a few cycles to reach the register, and it must have
+    // done so before the next WFI
+    let standby = mode == SleepMode::Standby;
+    while pm.sleepcfg().read().sleepmode().is_standby() != standby {}
+}
@@ -8,6 +8,90 @@ pub struct TimerParams {
     pub cycles: u32,
 }
 
+/// Prescaler dividing a timer's source clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Prescaler {
+    Div1,
+    Div2,
+    Div4,
+    Div8,
+    Div16,
+    Div64,
+    Div256,
+    Div1024,
+}
+
+impl Prescaler {
+    /// Get the division factor of this prescaler
+    #[inline]
+    pub const fn divider(self) -> u16 {
+        match self {
+            Prescaler::Div1 => 1,
+            Prescaler::Div2 => 2,
+            Prescaler::Div4 => 4,
+            Prescaler::Div8 => 8,
+            Prescaler::Div16 => 16,
+            Prescaler::Div64 => 64,
+            Prescaler::Div256 => 256,
+            Prescaler::Div1024 => 1024,
+        }
+    }
+
+    /// Duration of a single timer tick with this prescaler, rounded down to
+    /// the nearest nanosecond
+    #[inline]
+    pub fn resolution(self, src_freq: Hertz) -> Nanoseconds {
+        Nanoseconds::from_ticks(ticks_to_ns(1, self, src_freq))
+    }
+
+    /// Longest period a 16-bit counter can reach with this prescaler, rounded
+    /// down to the nearest nanosecond and saturating at `u32::MAX` nanoseconds
+    #[inline]
+    pub fn max_period(self, src_freq: Hertz) -> Nanoseconds {
+        Nanoseconds::from_ticks(ticks_to_ns(u16::MAX as u64, self, src_freq))
+    }
+
+    /// Number of timer ticks elapsing during `duration` with this prescaler,
+    /// rounded down
+    ///
+    /// Returns [`Error::PeriodTooLong`] if the number of ticks doesn't fit in a
+    /// 16-bit counter, and [`Error::PeriodTooShort`] if a non-zero duration is
+    /// shorter than a single tick.
+    #[inline]
+    pub fn ticks_for(self, duration: Nanoseconds, src_freq: Hertz) -> Result<u16, Error> {
+        let ticks = ns_to_ticks_u64(duration, src_freq) / self.divider() as u64;
+        if ticks == 0 && duration.ticks() != 0 {
+            Err(Error::PeriodTooShort)
+        } else {
+            ticks.try_into().map_err(|_| Error::PeriodTooLong)
+        }
+    }
+
+    /// Duration of `ticks` timer ticks with this prescaler, rounded down to the
+    /// nearest nanosecond and saturating at `u32::MAX` nanoseconds
+    #[inline]
+    pub fn duration_for(self, ticks: u16, src_freq: Hertz) -> Nanoseconds {
+        Nanoseconds::from_ticks(ticks_to_ns(ticks as u64, self, src_freq))
+    }
+}
+
+fn ticks_to_ns(cycles: u64, prescaler: Prescaler, src_freq: Hertz) -> u32 {
+    let ns = cycles * prescaler.divider() as u64 * 1_000_000_000 / src_freq.to_Hz().max(1) as u64;
+    ns.try_into().unwrap_or(u32::MAX)
+}
+
+/// Errors returned when a timeout can't be represented by a 16-bit timer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// The timeout needs more counts than the 16-bit counter can hold, even
+    /// with the largest allowed prescaler
+    PeriodTooLong,
+    /// The timeout is shorter than a single tick of the prescaled clock
+    PeriodTooShort,
+}
+
 impl TimerParams {
     /// calculates TimerParams from a given frequency based timeout.
     pub fn new(timeout: Hertz, src_freq: Hertz) -> Self {
@@ -17,12 +101,58 @@ impl TimerParams {
 
     /// calculates TimerParams from a given period based timeout.
     pub fn new_ns(timeout: Nanoseconds, src_freq: Hertz) -> Self {
-        let ticks: u32 =
-            (timeout.to_nanos() as u64 * src_freq.to_Hz() as u64 / 1_000_000_000_u64) as u32;
-        Self::new_from_ticks(ticks)
+        Self::new_from_ticks(ns_to_ticks(timeout, src_freq))
+    }
+
+    /// Calculates TimerParams from a given period based timeout, selecting the
+    /// smallest prescaler that can reach it.
+    ///
+    /// Unlike [`new_ns`](Self::new_ns), this returns an [`Error`] if the
+    /// timeout is zero or too long for a 16-bit counter.
+    pub fn try_new_ns(timeout: Nanoseconds, src_freq: Hertz) -> Result<Self, Error> {
+        let ticks = ns_to_ticks(timeout, src_freq);
+        if ticks == 0 {
+            return Err(Error::PeriodTooShort);
+        }
+        Self::try_from_ticks(ticks)
+    }
+
+    /// Calculates TimerParams from a given period based timeout, using the
+    /// given prescaler.
+    ///
+    /// Returns an [`Error`] if the timeout is shorter than a single tick, or
+    /// longer than [`Prescaler::max_period`].
+    pub fn try_with_prescaler_ns(
+        timeout: Nanoseconds,
+        src_freq: Hertz,
+        prescaler: Prescaler,
+    ) -> Result<Self, Error> {
+        let divider = prescaler.divider() as u32;
+        let cycles = ns_to_ticks(timeout, src_freq) / divider;
+
+        if cycles == 0 {
+            Err(Error::PeriodTooShort)
+        } else if cycles > u16::MAX as u32 {
+            Err(Error::PeriodTooLong)
+        } else {
+            Ok(TimerParams {
+                divider: divider as u16,
+                cycles,
+            })
+        }
     }
 
     fn new_from_ticks(ticks: u32) -> Self {
+        match Self::try_from_ticks(ticks) {
+            Ok(params) => params,
+            Err(_) => panic!(
+                "cycles {} is out of range for a 16 bit counter",
+                ticks / 1024
+            ),
+        }
+    }
+
+    fn try_from_ticks(ticks: u32) -> Result<Self, Error> {
         let divider = ((ticks >> 16) + 1).next_power_of_two();
         let divider = match divider {
             1 | 2 | 4 | 8 | 16 | 64 | 256 | 1024 => divider,
@@ -31,28 +161,36 @@ impl TimerParams {
             32 => 64,
             128 => 256,
             512 => 1024,
-            // Catch all case; this is lame.  Would be great to detect this
-            // and fail at compile time.
+            // Anything larger can't be reached, even with the largest divider
             _ => 1024,
         };
 
         let cycles: u32 = ticks / divider;
 
         if cycles > u16::MAX as u32 {
-            panic!("cycles {} is out of range for a 16 bit counter", cycles);
+            return Err(Error::PeriodTooLong);
         }
 
-        TimerParams {
+        Ok(TimerParams {
             divider: divider as u16,
             cycles,
-        }
+        })
     }
 }
 
+fn ns_to_ticks(timeout: Nanoseconds, src_freq: Hertz) -> u32 {
+    ns_to_ticks_u64(timeout, src_freq) as u32
+}
+
+fn ns_to_ticks_u64(timeout: Nanoseconds, src_freq: Hertz) -> u64 {
+    timeout.to_nanos() as u64 * src_freq.to_Hz() as u64 / 1_000_000_000_u64
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fugit::{ExtU32, RateExtU32};
-    use crate::timer_params::TimerParams;
+    use crate::time::Nanoseconds;
+    use crate::timer_params::{Error, Prescaler, TimerParams};
 
     #[test]
     fn timer_params_hz_and_us_same_1hz() {
@@ -73,4 +211,144 @@ mod tests {
         assert_eq!(tp_from_hz.divider, tp_from_us.divider);
         assert!((tp_from_hz.cycles as i32 - tp_from_us.cycles as i32).abs() <= 1);
     }
+
+    #[test]
+    fn prescaler_resolution_and_max_period() {
+        assert_eq!(
+            Prescaler::Div1.resolution(48.MHz()),
+            20.nanos::<1, 1_000_000_000>()
+        );
+        assert_eq!(
+            Prescaler::Div1024.resolution(48.MHz()),
+            21_333.nanos::<1, 1_000_000_000>()
+        );
+        assert_eq!(
+            Prescaler::Div1.max_period(48.MHz()),
+            1_365_312.nanos::<1, 1_000_000_000>()
+        );
+        assert_eq!(
+            Prescaler::Div1024.max_period(120.MHz()),
+            559_232_000.nanos::<1, 1_000_000_000>()
+        );
+        // Saturates at the longest representable duration
+        assert_eq!(
+            Prescaler::Div1024.max_period(32_768.Hz()),
+            Nanoseconds::from_ticks(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn prescaler_ticks_and_durations() {
+        for freq in [32_768.Hz(), 48.MHz(), 120.MHz()] {
+            for prescaler in [
+                Prescaler::Div1,
+                Prescaler::Div2,
+                Prescaler::Div16,
+                Prescaler::Div64,
+                Prescaler::Div1024,
+            ] {
+                assert_eq!(prescaler.ticks_for(Nanoseconds::from_ticks(0), freq), Ok(0));
+                assert_eq!(prescaler.duration_for(0, freq), Nanoseconds::from_ticks(0));
+
+                // A round trip through ticks never gains any time
+                for ticks in [1, 2, 1000, u16::MAX - 1, u16::MAX] {
+                    let duration = prescaler.duration_for(ticks, freq);
+                    if duration == Nanoseconds::from_ticks(u32::MAX) {
+                        // Saturated
+                        continue;
+                    }
+                    let back = prescaler.ticks_for(duration, freq).unwrap_or(0);
+                    assert!(back <= ticks);
+                    assert!(ticks - back <= 1);
+                }
+
+                let resolution = prescaler.resolution(freq);
+                if resolution > 1.nanos::<1, 1_000_000_000>() {
+                    assert_eq!(
+                        prescaler.ticks_for(resolution - 1.nanos(), freq),
+                        Err(Error::PeriodTooShort)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(Prescaler::Div1.ticks_for(1.millis(), 48.MHz()), Ok(48_000));
+        assert_eq!(Prescaler::Div8.ticks_for(10.millis(), 48.MHz()), Ok(60_000));
+        assert_eq!(
+            Prescaler::Div4.ticks_for(10.millis(), 48.MHz()),
+            Err(Error::PeriodTooLong)
+        );
+        // Overflows an intermediate u32 product
+        assert_eq!(
+            Prescaler::Div1024.ticks_for(Nanoseconds::from_ticks(u32::MAX), 120.MHz()),
+            Err(Error::PeriodTooLong)
+        );
+        assert_eq!(
+            Prescaler::Div1024.ticks_for(500.millis(), 120.MHz()),
+            Ok(58_593)
+        );
+        assert_eq!(
+            Prescaler::Div64.duration_for(750, 48.MHz()),
+            1_000_000.nanos::<1, 1_000_000_000>()
+        );
+        assert_eq!(
+            Prescaler::Div1024.duration_for(u16::MAX, 32_768.Hz()),
+            Nanoseconds::from_ticks(u32::MAX)
+        );
+    }
+
+    /// Shortest duration spanning at least `ticks` source clock cycles
+    fn ns_for_ticks(ticks: u64, freq: crate::time::Hertz) -> Nanoseconds {
+        Nanoseconds::from_ticks((ticks * 1_000_000_000).div_ceil(freq.to_Hz() as u64) as u32)
+    }
+
+    #[test]
+    fn timer_params_boundaries() {
+        for freq in [48.MHz(), 120.MHz()] {
+            for prescaler in [Prescaler::Div1, Prescaler::Div16, Prescaler::Div1024] {
+                let div = prescaler.divider() as u64;
+
+                let max = prescaler.max_period(freq);
+                let params = TimerParams::try_with_prescaler_ns(max, freq, prescaler).unwrap();
+                assert_eq!(params.divider, prescaler.divider());
+                assert!(params.cycles >= u16::MAX as u32 - 1);
+
+                let longest = ns_for_ticks(u16::MAX as u64 * div, freq);
+                let params = TimerParams::try_with_prescaler_ns(longest, freq, prescaler).unwrap();
+                assert_eq!(params.cycles, u16::MAX as u32);
+
+                let too_long = ns_for_ticks((u16::MAX as u64 + 1) * div, freq);
+                assert_eq!(
+                    TimerParams::try_with_prescaler_ns(too_long, freq, prescaler).unwrap_err(),
+                    Error::PeriodTooLong
+                );
+
+                let shortest = ns_for_ticks(div, freq);
+                let params = TimerParams::try_with_prescaler_ns(shortest, freq, prescaler).unwrap();
+                assert_eq!(params.cycles, 1);
+
+                let too_short = shortest - 1.nanos();
+                assert_eq!(
+                    TimerParams::try_with_prescaler_ns(too_short, freq, prescaler).unwrap_err(),
+                    Error::PeriodTooShort
+                );
+            }
+
+            // Automatic selection reaches the same range as the largest prescaler
+            let longest = ns_for_ticks(u16::MAX as u64 * 1024, freq);
+            let params = TimerParams::try_new_ns(longest, freq).unwrap();
+            assert_eq!(params.divider, 1024);
+            assert_eq!(params.cycles, u16::MAX as u32);
+
+            let too_long = ns_for_ticks((u16::MAX as u64 + 1) * 1024, freq);
+            assert_eq!(
+                TimerParams::try_new_ns(too_long, freq).unwrap_err(),
+                Error::PeriodTooLong
+            );
+            assert_eq!(
+                TimerParams::try_new_ns(0.nanos(), freq).unwrap_err(),
+                Error::PeriodTooShort
+            );
+        }
+    }
 }